@@ -0,0 +1,324 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs;
+use tokio::sync::RwLock;
+
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "redis")]
+pub use self::redis::RedisCache;
+
+/// A trait for defining custom cache storage engines.
+///
+/// This allows the scraper to persist data (like course lists or session cookies)
+/// using different backends such as the local filesystem, memory, or external
+/// stores like Redis.
+#[async_trait]
+pub trait CacheBackend: Send + Sync {
+    /// Retrieves a value from the cache by its key.
+    ///
+    /// Returns `Some(String)` if the key exists and is not expired, otherwise `None`.
+    async fn get(&self, key: &str) -> Option<String>;
+
+    /// Stores a value in the cache with a specific Time-To-Live (TTL).
+    ///
+    /// # Arguments
+    /// * `key` - The unique identifier for the data.
+    /// * `value` - The string content to store.
+    /// * `ttl_secs` - Duration in seconds before the data expires.
+    async fn set(&self, key: &str, value: &str, ttl_secs: u64) -> Result<(), String>;
+
+    /// Removes a value from the cache.
+    async fn delete(&self, key: &str) -> Result<(), String>;
+
+    /// Removes every value whose key starts with `prefix`.
+    ///
+    /// Used on logout to drop the entries under the client's cache prefix. The default
+    /// implementation reports that it is unsupported, which logout ignores.
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), String> {
+        let _ = prefix;
+        Err("delete_prefix is not supported by this cache backend".to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    data: String,
+    expires_at: u64,
+}
+
+impl CacheEntry {
+    fn new(value: &str, ttl_secs: u64) -> Self {
+        Self {
+            data: value.to_string(),
+            expires_at: now_secs() + ttl_secs,
+        }
+    }
+
+    fn is_expired(&self) -> bool {
+        self.expires_at < now_secs()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// A default cache implementation that stores data as JSON files on the local filesystem.
+///
+/// It ensures data integrity through atomic writes (writing to a temporary file before renaming).
+pub struct FileCache {
+    cache_dir: PathBuf,
+    max_bytes: Option<u64>,
+}
+
+impl FileCache {
+    /// Creates a new `FileCache` using the specified directory.
+    pub fn new<P: AsRef<Path>>(cache_dir: P) -> Self {
+        Self {
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            max_bytes: None,
+        }
+    }
+
+    /// Creates a new `FileCache` whose total size on disk is capped at `max_bytes`.
+    ///
+    /// When a `set` pushes the directory over the limit, the least-recently-used
+    /// entries (by file modification time, refreshed on every cache hit) are
+    /// removed until it fits again.
+    pub fn with_limit<P: AsRef<Path>>(cache_dir: P, max_bytes: u64) -> Self {
+        Self {
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            max_bytes: Some(max_bytes),
+        }
+    }
+
+    /// Creates a `FileCache` in the OS-appropriate cache directory, creating it if needed.
+    ///
+    /// The entries live in a `spotifier-core` subdirectory of the user's cache directory,
+    /// e.g. `~/.cache/spotifier-core` on Linux, `~/Library/Caches/spotifier-core` on macOS
+    /// and `%LOCALAPPDATA%\spotifier-core` on Windows.
+    #[cfg(feature = "default-cache-dir")]
+    pub fn default_location() -> crate::error::Result<Self> {
+        let base_dirs = directories::BaseDirs::new().ok_or_else(|| {
+            crate::error::ScraperError::ParsingError(
+                "Could not determine the user's cache directory".to_string(),
+            )
+        })?;
+
+        let cache_dir = base_dirs.cache_dir().join("spotifier-core");
+        std::fs::create_dir_all(&cache_dir).map_err(|e| {
+            crate::error::ScraperError::ParsingError(format!(
+                "Failed to create cache directory: {}",
+                e
+            ))
+        })?;
+
+        Ok(Self::new(cache_dir))
+    }
+
+    /// Returns the directory the cache entries are stored in.
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    fn get_path(&self, key: &str) -> PathBuf {
+        self.cache_dir.join(format!("{}.json", key))
+    }
+
+    /// Marks an entry as recently used by bumping its modification time.
+    async fn touch(path: &Path) {
+        if let Ok(file) = fs::OpenOptions::new().write(true).open(path).await {
+            let file = file.into_std().await;
+            let _ = tokio::task::spawn_blocking(move || file.set_modified(SystemTime::now())).await;
+        }
+    }
+
+    /// Removes the least-recently-used entries until the cache fits within `max_bytes`.
+    ///
+    /// In-flight `.tmp` files and the entry that was just written (`keep`) are never evicted.
+    async fn evict_lru(&self, keep: &Path) -> Result<(), String> {
+        let Some(max_bytes) = self.max_bytes else {
+            return Ok(());
+        };
+
+        let mut entries = Vec::new();
+        let mut total: u64 = 0;
+        let mut dir = fs::read_dir(&self.cache_dir)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        while let Some(entry) = dir.next_entry().await.map_err(|e| e.to_string())? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+            let Ok(metadata) = entry.metadata().await else {
+                continue;
+            };
+            total += metadata.len();
+            if path != keep {
+                let modified = metadata.modified().unwrap_or(UNIX_EPOCH);
+                entries.push((modified, metadata.len(), path));
+            }
+        }
+
+        entries.sort_by_key(|(modified, _, _)| *modified);
+
+        for (_, len, path) in entries {
+            if total <= max_bytes {
+                break;
+            }
+            // Another writer may have removed the file already; either way it no longer counts.
+            let _ = fs::remove_file(&path).await;
+            total = total.saturating_sub(len);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CacheBackend for FileCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let path = self.get_path(key);
+        if !path.exists() {
+            return None;
+        }
+
+        let content = fs::read_to_string(&path).await.ok()?;
+        let entry: CacheEntry = serde_json::from_str(&content).ok()?;
+
+        if entry.is_expired() {
+            let _ = fs::remove_file(&path).await;
+            return None;
+        }
+
+        if self.max_bytes.is_some() {
+            Self::touch(&path).await;
+        }
+
+        Some(entry.data)
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl_secs: u64) -> Result<(), String> {
+        if !self.cache_dir.exists() {
+            fs::create_dir_all(&self.cache_dir)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        let path = self.get_path(key);
+        let tmp_path = path.with_extension("tmp");
+
+        let entry = CacheEntry::new(value, ttl_secs);
+
+        let json = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+
+        // Atomic write: write to tmp then rename
+        fs::write(&tmp_path, json)
+            .await
+            .map_err(|e| e.to_string())?;
+        fs::rename(&tmp_path, &path)
+            .await
+            .map_err(|e| e.to_string())?;
+
+        self.evict_lru(&path).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        let path = self.get_path(key);
+        if path.exists() {
+            fs::remove_file(path).await.map_err(|e| e.to_string())?;
+        }
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), String> {
+        if !self.cache_dir.exists() {
+            return Ok(());
+        }
+        let mut dir = fs::read_dir(&self.cache_dir)
+            .await
+            .map_err(|e| e.to_string())?;
+        while let Some(entry) = dir.next_entry().await.map_err(|e| e.to_string())? {
+            let path = entry.path();
+            let matches = path.extension().and_then(|ext| ext.to_str()) == Some("json")
+                && path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|key| key.starts_with(prefix));
+            if matches {
+                // Another writer may have removed the file already.
+                let _ = fs::remove_file(&path).await;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A cache that keeps entries in process memory.
+///
+/// Entries expire exactly like `FileCache` entries and are dropped lazily on `get`.
+/// Nothing survives the process, which makes it a good fit for tests and short-lived
+/// CLIs that should not touch the filesystem.
+#[derive(Default)]
+pub struct MemoryCache {
+    entries: RwLock<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    /// Creates an empty `MemoryCache`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl CacheBackend for MemoryCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        {
+            let entries = self.entries.read().await;
+            let entry = entries.get(key)?;
+            if !entry.is_expired() {
+                return Some(entry.data.clone());
+            }
+        }
+
+        let mut entries = self.entries.write().await;
+        // Re-check: another task may have refreshed the entry in the meantime.
+        if entries.get(key).is_some_and(CacheEntry::is_expired) {
+            entries.remove(key);
+        }
+        None
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl_secs: u64) -> Result<(), String> {
+        self.entries
+            .write()
+            .await
+            .insert(key.to_string(), CacheEntry::new(value, ttl_secs));
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        self.entries.write().await.remove(key);
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), String> {
+        self.entries
+            .write()
+            .await
+            .retain(|key, _| !key.starts_with(prefix));
+        Ok(())
+    }
+}
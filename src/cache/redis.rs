@@ -0,0 +1,100 @@
+use super::CacheBackend;
+use async_trait::async_trait;
+use redis::AsyncCommands;
+use redis::aio::ConnectionManager;
+
+/// A cache stored in Redis, so several workers can share course lists and pages.
+///
+/// Unlike `FileCache` and `MemoryCache`, values are stored as-is and expiry is left to
+/// Redis' native TTL (`SET key value EX ttl_secs`), so entries can also be inspected or
+/// expired with standard Redis tooling. Combine with
+/// [`SpotifierCoreClient::set_cache_prefix`](crate::SpotifierCoreClient::set_cache_prefix)
+/// to keep users apart.
+///
+/// Connection errors are treated like cache misses on `get` and reported as `Err` on
+/// `set`/`delete`; the connection manager reconnects automatically.
+#[derive(Clone)]
+pub struct RedisCache {
+    connection: ConnectionManager,
+}
+
+impl RedisCache {
+    /// Connects to the Redis server at `url` (e.g. `redis://127.0.0.1/`).
+    pub async fn new(url: &str) -> redis::RedisResult<Self> {
+        let client = redis::Client::open(url)?;
+        Ok(Self::from_connection_manager(
+            ConnectionManager::new(client).await?,
+        ))
+    }
+
+    /// Wraps an existing connection manager.
+    pub fn from_connection_manager(connection: ConnectionManager) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl CacheBackend for RedisCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let mut connection = self.connection.clone();
+        connection.get(key).await.ok().flatten()
+    }
+
+    async fn set(&self, key: &str, value: &str, ttl_secs: u64) -> Result<(), String> {
+        let mut connection = self.connection.clone();
+        // Redis rejects `EX 0`; an entry that expires immediately is simply not stored.
+        if ttl_secs == 0 {
+            return connection
+                .del::<_, ()>(key)
+                .await
+                .map_err(|e| e.to_string());
+        }
+        connection
+            .set_ex::<_, _, ()>(key, value, ttl_secs)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), String> {
+        let mut connection = self.connection.clone();
+        connection
+            .del::<_, ()>(key)
+            .await
+            .map_err(|e| e.to_string())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), String> {
+        let mut connection = self.connection.clone();
+        let pattern = format!("{}*", escape_glob(prefix));
+        let keys: Vec<String> = {
+            let mut scan = connection
+                .scan_match::<_, String>(&pattern)
+                .await
+                .map_err(|e| e.to_string())?;
+            let mut keys = Vec::new();
+            while let Some(key) = scan.next_item().await {
+                keys.push(key);
+            }
+            keys
+        };
+        if keys.is_empty() {
+            return Ok(());
+        }
+        connection
+            .del::<_, ()>(keys)
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Escapes the characters `SCAN MATCH` treats as wildcards.
+fn escape_glob(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
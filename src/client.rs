@@ -1,74 +1,772 @@
 use crate::cache::CacheBackend;
+use crate::cookies::SessionJar;
+use crate::credentials::Credentials;
 use crate::error::{Result, ScraperError};
+use crate::metrics::{ClientMetrics, MetricsRecorder};
 use crate::models::{
-    Course, DelayConfig, DetailCourse, Period, Semester, TopicDetail, TopicInfo, User,
+    Announcement, Answer, AttendanceRecord, CacheTtls, Course, CourseSummary, DelayAction,
+    DelayConfig, DetailCourse, Notification, Period, RetryConfig, Rps, ScheduleEntry, Semester,
+    Task, TopicDetail, TopicInfo, Transcript, User, UserAgentConfig,
 };
-use crate::parsers;
-use rand::Rng;
-use reqwest::cookie::{CookieStore, Jar};
-use reqwest::header::{HeaderMap, USER_AGENT};
-use reqwest::multipart;
-use scraper::{Html, Selector};
+use crate::parsers::{self, DEFAULT_BASE_URL};
+use crate::rate_limit::RateLimiter;
+use crate::recording::{self, DryRunRequest, Recording};
+use crate::retry;
+use crate::transport::{HttpTransport, ReqwestTransport};
+use crate::upload::{
+    MultipartForm, check_upload_type, content_disposition_file_name, sanitize_file_name,
+};
+use chrono::NaiveDateTime;
+use futures::stream::{self, Stream, StreamExt};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use reqwest::ResponseBuilderExt;
+use reqwest::cookie::CookieStore;
+use reqwest::header::{
+    CONTENT_DISPOSITION, CONTENT_TYPE, HeaderMap, HeaderValue, REFERER, RETRY_AFTER, USER_AGENT,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::Arc;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
-const SSO_LOGIN_PAGE_URL: &str =
-    "https://sso.upi.edu/cas/login?service=https://spot.upi.edu/beranda";
+const DEFAULT_SSO_URL: &str = "https://sso.upi.edu";
+
+/// Version of the file format written by `save_session`.
+const SESSION_SCHEMA_VERSION: u32 = 1;
+
+/// The envelope written by `save_session`: cookies plus the session's active period.
+#[derive(Serialize, Deserialize)]
+struct SavedSession {
+    version: u32,
+    cookies: HashMap<String, String>,
+    period: Option<Period>,
+}
+
+/// Default cap on the combined size of the files attached to one submission (10 MiB).
+const DEFAULT_MAX_UPLOAD_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Default cap on the number of course-list pages followed by `get_courses`.
+const DEFAULT_MAX_COURSE_PAGES: usize = 10;
+
+/// Default limit on a whole request, from connecting to reading the last byte of the body.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default limit on establishing the TCP (and TLS) connection.
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long the hash of the last fetched course list is cached. It has to outlive the
+/// course list itself, or a refresh after the list expired would count as a change.
+const COURSE_LIST_HASH_TTL_SECS: u64 = 30 * 24 * 3600;
+
+/// JSON variant of the course list, tried before scraping `/mhs` when `prefer_json` is on.
+///
+/// Not every SPOT deployment serves it, which is why it is opt-in.
+const COURSES_JSON_PATH: &str = "/api/mhs/matakuliah";
+
+/// Validates a base URL and strips its trailing slash, since paths are appended to it.
+fn normalize_base_url(url: &str) -> Result<String> {
+    let parsed = reqwest::Url::parse(url.trim())
+        .map_err(|e| ScraperError::ClientBuild(format!("Invalid base URL {:?}: {}", url, e)))?;
+    if !matches!(parsed.scheme(), "http" | "https") || parsed.host_str().is_none() {
+        return Err(ScraperError::ClientBuild(format!(
+            "Base URL {:?} must be an absolute http(s) URL",
+            url
+        )));
+    }
+    Ok(parsed.as_str().trim_end_matches('/').to_string())
+}
+
+/// Selects the original period again once a multi-period fetch is done.
+///
+/// Call [`PeriodGuard::restore`] on every normal exit. If the guard is dropped without
+/// it, because the future owning it was cancelled or panicked, the restore is spawned
+/// onto the runtime instead so the session is not left in another period.
+struct PeriodGuard {
+    client: Option<SpotifierCoreClient>,
+    original: Period,
+}
+
+impl PeriodGuard {
+    fn new(client: &SpotifierCoreClient, original: Period) -> Self {
+        Self {
+            client: Some(client.clone()),
+            original,
+        }
+    }
+
+    async fn restore(mut self) -> Result<()> {
+        let client = self.client.take().expect("restore runs once");
+        Self::select(client, self.original).await
+    }
+
+    /// Selects `original` unless it is known to be active. A switch that failed halfway
+    /// may leave the active period unknown, so that case is restored as well.
+    async fn select(client: SpotifierCoreClient, original: Period) -> Result<()> {
+        if client.current_period() == Some(original) {
+            return Ok(());
+        }
+        client.change_period(original.year, original.semester).await
+    }
+}
+
+impl Drop for PeriodGuard {
+    fn drop(&mut self) {
+        if let Some(client) = self.client.take()
+            && let Ok(runtime) = tokio::runtime::Handle::try_current()
+        {
+            let original = self.original;
+            runtime.spawn(async move {
+                let _ = Self::select(client, original).await;
+            });
+        }
+    }
+}
+
+/// Reads the period a course list belongs to from its first course.
+fn period_of_courses(courses: &[Course]) -> Option<Period> {
+    courses
+        .first()
+        .and_then(|course| Period::from_academic_year_string(&course.academic_year).ok())
+}
+
+/// Removes repeated courses, merging each into its first occurrence.
+fn dedup_courses(courses: Vec<Course>) -> Vec<Course> {
+    let mut unique: Vec<Course> = Vec::with_capacity(courses.len());
+    let mut index: HashMap<u64, usize> = HashMap::new();
+    for course in courses {
+        match index.get(&course.id) {
+            Some(&i) => unique[i] = unique[i].merge(&course),
+            None => {
+                index.insert(course.id, unique.len());
+                unique.push(course);
+            }
+        }
+    }
+    unique
+}
+
+/// When the request behind a response was handed to the transport.
+///
+/// Stored in the response's extensions, so a body read that runs into the client timeout
+/// reports the time since the request was sent rather than since the headers arrived.
+#[derive(Debug, Clone, Copy)]
+struct SentAt(Instant);
+
+/// Returns when the request behind `response` was sent, or now if that is unknown.
+fn sent_at(response: &reqwest::Response) -> Instant {
+    response
+        .extensions()
+        .get::<SentAt>()
+        .map_or_else(Instant::now, |sent| sent.0)
+}
+
+/// Reads a response body as text, mapping failures such as timeouts with
+/// `ScraperError::from_request`.
+async fn response_text(response: reqwest::Response) -> Result<String> {
+    let url = response.url().to_string();
+    let sent = sent_at(&response);
+    response
+        .text()
+        .await
+        .map_err(|e| ScraperError::from_request(e, &url, sent))
+}
+
+/// Turns a 429 response into `ScraperError::RateLimited`, reading its `Retry-After`.
+fn reject_rate_limited(response: reqwest::Response) -> Result<reqwest::Response> {
+    if response.status() != reqwest::StatusCode::TOO_MANY_REQUESTS {
+        return Ok(response);
+    }
+    let retry_after = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| retry::parse_retry_after(value, chrono::Utc::now()));
+    Err(ScraperError::RateLimited { retry_after })
+}
+
+/// Lowercases upload type extensions and strips their leading dot.
+fn normalize_extensions(extensions: &[&str]) -> Vec<String> {
+    extensions
+        .iter()
+        .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
+        .collect()
+}
+
+/// Result of [`SpotifierCoreClient::health_check`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct HealthStatus {
+    /// Whether SPOT answered at all (no network error, timeout or 5xx response).
+    pub reachable: bool,
+    /// Whether the session is logged in, i.e. the dashboard shows the student profile.
+    pub authenticated: bool,
+    /// The period the dashboard's course list belongs to, as an academic year string
+    /// (e.g. `"2025/2026 - Ganjil"`). `None` when not authenticated or the dashboard
+    /// lists no courses.
+    pub active_period: Option<String>,
+}
+
+/// Result of [`SpotifierCoreClient::get_courses_checked`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CourseListResult {
+    /// The course list of the active period.
+    pub courses: Vec<Course>,
+    /// Whether the list was served from the cache without contacting SPOT.
+    pub from_cache: bool,
+    /// Whether a fresh fetch differs from the list this client saw last time. `true` on
+    /// the first fetch, always `false` when `from_cache` is set.
+    pub changed: bool,
+}
+
+tokio::task_local! {
+    /// Set while a re-login restores the active period, to stop it from recursing.
+    static RESTORING_PERIOD: ();
+    /// How many `switch_period` calls are nested in this task; see
+    /// [`MAX_PERIOD_SWITCH_DEPTH`].
+    static PERIOD_SWITCH_DEPTH: usize;
+}
+
+/// How deeply period switches may nest. A switch reads the course list back, which can
+/// trigger a re-login, which switches to the active period again. `RESTORING_PERIOD`
+/// already stops that second switch from logging in once more; this bounds the chain
+/// even if another path into it appears.
+const MAX_PERIOD_SWITCH_DEPTH: usize = 2;
 
-const MODERN_USER_AGENTS: &[&str] = &[
-    "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36",
-    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36",
-    "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:133.0) Gecko/20100101 Firefox/133.0",
-    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.1 Safari/605.1.15",
-];
+/// Supplies the NIM and password for an automatic re-login, or `None` to give up.
+pub type CredentialProvider = Arc<dyn Fn() -> Option<(String, String)> + Send + Sync>;
+
+/// How the client re-authenticates when it finds the session expired mid-request.
+///
+/// Disabled unless installed with [`SpotifierCoreClient::set_auto_relogin`]. With it, a
+/// page fetch that hits `ScraperError::SessionExpired` logs in again once and repeats the
+/// request; a second expiry in the same call is returned to the caller.
+#[derive(Clone)]
+pub enum AutoReloginConfig {
+    /// Logs in again with the NIM and password of the last successful
+    /// [`SpotifierCoreClient::login`], whether that happened before or after this was
+    /// enabled.
+    ///
+    /// The client keeps those credentials in memory (shared by clones of the client)
+    /// until [`SpotifierCoreClient::logout`] or the last clone is dropped, and the
+    /// password is overwritten with zeros then. If even that is not acceptable, use
+    /// `Provider` and fetch it from a keyring or prompt instead.
+    RememberLogin,
+    /// Asks the closure for credentials on every re-login; nothing is stored.
+    Provider(CredentialProvider),
+}
 
 /// The core client for interacting with the SPOT API.
 ///
 /// This client handles authentication, period management, course/topic retrieval,
 /// and task submissions with built-in protections like randomized delays and
 /// User-Agent rotation to simulate human behavior.
+///
+/// Cloning is cheap: clones share the same HTTP connection pool, cookie jar (session),
+/// cache backend and rate limiter. Configuration changed on a clone afterwards (e.g. via
+/// `set_cache`) only affects that clone, so install a shared cache before cloning or use
+/// [`SpotifierCoreClient::shared`].
+#[derive(Clone)]
 pub struct SpotifierCoreClient {
     client: reqwest::Client,
+    transport: Arc<dyn HttpTransport>,
     base_url: String,
+    sso_url: String,
     delay_config: DelayConfig,
-    cookie_jar: Arc<Jar>,
+    cookie_jar: Arc<SessionJar>,
     cache: Option<Arc<dyn CacheBackend>>,
     cache_prefix: Option<String>,
+    cache_ttls: CacheTtls,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_config: Option<RetryConfig>,
+    prefer_json: bool,
+    json_unavailable: Arc<AtomicBool>,
+    recording_dir: Option<PathBuf>,
+    send_referer: bool,
+    error_body_snippets: bool,
+    last_page: Arc<Mutex<Option<reqwest::Url>>>,
+    pending_otp: Arc<Mutex<Option<(reqwest::Url, parsers::login::OtpForm)>>>,
+    user_agents: Arc<UserAgentConfig>,
+    requests_sent: Arc<AtomicUsize>,
+    current_period: Arc<Mutex<Option<Period>>>,
+    auto_relogin: Option<AutoReloginConfig>,
+    /// The credentials of the last successful login, zeroized when replaced or dropped.
+    remembered_login: Arc<Mutex<Option<Credentials>>>,
+    /// Held while re-logging in, so concurrent requests that find the session expired
+    /// log in only once.
+    relogin_lock: Arc<tokio::sync::Mutex<()>>,
+    /// Bumped after every re-login; a request that saw an older value knows another
+    /// request has already renewed the session.
+    session_generation: Arc<AtomicU64>,
+    max_upload_bytes: u64,
+    allowed_upload_types: Option<Vec<String>>,
+    max_course_pages: usize,
+    delay_rng: Option<Arc<Mutex<StdRng>>>,
+    metrics: Arc<MetricsRecorder>,
+    dry_run: bool,
+    dry_run_fixture: Option<Arc<str>>,
+    dry_run_log: Arc<Mutex<Vec<DryRunRequest>>>,
+    course_list_hash: Arc<Mutex<HashMap<String, u64>>>,
 }
 
-impl SpotifierCoreClient {
-    /// Creates a new `SpotifierCoreClient` with default configuration.
+/// Builder for [`SpotifierCoreClient`], created with [`SpotifierCoreClient::builder`].
+///
+/// Every option defaults to what [`SpotifierCoreClient::new`] uses, so only the settings
+/// that differ need to be given:
+///
+/// ```no_run
+/// use spotifier_core::{DelayConfig, MemoryCache, RetryConfig, SpotifierCoreClient};
+/// use std::sync::Arc;
+///
+/// # fn main() -> spotifier_core::Result<()> {
+/// let client = SpotifierCoreClient::builder()
+///     .delay(DelayConfig::default())
+///     .cache(Arc::new(MemoryCache::new()))
+///     .retry(RetryConfig::default())
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Default)]
+pub struct SpotifierCoreClientBuilder {
+    delay_config: DelayConfig,
+    user_agents: UserAgentConfig,
+    base_url: Option<String>,
+    sso_url: Option<String>,
+    cache: Option<Arc<dyn CacheBackend>>,
+    cache_prefix: Option<String>,
+    cache_ttls: CacheTtls,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_config: Option<RetryConfig>,
+    auto_relogin: Option<AutoReloginConfig>,
+    transport: Option<Arc<dyn HttpTransport>>,
+    prefer_json: Option<bool>,
+    send_referer: Option<bool>,
+    error_body_snippets: bool,
+    recording_dir: Option<PathBuf>,
+    max_upload_bytes: Option<u64>,
+    allowed_upload_types: Option<Vec<String>>,
+    max_course_pages: Option<usize>,
+    rng_seed: Option<u64>,
+    dry_run: bool,
+    dry_run_fixture: Option<Arc<str>>,
+    proxies: Vec<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    root_certificates_only: bool,
+    danger_accept_invalid_certs: bool,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+}
+
+impl SpotifierCoreClientBuilder {
+    /// Sets the delay between requests (default: 1-3s, randomized).
+    pub fn delay(mut self, config: DelayConfig) -> Self {
+        self.delay_config = config;
+        self
+    }
+
+    /// Sets the User-Agent pool rotated across requests.
+    pub fn user_agents(mut self, config: UserAgentConfig) -> Self {
+        self.user_agents = config;
+        self
+    }
+
+    /// Sets the SPOT instance to talk to; see [`SpotifierCoreClient::set_base_url`].
     ///
-    /// The default configuration includes randomized delays (1-3s) between requests.
-    pub fn new() -> Self {
-        Self::with_config(DelayConfig::default())
+    /// The URL is validated by [`build`](Self::build).
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = Some(base_url.to_string());
+        self
     }
 
-    /// Creates a new `SpotifierCoreClient` with a custom `DelayConfig`.
-    pub fn with_config(delay_config: DelayConfig) -> Self {
-        let cookie_jar = Arc::new(Jar::default());
+    /// Sets the SSO (CAS) server; see [`SpotifierCoreClient::set_sso_url`].
+    ///
+    /// The URL is validated by [`build`](Self::build).
+    pub fn sso_url(mut self, sso_url: &str) -> Self {
+        self.sso_url = Some(sso_url.to_string());
+        self
+    }
 
-        let mut headers = HeaderMap::new();
-        // Pick a random User-Agent at initialization
-        let ua = MODERN_USER_AGENTS[rand::rng().random_range(0..MODERN_USER_AGENTS.len())];
-        headers.insert(USER_AGENT, ua.parse().unwrap());
+    /// Sets the cache backend; see [`SpotifierCoreClient::set_cache`].
+    pub fn cache(mut self, cache: Arc<dyn CacheBackend>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets a prefix for all cache keys; see [`SpotifierCoreClient::set_cache_prefix`].
+    pub fn cache_prefix(mut self, prefix: &str) -> Self {
+        self.cache_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Sets how long each kind of resource stays in the cache.
+    pub fn cache_ttls(mut self, ttls: CacheTtls) -> Self {
+        self.cache_ttls = ttls;
+        self
+    }
+
+    /// Installs a shared rate limiter; see [`SpotifierCoreClient::set_rate_limiter`].
+    pub fn rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(limiter);
+        self
+    }
+
+    /// Caps the aggregate request rate; see [`SpotifierCoreClient::set_requests_per_second`].
+    ///
+    /// Replaces any limiter given to [`rate_limiter`](Self::rate_limiter).
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is not a positive, finite number.
+    pub fn requests_per_second(mut self, requests_per_second: f64) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::per_second(requests_per_second)));
+        self
+    }
+
+    /// Enables automatic retries; see [`SpotifierCoreClient::set_retry_config`].
+    pub fn retry(mut self, config: RetryConfig) -> Self {
+        self.retry_config = Some(config);
+        self
+    }
+
+    /// Enables automatic re-login; see [`SpotifierCoreClient::set_auto_relogin`].
+    pub fn auto_relogin(mut self, config: AutoReloginConfig) -> Self {
+        self.auto_relogin = Some(config);
+        self
+    }
+
+    /// Sends every request through `transport`; see [`SpotifierCoreClient::with_transport`].
+    pub fn transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets whether JSON endpoints are tried first; see [`SpotifierCoreClient::set_prefer_json`].
+    pub fn prefer_json(mut self, prefer_json: bool) -> Self {
+        self.prefer_json = Some(prefer_json);
+        self
+    }
+
+    /// Enables or disables the `Referer` header; see [`SpotifierCoreClient::set_send_referer`].
+    pub fn send_referer(mut self, send_referer: bool) -> Self {
+        self.send_referer = Some(send_referer);
+        self
+    }
+
+    /// Keeps the start of failed responses in errors; see
+    /// [`SpotifierCoreClient::set_error_body_snippets`].
+    pub fn error_body_snippets(mut self, enabled: bool) -> Self {
+        self.error_body_snippets = enabled;
+        self
+    }
+
+    /// Enables recording mode; see [`SpotifierCoreClient::set_recording_dir`].
+    pub fn recording_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.recording_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Caps the size of task uploads; see [`SpotifierCoreClient::set_max_upload_bytes`].
+    pub fn max_upload_bytes(mut self, limit: u64) -> Self {
+        self.max_upload_bytes = Some(limit);
+        self
+    }
+
+    /// Restricts task uploads to the given file types; see
+    /// [`SpotifierCoreClient::set_allowed_upload_types`].
+    pub fn allowed_upload_types(mut self, extensions: &[&str]) -> Self {
+        self.allowed_upload_types = Some(normalize_extensions(extensions));
+        self
+    }
+
+    /// Caps how many course-list pages are followed; see
+    /// [`SpotifierCoreClient::set_max_course_pages`].
+    pub fn max_course_pages(mut self, max_pages: usize) -> Self {
+        self.max_course_pages = Some(max_pages);
+        self
+    }
+
+    /// Seeds the RNG that draws request delays; see [`SpotifierCoreClient::set_rng_seed`].
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.rng_seed = Some(seed);
+        self
+    }
+
+    /// Enables dry-run mode; see [`SpotifierCoreClient::set_dry_run`].
+    pub fn dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled;
+        self
+    }
+
+    /// Sets the page served to every request in dry-run mode; see
+    /// [`SpotifierCoreClient::set_dry_run_fixture`].
+    pub fn dry_run_fixture(mut self, html: &str) -> Self {
+        self.dry_run_fixture = Some(Arc::from(html));
+        self
+    }
+
+    /// Routes requests through `proxy`. May be called more than once; reqwest picks the
+    /// first proxy whose scheme matches each request.
+    ///
+    /// HTTP and HTTPS proxies work out of the box; `socks5://` and `socks5h://` need the
+    /// `socks` feature. Cookies are still kept by the client, and TLS certificates are
+    /// still verified end to end through the proxy.
+    ///
+    /// ```no_run
+    /// use spotifier_core::SpotifierCoreClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = SpotifierCoreClient::builder()
+    ///     .proxy(reqwest::Proxy::https("http://proxy.example.com:3128")?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trusts `cert` as a root CA in addition to the built-in roots, e.g. a corporate CA
+    /// or the CA of an intercepting debugging proxy.
+    ///
+    /// ```no_run
+    /// use spotifier_core::SpotifierCoreClient;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let pem = std::fs::read("corporate-ca.pem")?;
+    /// let client = SpotifierCoreClient::builder()
+    ///     .add_root_certificate(reqwest::Certificate::from_pem(&pem)?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_root_certificate(mut self, cert: reqwest::Certificate) -> Self {
+        self.root_certificates.push(cert);
+        self
+    }
+
+    /// Trusts only the certificates given to
+    /// [`add_root_certificate`](Self::add_root_certificate), ignoring the built-in roots.
+    ///
+    /// Use this to pin SPOT and the SSO server to a known CA (default: `false`).
+    pub fn root_certificates_only(mut self, only: bool) -> Self {
+        self.root_certificates_only = only;
+        self
+    }
+
+    /// Disables TLS certificate verification (default: `false`).
+    ///
+    /// # Warning
+    /// **Dangerous.** Any certificate is accepted, including expired, self-signed and
+    /// forged ones, so anyone on the network path can read the session cookie and the
+    /// credentials sent to the SSO server. Only meant for local debugging; prefer
+    /// [`add_root_certificate`](Self::add_root_certificate) for intercepting proxies.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.danger_accept_invalid_certs = accept;
+        self
+    }
+
+    /// Limits how long a whole request may take, including reading the response body
+    /// (default: 30s).
+    ///
+    /// A request that runs out of time fails with `ScraperError::Timeout`; GET requests
+    /// are then retried as configured with [`retry`](Self::retry).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Limits how long connecting to SPOT or the SSO server may take (default: 10s).
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// Returns `ScraperError::ClientBuild` if the User-Agent pool is empty or holds an
+    /// invalid header value, a URL is not an absolute http(s) URL, or the HTTP client
+    /// (e.g. its TLS backend) fails to initialize.
+    pub fn build(self) -> Result<SpotifierCoreClient> {
+        let user_agents = self.user_agents;
+        if user_agents.user_agents.is_empty() {
+            return Err(ScraperError::ClientBuild(
+                "The User-Agent pool is empty".to_string(),
+            ));
+        }
+        for ua in &user_agents.user_agents {
+            HeaderValue::from_str(ua).map_err(|e| {
+                ScraperError::ClientBuild(format!("Invalid User-Agent {:?}: {}", ua, e))
+            })?;
+        }
+
+        let base_url = match &self.base_url {
+            Some(url) => normalize_base_url(url)?,
+            None => DEFAULT_BASE_URL.to_string(),
+        };
+        let sso_url = match &self.sso_url {
+            Some(url) => normalize_base_url(url)?,
+            None => DEFAULT_SSO_URL.to_string(),
+        };
 
-        let client = reqwest::Client::builder()
+        let cookie_jar = Arc::new(SessionJar::default());
+
+        let mut headers = HeaderMap::new();
+        // Fallback for requests that bypass the per-request rotation
+        let ua = user_agents.pick(0, &mut rand::rng());
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(ua)
+                .map_err(|e| ScraperError::ClientBuild(format!("Invalid User-Agent: {}", e)))?,
+        );
+
+        let mut client = reqwest::Client::builder()
             .cookie_store(true)
             .cookie_provider(Arc::clone(&cookie_jar))
             .default_headers(headers) // Use the headers
+            .timeout(self.timeout.unwrap_or(DEFAULT_TIMEOUT))
+            .connect_timeout(self.connect_timeout.unwrap_or(DEFAULT_CONNECT_TIMEOUT));
+        for proxy in self.proxies {
+            client = client.proxy(proxy);
+        }
+        client = if self.root_certificates_only {
+            client.tls_certs_only(self.root_certificates)
+        } else {
+            client.tls_certs_merge(self.root_certificates)
+        };
+        if self.danger_accept_invalid_certs {
+            client = client.tls_danger_accept_invalid_certs(true);
+        }
+        let client = client
             .build()
-            .unwrap();
+            .map_err(|e| ScraperError::ClientBuild(e.to_string()))?;
 
-        Self {
+        Ok(SpotifierCoreClient {
+            transport: self
+                .transport
+                .unwrap_or_else(|| Arc::new(ReqwestTransport::new(client.clone()))),
             client,
-            base_url: "https://spot.upi.edu".to_string(),
-            delay_config,
+            base_url,
+            sso_url,
+            delay_config: self.delay_config,
             cookie_jar,
-            cache: None,
-            cache_prefix: None,
-        }
+            cache: self.cache,
+            cache_prefix: self.cache_prefix,
+            cache_ttls: self.cache_ttls,
+            rate_limiter: self.rate_limiter,
+            retry_config: self.retry_config,
+            prefer_json: self.prefer_json.unwrap_or(true),
+            json_unavailable: Arc::new(AtomicBool::new(false)),
+            recording_dir: self.recording_dir,
+            send_referer: self.send_referer.unwrap_or(true),
+            error_body_snippets: self.error_body_snippets,
+            last_page: Arc::new(Mutex::new(None)),
+            pending_otp: Arc::new(Mutex::new(None)),
+            user_agents: Arc::new(user_agents),
+            requests_sent: Arc::new(AtomicUsize::new(0)),
+            current_period: Arc::new(Mutex::new(None)),
+            auto_relogin: self.auto_relogin,
+            remembered_login: Arc::new(Mutex::new(None)),
+            relogin_lock: Arc::new(tokio::sync::Mutex::new(())),
+            session_generation: Arc::new(AtomicU64::new(0)),
+            max_upload_bytes: self.max_upload_bytes.unwrap_or(DEFAULT_MAX_UPLOAD_BYTES),
+            allowed_upload_types: self.allowed_upload_types,
+            max_course_pages: self.max_course_pages.unwrap_or(DEFAULT_MAX_COURSE_PAGES),
+            delay_rng: self
+                .rng_seed
+                .map(|seed| Arc::new(Mutex::new(StdRng::seed_from_u64(seed)))),
+            metrics: Arc::new(MetricsRecorder::default()),
+            dry_run: self.dry_run,
+            dry_run_fixture: self.dry_run_fixture,
+            dry_run_log: Arc::new(Mutex::new(Vec::new())),
+            course_list_hash: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+}
+
+impl Default for SpotifierCoreClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpotifierCoreClient {
+    /// Creates a new `SpotifierCoreClient` with default configuration.
+    ///
+    /// The default configuration includes randomized delays (1-3s) between requests.
+    ///
+    /// # Panics
+    /// Panics if the HTTP client cannot be built; use [`try_new`](Self::try_new) to
+    /// handle that as an error.
+    pub fn new() -> Self {
+        Self::try_new().expect("failed to build the SPOT HTTP client")
+    }
+
+    /// Creates a new `SpotifierCoreClient` with default configuration, returning
+    /// `ScraperError::ClientBuild` instead of panicking if the HTTP client cannot be built.
+    pub fn try_new() -> Result<Self> {
+        Self::try_with_config(DelayConfig::default())
+    }
+
+    /// Returns a [`SpotifierCoreClientBuilder`] for configuring a client in one expression.
+    pub fn builder() -> SpotifierCoreClientBuilder {
+        SpotifierCoreClientBuilder::default()
+    }
+
+    /// Creates a new `SpotifierCoreClient` with default configuration that uses `cache`.
+    ///
+    /// The cache is held behind an `Arc`, so every clone of the returned client reads and
+    /// writes the same cache entries.
+    pub fn shared(cache: Arc<dyn CacheBackend>) -> Self {
+        Self::builder()
+            .cache(cache)
+            .build()
+            .expect("failed to build the SPOT HTTP client")
+    }
+
+    /// Creates a client that sends every request through `transport` instead of the
+    /// network.
+    ///
+    /// Meant for tests, e.g. with a [`ReplayTransport`](crate::ReplayTransport) serving
+    /// recorded fixtures. Cookies set through a custom transport never reach the client's
+    /// cookie jar, so `save_cookies` and `save_session` do not see them.
+    pub fn with_transport(transport: Arc<dyn HttpTransport>) -> Self {
+        Self::builder()
+            .transport(transport)
+            .build()
+            .expect("failed to build the SPOT HTTP client")
+    }
+
+    /// Creates a new `SpotifierCoreClient` with a custom `DelayConfig`.
+    ///
+    /// # Panics
+    /// Panics if the HTTP client cannot be built; see [`try_with_config`](Self::try_with_config).
+    pub fn with_config(delay_config: DelayConfig) -> Self {
+        Self::try_with_config(delay_config).expect("failed to build the SPOT HTTP client")
+    }
+
+    /// Creates a new `SpotifierCoreClient` with a custom `DelayConfig`, returning
+    /// `ScraperError::ClientBuild` if the HTTP client (e.g. its TLS backend) fails to
+    /// initialize.
+    pub fn try_with_config(delay_config: DelayConfig) -> Result<Self> {
+        Self::builder().delay(delay_config).build()
+    }
+
+    /// Creates a new `SpotifierCoreClient` with a custom `DelayConfig` and User-Agent pool.
+    ///
+    /// Returns `ScraperError::ClientBuild` if the pool is empty, one of its entries is not
+    /// a valid header value, or the HTTP client fails to initialize.
+    pub fn try_with_user_agents(
+        delay_config: DelayConfig,
+        user_agents: UserAgentConfig,
+    ) -> Result<Self> {
+        Self::builder()
+            .delay(delay_config)
+            .user_agents(user_agents)
+            .build()
     }
 
     /// Sets the cache backend for the client.
@@ -79,6 +777,16 @@ impl SpotifierCoreClient {
         self.cache = Some(cache);
     }
 
+    /// Installs a [`FileCache`](crate::FileCache) in the OS-appropriate cache directory.
+    ///
+    /// See [`FileCache::default_location`](crate::FileCache::default_location).
+    #[cfg(feature = "default-cache-dir")]
+    pub fn set_default_file_cache(&mut self) -> Result<()> {
+        let cache = crate::cache::FileCache::default_location()?;
+        self.set_cache(Arc::new(cache));
+        Ok(())
+    }
+
     /// Sets a prefix/namespace for all cache keys.
     ///
     /// This is particularly useful for multi-user applications (like an API)
@@ -87,6 +795,68 @@ impl SpotifierCoreClient {
         self.cache_prefix = Some(prefix.to_string());
     }
 
+    /// Sets how long each kind of resource stays in the cache.
+    ///
+    /// See [`CacheTtls`] for the defaults.
+    pub fn set_cache_ttls(&mut self, ttls: CacheTtls) {
+        self.cache_ttls = ttls;
+    }
+
+    /// Returns the base URL of the SPOT instance this client talks to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the base URL of the SSO (CAS) server used for login.
+    pub fn sso_url(&self) -> &str {
+        &self.sso_url
+    }
+
+    /// Points the client at another SPOT instance, such as a staging mirror or a local
+    /// proxy (default: `https://spot.upi.edu`).
+    ///
+    /// Every page, the SSO `service` parameter and the host checks after login use this
+    /// URL. Returns `ScraperError::ClientBuild` if it is not an absolute http(s) URL.
+    pub fn set_base_url(&mut self, base_url: &str) -> Result<()> {
+        self.base_url = normalize_base_url(base_url)?;
+        Ok(())
+    }
+
+    /// Sets the SSO (CAS) server used for login (default: `https://sso.upi.edu`).
+    ///
+    /// The login page is expected at `<sso_url>/cas/login`. Returns
+    /// `ScraperError::ClientBuild` if it is not an absolute http(s) URL.
+    pub fn set_sso_url(&mut self, sso_url: &str) -> Result<()> {
+        self.sso_url = normalize_base_url(sso_url)?;
+        Ok(())
+    }
+
+    /// Builds the canonical link to a course's detail page.
+    pub fn course_url(&self, course_id: u64) -> String {
+        format!("{}/mhs/topik/{}", self.base_url, course_id)
+    }
+
+    /// Builds the canonical link to a topic page within a course.
+    pub fn topic_url(&self, course_id: u64, topic_id: u64) -> String {
+        format!("{}/mhs/topik/{}/{}", self.base_url, course_id, topic_id)
+    }
+
+    /// Turns an href from a parsed model (e.g. `Course::href`, `Answer::file_href`) into
+    /// an absolute URL on this client's SPOT instance.
+    ///
+    /// Paths are appended to the base URL; absolute URLs are returned unchanged. See
+    /// [`parsers::normalize_href`] for the form the parsers produce.
+    pub fn resolve_href(&self, href: &str) -> String {
+        let href = href.trim();
+        if reqwest::Url::parse(href).is_ok() {
+            href.to_string()
+        } else if href.starts_with('/') {
+            format!("{}{}", self.base_url, href)
+        } else {
+            format!("{}/{}", self.base_url, href)
+        }
+    }
+
     fn get_cache_key(&self, key: &str) -> String {
         match &self.cache_prefix {
             Some(prefix) => format!("{}:{}", prefix, key),
@@ -94,229 +864,1621 @@ impl SpotifierCoreClient {
         }
     }
 
-    /// Saves the current session cookies to a JSON file.
+    /// Scopes a cache key to the active period, e.g. `courses:20251`.
+    ///
+    /// SPOT serves the course list, course and topic pages of whichever period the
+    /// session has selected, so entries of different periods must not be mixed up. Keys
+    /// stay bare while no period has been selected.
+    fn period_key(&self, key: &str) -> String {
+        match self.current_period() {
+            Some(period) => format!("{}:{}", key, period.format()),
+            None => key.to_string(),
+        }
+    }
+
+    /// Reads a cached value, returning `None` if there is no cache, no entry or the
+    /// entry no longer deserializes.
+    async fn cache_get<T: serde::de::DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let cache = self.cache.as_ref()?;
+        let value = match cache.get(&self.get_cache_key(key)).await {
+            Some(cached_data) => serde_json::from_str(&cached_data).ok(),
+            None => None,
+        };
+        self.metrics.record_cache_lookup(value.is_some());
+        value
+    }
+
+    /// Stores a value in the cache, if one is configured. Cache failures are ignored.
+    async fn cache_put<T: serde::Serialize>(&self, key: &str, value: &T, ttl_secs: u64) {
+        if let Some(cache) = &self.cache
+            && let Ok(json) = serde_json::to_string(value)
+        {
+            let _ = cache.set(&self.get_cache_key(key), &json, ttl_secs).await;
+        }
+    }
+
+    /// Drops cached data that a submission or deletion in a topic makes stale.
+    async fn invalidate_topic(&self, course_id: u64, topic_id: u64) {
+        if let Some(cache) = &self.cache {
+            let keys = [
+                self.period_key(&format!("topic_detail:{}:{}", course_id, topic_id)),
+                self.period_key(&format!("course_summary:{}", course_id)),
+            ];
+            for key in keys {
+                let _ = cache.delete(&self.get_cache_key(&key)).await;
+            }
+        }
+    }
+
+    /// Returns the session cookies as `(domain, "name=value")` pairs, one per cookie.
+    ///
+    /// Covers the SPOT and SSO hosts. Together with
+    /// [`import_cookies`](Self::import_cookies) this lets a session be stored anywhere
+    /// (a database, a browser extension) instead of the files written by
+    /// [`save_cookies`](Self::save_cookies).
+    pub fn export_cookies(&self) -> Vec<(String, String)> {
+        let mut cookies = Vec::new();
+        for url in self.cookie_urls() {
+            let Some(domain) = url.host_str() else {
+                continue;
+            };
+            if let Some(header) = self.cookie_jar.cookies(&url) {
+                for pair in header.to_str().unwrap_or_default().split(';') {
+                    let pair = pair.trim();
+                    if !pair.is_empty() {
+                        cookies.push((domain.to_string(), pair.to_string()));
+                    }
+                }
+            }
+        }
+        cookies
+    }
+
+    /// Adds cookies in the form returned by [`export_cookies`](Self::export_cookies).
+    ///
+    /// The cookie may also carry `Set-Cookie` attributes (`Path`, `Expires`, ...).
+    /// Pairs whose domain is neither the SPOT nor the SSO host are ignored.
+    pub fn import_cookies(&self, cookies: &[(String, String)]) {
+        let urls = self.cookie_urls();
+        for (domain, cookie) in cookies {
+            if let Some(url) = urls.iter().find(|url| url.host_str() == Some(domain)) {
+                self.cookie_jar.add_cookie_str(cookie, url);
+            }
+        }
+    }
+
+    /// The URLs whose cookies make up the session: SPOT, then SSO's CAS path.
+    ///
+    /// CAS scopes its ticket-granting cookie to `/cas`, so the SSO root would miss it.
+    /// If both share a host, only SPOT's is returned.
+    fn cookie_urls(&self) -> Vec<reqwest::Url> {
+        let spot_url: reqwest::Url = self.base_url.parse().unwrap();
+        let sso_url = self.sso_cookie_url();
+        if spot_url.host_str() == sso_url.host_str() {
+            vec![spot_url]
+        } else {
+            vec![spot_url, sso_url]
+        }
+    }
+
+    /// SSO's CAS path, where its cookies are read and restored.
+    fn sso_cookie_url(&self) -> reqwest::Url {
+        format!("{}/cas/", self.sso_url).parse().unwrap()
+    }
+
+    /// Saves the current session cookies to a JSON file.
+    ///
+    /// This allows for session persistence across different runs of the application.
+    /// Use [`save_session`](Self::save_session) to also keep the active period.
+    pub async fn save_cookies(&self, path: &std::path::Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.cookie_map()).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to serialize cookies: {}", e))
+        })?;
+
+        tokio::fs::write(path, json).await.map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to write cookie file: {}", e))
+        })?;
+
+        Ok(())
+    }
+
+    /// Loads session cookies from a JSON file.
+    ///
+    /// This populates the internal cookie jar, allowing the client to resume a session
+    /// without re-authenticating through SSO.
+    pub async fn load_cookies(&self, path: &std::path::Path) -> Result<()> {
+        let json = tokio::fs::read_to_string(path).await.map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to read cookie file: {}", e))
+        })?;
+
+        let cookie_map: HashMap<String, String> = serde_json::from_str(&json).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to deserialize cookies: {}", e))
+        })?;
+
+        self.restore_cookies(&cookie_map);
+        Ok(())
+    }
+
+    /// Saves the session cookies together with the active academic period.
+    ///
+    /// The period is the one last set through [`change_period`](Self::change_period) (or a
+    /// restored session). The file carries a schema version so the format can evolve.
+    pub async fn save_session(&self, path: &std::path::Path) -> Result<()> {
+        let session = SavedSession {
+            version: SESSION_SCHEMA_VERSION,
+            cookies: self.cookie_map(),
+            period: self.current_period(),
+        };
+
+        let json = serde_json::to_string_pretty(&session).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to serialize session: {}", e))
+        })?;
+
+        tokio::fs::write(path, json)
+            .await
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to write session file: {}", e)))
+    }
+
+    /// Restores a session written by [`save_session`](Self::save_session).
+    ///
+    /// Period selection lives on SPOT's side of the session, so if the saved period differs
+    /// from the one this client last set, it is switched again with `change_period`.
+    /// Otherwise a restored session could silently serve another semester's data.
+    pub async fn load_session(&self, path: &std::path::Path) -> Result<()> {
+        let json = tokio::fs::read_to_string(path).await.map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to read session file: {}", e))
+        })?;
+
+        let session: SavedSession = serde_json::from_str(&json).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to deserialize session: {}", e))
+        })?;
+        if session.version != SESSION_SCHEMA_VERSION {
+            return Err(ScraperError::ParsingError(format!(
+                "Unsupported session file version {} (expected {})",
+                session.version, SESSION_SCHEMA_VERSION
+            )));
+        }
+
+        self.restore_cookies(&session.cookies);
+
+        if let Some(period) = session.period
+            && self.current_period() != Some(period)
+        {
+            self.change_period(period.year, period.semester).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns a snapshot of the request counters shared by this client and its clones.
+    pub fn metrics(&self) -> ClientMetrics {
+        self.metrics.snapshot()
+    }
+
+    /// Returns the academic period last set through this client, if any.
+    pub fn current_period(&self) -> Option<Period> {
+        *self.current_period.lock().unwrap()
+    }
+
+    fn cookie_map(&self) -> HashMap<String, String> {
+        let spot_url = self.base_url.parse().unwrap();
+        let sso_url = self.sso_cookie_url();
+
+        let mut cookie_map = HashMap::new();
+        if let Some(c) = self.cookie_jar.cookies(&spot_url) {
+            cookie_map.insert(
+                "spot".to_string(),
+                c.to_str().unwrap_or_default().to_string(),
+            );
+        }
+        if let Some(c) = self.cookie_jar.cookies(&sso_url) {
+            cookie_map.insert(
+                "sso".to_string(),
+                c.to_str().unwrap_or_default().to_string(),
+            );
+        }
+        cookie_map
+    }
+
+    fn restore_cookies(&self, cookie_map: &HashMap<String, String>) {
+        let spot_url = self.base_url.parse().unwrap();
+        let sso_url = self.sso_cookie_url();
+
+        if let Some(c) = cookie_map.get("spot") {
+            for cookie in c.split(';') {
+                self.cookie_jar.add_cookie_str(cookie.trim(), &spot_url);
+            }
+        }
+        if let Some(c) = cookie_map.get("sso") {
+            for cookie in c.split(';') {
+                self.cookie_jar.add_cookie_str(cookie.trim(), &sso_url);
+            }
+        }
+    }
+
+    /// Sets a new delay configuration for the client.
+    pub fn set_delay_config(&mut self, config: DelayConfig) {
+        self.delay_config = config;
+    }
+
+    /// Draws request delays from an RNG seeded with `seed` instead of the thread-local one.
+    ///
+    /// Meant for tests: with the same seed and sequence of requests, every run sleeps for
+    /// exactly the same durations (see [`DelayConfig::compute_delay`]). Clones made
+    /// afterwards share the RNG. User-Agent rotation and retry backoff stay random.
+    pub fn set_rng_seed(&mut self, seed: u64) {
+        self.delay_rng = Some(Arc::new(Mutex::new(StdRng::seed_from_u64(seed))));
+    }
+
+    /// Enables or disables dry-run mode (disabled by default).
+    ///
+    /// In dry-run mode no request reaches SPOT or SSO, not even logins or task
+    /// submissions. Each request is logged instead (see
+    /// [`dry_run_requests`](Self::dry_run_requests), and a `tracing` event with the
+    /// `tracing` feature) and answered with the page set by
+    /// [`set_dry_run_fixture`](Self::set_dry_run_fixture), or fails with
+    /// `ScraperError::DryRun` if there is none. Delays between requests are skipped.
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+    }
+
+    /// Sets the page returned (as a 200 at the requested URL) for every request made in
+    /// dry-run mode.
+    pub fn set_dry_run_fixture(&mut self, html: &str) {
+        self.dry_run_fixture = Some(Arc::from(html));
+    }
+
+    /// Returns the requests logged in dry-run mode so far, oldest first.
+    ///
+    /// Form fields are included with passwords redacted.
+    pub fn dry_run_requests(&self) -> Vec<DryRunRequest> {
+        self.dry_run_log.lock().unwrap().clone()
+    }
+
+    /// Installs a shared `RateLimiter` ("polite mode").
+    ///
+    /// Every client holding the same limiter waits for its turn before each request,
+    /// so the combined request rate stays under the limiter's ceiling no matter how
+    /// many clients or concurrent tasks share the session.
+    pub fn set_rate_limiter(&mut self, limiter: Arc<RateLimiter>) {
+        self.rate_limiter = Some(limiter);
+    }
+
+    /// Caps the aggregate request rate of this client and its clones.
+    ///
+    /// Shorthand for installing a fresh [`RateLimiter::per_second`]. Clones made
+    /// afterwards share it, so concurrent batch and stream calls on any of them count
+    /// against the same ceiling. `DelayConfig` jitter still applies on top.
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is not a positive, finite number.
+    pub fn set_requests_per_second(&mut self, requests_per_second: f64) {
+        self.set_rate_limiter(Arc::new(RateLimiter::per_second(requests_per_second)));
+    }
+
+    /// Enables automatic retries of transient failures (disabled by default).
+    ///
+    /// GET requests are retried on timeouts, connection errors and the statuses in
+    /// `RetryConfig::retry_on_status`. Logins and task submissions are only retried if
+    /// `RetryConfig::retry_non_idempotent` is set.
+    pub fn set_retry_config(&mut self, config: RetryConfig) {
+        self.retry_config = Some(config);
+    }
+
+    /// Enables automatic re-login when a page fetch finds the session expired.
+    ///
+    /// After logging in again, the period last selected with `change_period` is selected
+    /// again before the request is repeated. See [`AutoReloginConfig`] for how the
+    /// credentials are kept.
+    pub fn set_auto_relogin(&mut self, config: AutoReloginConfig) {
+        self.auto_relogin = Some(config);
+    }
+
+    /// Caps the combined size of the files attached to one submission (default: 10 MiB).
+    ///
+    /// Larger uploads fail with `ScraperError::UploadTooLarge` before anything is sent.
+    pub fn set_max_upload_bytes(&mut self, limit: u64) {
+        self.max_upload_bytes = limit;
+    }
+
+    /// Restricts task uploads to the given file types, e.g. [`DEFAULT_UPLOAD_TYPES`](crate::DEFAULT_UPLOAD_TYPES).
+    ///
+    /// Types are given as extensions (`"pdf"`, `"jpg"`) and checked against each file's
+    /// contents rather than its name; files without a known signature fall back to the
+    /// name's extension. Anything else fails with `ScraperError::UnsupportedFileType`
+    /// before the upload starts. By default every type is accepted.
+    pub fn set_allowed_upload_types(&mut self, extensions: &[&str]) {
+        self.allowed_upload_types = Some(normalize_extensions(extensions));
+    }
+
+    /// Caps how many pages of a paginated course list `get_courses` follows (default: 10).
+    ///
+    /// Courses on pages past the cap are silently left out; the cap only guards against
+    /// pagination links that never end.
+    pub fn set_max_course_pages(&mut self, max_pages: usize) {
+        self.max_course_pages = max_pages;
+    }
+
+    /// Sets whether JSON endpoints are tried before scraping HTML (default: `true`).
+    ///
+    /// When a JSON endpoint is missing (404, a redirect elsewhere, or a body that is not
+    /// the expected JSON) the client falls back to HTML scraping and stops trying that
+    /// endpoint for the rest of its lifetime. Transient failures only fall back once.
+    pub fn set_prefer_json(&mut self, prefer_json: bool) {
+        self.prefer_json = prefer_json;
+    }
+
+    /// Enables recording mode: every request made afterwards is written to `dir` as a
+    /// [`Recording`], keyed by a hash of its method, URL and body.
+    ///
+    /// The SSO password is redacted before anything touches the disk, but recordings
+    /// still contain session-bound pages (profile, grades, CSRF tokens), so treat the
+    /// directory as private.
+    pub fn set_recording_dir<P: AsRef<Path>>(&mut self, dir: P) {
+        self.recording_dir = Some(dir.as_ref().to_path_buf());
+    }
+
+    /// Enables or disables the automatic `Referer` header (enabled by default).
+    ///
+    /// When enabled, each request carries the URL of the previously fetched page, the
+    /// way a browser does while navigating, e.g. a topic is requested with its course
+    /// page as referer. The referer is only sent to the host it came from, so SSO URLs
+    /// never leak to SPOT and vice versa. Clones share the navigation history.
+    pub fn set_send_referer(&mut self, send_referer: bool) {
+        self.send_referer = send_referer;
+    }
+
+    /// Enables or disables body snippets in `ScraperError::HttpStatus` (disabled by default).
+    ///
+    /// Error pages can echo the student's name, NIM or other personal data, and errors
+    /// tend to end up in logs, so `body_snippet` stays empty unless this is enabled.
+    pub fn set_error_body_snippets(&mut self, enabled: bool) {
+        self.error_body_snippets = enabled;
+    }
+
+    /// Builds `ScraperError::HttpStatus`, keeping the body only if snippets are enabled.
+    fn http_status_error(&self, status: u16, url: &str, body: &str) -> ScraperError {
+        let body = if self.error_body_snippets { body } else { "" };
+        ScraperError::http_status(status, url, body)
+    }
+
+    /// Adds the `Referer` header for a navigation to `url`, if enabled and same-host.
+    fn with_referer(&self, request: reqwest::RequestBuilder, url: &str) -> reqwest::RequestBuilder {
+        if !self.send_referer {
+            return request;
+        }
+
+        let last_page = self.last_page.lock().unwrap().clone();
+        match (last_page, reqwest::Url::parse(url)) {
+            (Some(previous), Ok(target))
+                if previous.host_str() == target.host_str() && previous != target =>
+            {
+                request.header(REFERER, previous.as_str())
+            }
+            _ => request,
+        }
+    }
+
+    /// Remembers the final URL of a response as the page the "browser" is now on.
+    fn remember_page(&self, response: &reqwest::Response) {
+        *self.last_page.lock().unwrap() = Some(response.url().clone());
+    }
+
+    /// Picks the User-Agent for the next request according to the rotation strategy.
+    fn next_user_agent(&self) -> &str {
+        let request_number = self.requests_sent.fetch_add(1, Ordering::Relaxed);
+        self.user_agents.pick(request_number, &mut rand::rng())
+    }
+
+    /// Draws a delay with the RNG seeded by `set_rng_seed`, or the thread-local one.
+    fn draw_delay(&self, draw: impl FnOnce(&mut dyn rand::RngCore) -> Duration) -> Duration {
+        match &self.delay_rng {
+            Some(rng) => draw(&mut *rng.lock().unwrap()),
+            None => draw(&mut rand::rng()),
+        }
+    }
+
+    /// Pauses after a successful login, like a person waiting for the dashboard.
+    ///
+    /// The pause is drawn for `DelayAction::Login` when the `DelayProfile` has a login
+    /// range and lasts 2-5 s otherwise. Like request delays, it is skipped when delays
+    /// are disabled and follows `set_rng_seed`.
+    async fn pause_after_login(&self) {
+        if self.dry_run || !self.delay_config.enabled {
+            return;
+        }
+        let pause = self.draw_delay(|rng| match self.delay_config.profile.login {
+            Some(_) => self.delay_config.compute_delay(DelayAction::Login, rng),
+            None => Duration::from_millis(rng.random_range(2000..=5000)),
+        });
+        sleep(pause).await;
+    }
+
+    /// Waits for a random duration drawn for `action` from the current `DelayConfig`,
+    /// then for a slot from the shared `RateLimiter`, if one is installed.
+    async fn wait_random(&self, action: DelayAction) {
+        if self.dry_run {
+            return;
+        }
+        let delay = self.draw_delay(|rng| self.delay_config.compute_delay(action, rng));
+        if !delay.is_zero() {
+            sleep(delay).await;
+        }
+
+        if let Some(limiter) = &self.rate_limiter {
+            limiter.acquire().await;
+        }
+    }
+
+    /// Sends a request built by `send`, retrying transient failures if a `RetryConfig`
+    /// is installed.
+    ///
+    /// A 429 response is always turned into `ScraperError::RateLimited`. Timeouts,
+    /// connection errors, rate limiting and statuses in `RetryConfig::retry_on_status`
+    /// are retried with jittered exponential backoff, or after the `Retry-After` delay
+    /// when SPOT sends one. Requests that are not idempotent are
+    /// only retried if `RetryConfig::retry_non_idempotent` is set. Once the retries run
+    /// out, the last failure is returned wrapped in `ScraperError::RetriesExhausted`.
+    async fn send_with_retry<F, Fut>(
+        &self,
+        idempotent: bool,
+        mut send: F,
+    ) -> Result<reqwest::Response>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<reqwest::Response>>,
+    {
+        let config = match &self.retry_config {
+            Some(config) if idempotent || config.retry_non_idempotent => config,
+            _ => return send().await.and_then(reject_rate_limited),
+        };
+
+        let mut retry = 0;
+        loop {
+            let err = match send().await.and_then(reject_rate_limited) {
+                Ok(response) => {
+                    let status = response.status().as_u16();
+                    if !config.retry_on_status.contains(&status) {
+                        return Ok(response);
+                    }
+                    let url = response.url().to_string();
+                    match response_text(response).await {
+                        Ok(body) => self.http_status_error(status, &url, &body),
+                        Err(err) if err.is_retryable() => err,
+                        Err(err) => return Err(err),
+                    }
+                }
+                Err(err) if err.is_retryable() => err,
+                Err(err) => return Err(err),
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(retry, error = %err, "transient failure");
+
+            if retry >= config.max_retries {
+                return Err(ScraperError::RetriesExhausted {
+                    attempts: retry + 1,
+                    last: Box::new(err),
+                });
+            }
+            let backoff = config.jittered_backoff(retry, &mut rand::rng());
+            match retry::delay_before_retry(&err, backoff, config) {
+                Some(delay) => sleep(delay).await,
+                None => return Err(err),
+            }
+            retry += 1;
+            self.metrics.record_retry();
+        }
+    }
+
+    /// Builds `request` and sends it through the configured `HttpTransport`.
+    ///
+    /// Every request sent goes through here, so this is where the request and failure
+    /// counters of [`metrics`](Self::metrics) are updated and the body is wrapped to
+    /// count downloaded bytes.
+    async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        let request = request.build()?;
+        if self.dry_run {
+            return self.dry_run_response(request);
+        }
+        self.metrics.record_request();
+        let sent = Instant::now();
+        let response = match self.transport.execute(request).await {
+            Ok(response) => response,
+            Err(err) => {
+                self.metrics.record_failure(err.kind());
+                return Err(err);
+            }
+        };
+
+        let status = response.status();
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            self.metrics.record_failure("rate_limited");
+        } else if status.is_client_error() || status.is_server_error() {
+            self.metrics.record_failure("http_status");
+        }
+
+        let url = response.url().clone();
+        let (parts, body) = http::Response::from(response).into_parts();
+        let mut counted = http::Response::builder()
+            .status(parts.status)
+            .version(parts.version)
+            .url(url)
+            .extension(SentAt(sent));
+        if let Some(headers) = counted.headers_mut() {
+            *headers = parts.headers;
+        }
+        counted
+            .body(self.metrics.count_body(body))
+            .map(reqwest::Response::from)
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to rebuild response: {}", e)))
+    }
+
+    /// Logs `request` instead of sending it and answers with the dry-run fixture.
+    fn dry_run_response(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let is_form = request
+            .headers()
+            .get(CONTENT_TYPE)
+            .is_some_and(|value| value == "application/x-www-form-urlencoded");
+        let planned = DryRunRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            form: request
+                .body()
+                .and_then(|body| body.as_bytes())
+                .filter(|_| is_form)
+                .map(recording::redacted_form_pairs)
+                .unwrap_or_default(),
+        };
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(
+            method = %planned.method,
+            url = %planned.url,
+            form = ?planned.form,
+            "dry run: request not sent"
+        );
+
+        self.dry_run_log.lock().unwrap().push(planned);
+
+        let Some(fixture) = &self.dry_run_fixture else {
+            return Err(ScraperError::DryRun {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+            });
+        };
+        http::Response::builder()
+            .status(200)
+            .url(request.url().clone())
+            .body(fixture.to_string())
+            .map(reqwest::Response::from)
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to build response: {}", e)))
+    }
+
+    /// Helper to perform a GET request with randomized delay and rotated User-Agent.
+    async fn get_request(&self, action: DelayAction, url: &str) -> Result<reqwest::Response> {
+        let response = self
+            .send_with_retry(true, || async move {
+                self.wait_random(action).await;
+                let ua = self.next_user_agent();
+                self.execute(self.with_referer(self.client.get(url).header(USER_AGENT, ua), url))
+                    .await
+            })
+            .await?;
+        self.remember_page(&response);
+        self.record("GET", url, None, response).await
+    }
+
+    /// Helper to perform a POST request with randomized delay and rotated User-Agent.
+    async fn post_request<T: serde::Serialize + ?Sized>(
+        &self,
+        action: DelayAction,
+        url: &str,
+        form: &T,
+    ) -> Result<reqwest::Response> {
+        let response = self
+            .send_with_retry(false, || async move {
+                self.wait_random(action).await;
+                let ua = self.next_user_agent();
+                self.execute(
+                    self.with_referer(self.client.post(url).header(USER_AGENT, ua), url)
+                        .form(form),
+                )
+                .await
+            })
+            .await?;
+        self.remember_page(&response);
+        let request_body = match self.recording_dir {
+            Some(_) => recording::redacted_form(form),
+            None => None,
+        };
+        self.record("POST", url, request_body, response).await
+    }
+
+    /// Writes the exchange to the recording directory, if recording mode is enabled.
+    ///
+    /// The body has to be consumed to be recorded, so an equivalent response is rebuilt
+    /// from the buffered bytes for the caller. Every exchange passes through here, so it
+    /// is also where requests are traced.
+    async fn record(
+        &self,
+        method: &str,
+        url: &str,
+        request_body: Option<String>,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            method,
+            url = %recording::redact_url(url),
+            final_url = %recording::redact_url(response.url().as_str()),
+            status = response.status().as_u16(),
+            elapsed_ms = sent_at(&response).elapsed().as_millis() as u64,
+            "SPOT request finished"
+        );
+
+        let Some(dir) = &self.recording_dir else {
+            return Ok(response);
+        };
+
+        let status = response.status();
+        let final_url = response.url().clone();
+        let headers = response.headers().clone();
+        let sent = sent_at(&response);
+        let bytes = response
+            .bytes()
+            .await
+            .map_err(|e| ScraperError::from_request(e, url, sent))?;
+
+        Recording {
+            method: method.to_string(),
+            url: url.to_string(),
+            request_body,
+            status: status.as_u16(),
+            final_url: final_url.to_string(),
+            response_body: String::from_utf8_lossy(&bytes).into_owned(),
+        }
+        .save(dir)
+        .await?;
+
+        let mut rebuilt = http::Response::builder()
+            .status(status)
+            .url(final_url)
+            .extension(SentAt(sent));
+        if let Some(rebuilt_headers) = rebuilt.headers_mut() {
+            *rebuilt_headers = headers;
+        }
+        rebuilt
+            .body(bytes)
+            .map(reqwest::Response::from)
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to rebuild response: {}", e)))
+    }
+
+    /// Logs into SPOT using a student ID (NIM) and password through the SSO system.
+    ///
+    /// This involves a three-step process:
+    /// 1. Fetching the SSO login page to retrieve the execution token.
+    /// 2. Posting credentials to the SSO service.
+    /// 3. Validating the final redirection back to the SPOT platform.
+    ///
+    /// Returns `ScraperError::MissingCredentials` without touching the network if
+    /// either the NIM or the password is empty.
+    /// If SSO rejects the NIM/password it shows an error banner, reported as
+    /// `ScraperError::InvalidCredentials` with the banner's message. A 5xx from SSO is
+    /// `ScraperError::SsoUnavailable` (retryable). Any other unexpected landing page is
+    /// `ScraperError::AuthenticationFailed`, which reports the step that failed along
+    /// with its HTTP status and final URL.
+    /// If the credentials are valid but SSO forces a password change first,
+    /// `ScraperError::PasswordChangeRequired` is returned instead.
+    /// If SSO asks for a one-time password, `ScraperError::TwoFactorRequired` is
+    /// returned and the login is finished by calling [`submit_otp`](Self::submit_otp).
+    ///
+    /// With the `tracing` feature, the login runs inside a `login` span that records the
+    /// NIM and the outcome. The password is never logged.
+    pub async fn login(&self, nim: &str, password: &str) -> Result<()> {
+        #[cfg(feature = "tracing")]
+        {
+            use tracing::Instrument;
+
+            let span = tracing::info_span!("login", nim = %nim.trim());
+            let result = self
+                .login_inner(nim, password)
+                .instrument(span.clone())
+                .await;
+            match &result {
+                Ok(()) => tracing::info!(parent: &span, "login succeeded"),
+                Err(err) => tracing::warn!(parent: &span, error = %err, "login failed"),
+            }
+            result
+        }
+
+        #[cfg(not(feature = "tracing"))]
+        self.login_inner(nim, password).await
+    }
+
+    /// Logs in like [`login`](Self::login), taking the NIM and password from `credentials`.
+    pub async fn login_with(&self, credentials: &Credentials) -> Result<()> {
+        self.login(&credentials.nim, credentials.password()).await
+    }
+
+    async fn login_inner(&self, nim: &str, password: &str) -> Result<()> {
+        self.authenticate(nim, password).await?;
+        // Kept even while auto re-login is off, so `RememberLogin` can be enabled later.
+        *self.remembered_login.lock().unwrap() = Some(Credentials::new(nim, password));
+        Ok(())
+    }
+
+    /// Ends the current session.
+    ///
+    /// Visits the SSO logout endpoint so the ticket-granting cookie is invalidated on the
+    /// server, then clears the cookie jar and everything remembered about the session
+    /// (active period, pending OTP challenge, credentials kept for auto re-login). After
+    /// this, authenticated calls such as [`get_user_profile`](Self::get_user_profile) fail
+    /// with [`ScraperError::SessionExpired`] until the next [`login`](Self::login).
+    ///
+    /// If a [`cache_prefix`](SpotifierCoreClientBuilder::cache_prefix) is set, the cached
+    /// entries under it are dropped too, so a per-user prefix keeps the old session's
+    /// pages from being served. Without a prefix the cache may be shared with other
+    /// clients or applications, so it is left untouched.
+    ///
+    /// Calling it without a session is a no-op. Local state is cleared even if the logout
+    /// request itself fails; that error is still returned.
+    pub async fn logout(&self) -> Result<()> {
+        if self.cookie_map().is_empty() {
+            return Ok(());
+        }
+
+        let logout_url = format!("{}/cas/logout", self.sso_url);
+        let result = self.get_request(DelayAction::Login, &logout_url).await;
+
+        self.cookie_jar.clear();
+        *self.remembered_login.lock().unwrap() = None;
+        *self.pending_otp.lock().unwrap() = None;
+        *self.current_period.lock().unwrap() = None;
+        *self.last_page.lock().unwrap() = None;
+        if let (Some(cache), Some(prefix)) = (&self.cache, &self.cache_prefix) {
+            let _ = cache.delete_prefix(&format!("{}:", prefix)).await;
+        }
+
+        result.map(|_| ())
+    }
+
+    /// Runs the SSO login flow described on [`login`](Self::login).
+    async fn authenticate(&self, nim: &str, password: &str) -> Result<()> {
+        if nim.trim().is_empty() {
+            return Err(ScraperError::MissingCredentials("NIM"));
+        }
+        if password.is_empty() {
+            return Err(ScraperError::MissingCredentials("password"));
+        }
+
+        // Step 1: GET the login page to retrieve the "execution" token
+        let login_page_url = format!(
+            "{}/cas/login?service={}/beranda",
+            self.sso_url, self.base_url
+        );
+        let response = self
+            .get_request(DelayAction::Login, &login_page_url)
+            .await?;
+
+        // The page URL after redirects, which the form's action is relative to
+        let form_page_url = response.url().clone();
+
+        if response.status().is_server_error() {
+            return Err(ScraperError::SsoUnavailable {
+                status: response.status().as_u16(),
+            });
+        }
+        if !response.status().is_success() {
+            return Err(ScraperError::AuthenticationFailed {
+                step: "GET login page",
+                status: response.status().as_u16(),
+                final_url: form_page_url.to_string(),
+            });
+        }
+
+        let login_page = response_text(response).await?;
+        let login_form = parsers::sso::parse_cas_login_form(&login_page)?;
+
+        // Step 2: POST credentials, with the form's hidden fields, to where the form posts.
+        // The form is only trusted to post to SSO itself, never to another host.
+        let post_url = match &login_form.action {
+            Some(action) => form_page_url.join(action).map_err(|e| {
+                ScraperError::ParsingError(format!("Invalid CAS login form action: {}", e))
+            })?,
+            None => form_page_url.clone(),
+        };
+        if !self.is_sso_url(&post_url) {
+            return Err(ScraperError::ParsingError(format!(
+                "CAS login form posts to another host: {}",
+                recording::redact_url(post_url.as_str())
+            )));
+        }
+
+        let mut params: Vec<(&str, &str)> = login_form
+            .hidden_fields
+            .iter()
+            .filter(|(name, _)| name != "username" && name != "password")
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        if !params.iter().any(|(name, _)| *name == "_eventId") {
+            params.push(("_eventId", "submit"));
+        }
+        params.push(("username", nim));
+        params.push(("password", password));
+
+        let response = self
+            .post_request(DelayAction::Login, post_url.as_str(), &params)
+            .await?;
+
+        // Simulate human processing time after successful login
+        self.pause_after_login().await;
+
+        // Step 3: Verify the final redirection to SPOT
+        let final_url = response.url().clone();
+        if !self.is_spot_url(&final_url) {
+            let status = response.status().as_u16();
+            if response.status().is_server_error() {
+                return Err(ScraperError::SsoUnavailable { status });
+            }
+            let body = response_text(response).await?;
+            if parsers::login::is_password_change_page(final_url.as_str(), &body) {
+                return Err(ScraperError::PasswordChangeRequired);
+            }
+            if let Some(form) = parsers::login::parse_otp_challenge(final_url.as_str(), &body) {
+                let challenge_url = final_url.to_string();
+                *self.pending_otp.lock().unwrap() = Some((final_url, form));
+                return Err(ScraperError::TwoFactorRequired { challenge_url });
+            }
+            if let Some(message) = parsers::login::parse_cas_error(&body) {
+                return Err(ScraperError::InvalidCredentials(message));
+            }
+
+            return Err(ScraperError::AuthenticationFailed {
+                step: "POST credentials",
+                status,
+                final_url: final_url.to_string(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Completes a login that stopped at an OTP/2FA challenge.
+    ///
+    /// Posts `code` to the challenge form returned by the last [`login`](Self::login)
+    /// that failed with `ScraperError::TwoFactorRequired`, then verifies that SSO
+    /// redirected back to SPOT. If the code is rejected, the challenge stays pending
+    /// and this can be called again with a new code.
+    pub async fn submit_otp(&self, code: &str) -> Result<()> {
+        let code = code.trim();
+        if code.is_empty() {
+            return Err(ScraperError::MissingCredentials("OTP code"));
+        }
+
+        let (challenge_url, form) =
+            self.pending_otp.lock().unwrap().clone().ok_or_else(|| {
+                ScraperError::ElementNotFound("Pending OTP challenge".to_string())
+            })?;
+
+        let action_url = match &form.action {
+            Some(action) => challenge_url.join(action).map_err(|e| {
+                ScraperError::ParsingError(format!("Invalid OTP form action: {}", e))
+            })?,
+            None => challenge_url.clone(),
+        };
+
+        let mut params: Vec<(&str, &str)> = form
+            .hidden_fields
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        params.push((form.code_field.as_str(), code));
+
+        let response = self
+            .post_request(DelayAction::Login, action_url.as_str(), &params)
+            .await?;
+        let final_url = response.url().clone();
+        if !self.is_spot_url(&final_url) {
+            let status = response.status().as_u16();
+            let body = response_text(response).await?;
+            // A rejected code shows the challenge again, with a fresh execution token.
+            if let Some(form) = parsers::login::parse_otp_challenge(final_url.as_str(), &body) {
+                *self.pending_otp.lock().unwrap() = Some((final_url.clone(), form));
+            }
+            return Err(ScraperError::AuthenticationFailed {
+                step: "POST OTP",
+                status,
+                final_url: final_url.to_string(),
+            });
+        }
+
+        *self.pending_otp.lock().unwrap() = None;
+        Ok(())
+    }
+
+    /// Returns `true` if `url` is on the SPOT host (as opposed to SSO).
+    fn is_spot_url(&self, url: &reqwest::Url) -> bool {
+        let spot_host = reqwest::Url::parse(&self.base_url)
+            .ok()
+            .and_then(|url| url.host_str().map(String::from));
+        url.host_str() == spot_host.as_deref()
+    }
+
+    /// Returns whether `url` points at the SSO (CAS) server.
+    fn is_sso_url(&self, url: &reqwest::Url) -> bool {
+        reqwest::Url::parse(&self.sso_url).is_ok_and(|sso| sso.host_str() == url.host_str())
+    }
+
+    /// Internal helper to fetch HTML content from a specific path.
+    ///
+    /// The session counts as expired if the request ended up on SSO, or if SPOT answered
+    /// with the CAS login form (see [`parsers::login::is_session_expired_page`]).
+    /// Redirects within SPOT are followed
+    /// as normal pages; any other non-2xx response is `ScraperError::HttpStatus`. Paths
+    /// that resolve to a host other than SPOT or SSO are refused with
+    /// `ScraperError::ParsingError` before anything is sent.
+    ///
+    /// If auto re-login is enabled, an expired session is renewed once and the page
+    /// fetched again.
+    async fn get_html(&self, path: &str) -> Result<String> {
+        let generation = self.session_generation.load(Ordering::SeqCst);
+        match self.fetch_html(path).await {
+            Err(ScraperError::SessionExpired) if self.auto_relogin.is_some() => {
+                self.relogin(generation).await?;
+                self.fetch_html(path).await
+            }
+            result => result,
+        }
+    }
+
+    /// Logs in again with the credentials from the `AutoReloginConfig`, then restores
+    /// the active period. Returns `SessionExpired` if no credentials are available.
+    ///
+    /// `seen` is the session generation the failed request started with. Concurrent
+    /// callers wait for each other, and those that find the generation moved on reuse
+    /// the session the first one established instead of logging in again.
+    async fn relogin(&self, seen: u64) -> Result<()> {
+        // Restoring the period below fetches pages, which may find the fresh session
+        // expired again; give up then instead of waiting on our own lock.
+        if RESTORING_PERIOD.try_with(|_| ()).is_ok() {
+            return Err(ScraperError::SessionExpired);
+        }
+        let _flight = self.relogin_lock.lock().await;
+        if self.session_generation.load(Ordering::SeqCst) != seen {
+            return Ok(());
+        }
+
+        let credentials = match &self.auto_relogin {
+            Some(AutoReloginConfig::RememberLogin) => self.remembered_login.lock().unwrap().clone(),
+            Some(AutoReloginConfig::Provider(provider)) => {
+                provider().map(|(nim, password)| Credentials::new(nim, password))
+            }
+            None => None,
+        };
+        let credentials = credentials.ok_or(ScraperError::SessionExpired)?;
+
+        self.login_with(&credentials).await?;
+        self.session_generation.fetch_add(1, Ordering::SeqCst);
+        if let Some(period) = self.current_period() {
+            // Boxed because `change_period` reads the course list through `get_html`.
+            RESTORING_PERIOD
+                .scope(
+                    (),
+                    Box::pin(self.change_period(period.year, period.semester)),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn fetch_html(&self, path: &str) -> Result<String> {
+        let url = self.resolve_href(path);
+        let parsed = reqwest::Url::parse(&url)
+            .map_err(|e| ScraperError::ParsingError(format!("Invalid page URL {}: {}", path, e)))?;
+        if !self.is_spot_url(&parsed) && !self.is_sso_url(&parsed) {
+            return Err(ScraperError::ParsingError(format!(
+                "Refusing to fetch a page outside SPOT: {}",
+                recording::redact_url(&url)
+            )));
+        }
+        let response = self.get_request(DelayAction::Navigation, &url).await?;
+
+        if self.is_sso_url(response.url()) {
+            return Err(ScraperError::SessionExpired);
+        }
+
+        let status = response.status();
+        let final_url = response.url().to_string();
+        let html = response_text(response).await?;
+
+        if !status.is_success() {
+            return Err(self.http_status_error(status.as_u16(), &final_url, &html));
+        }
+        if parsers::login::is_session_expired_page(&html) {
+            return Err(ScraperError::SessionExpired);
+        }
+        Ok(html)
+    }
+
+    /// Checks that SPOT is reachable and the session is logged in, with a single GET of
+    /// the dashboard (`/mhs`).
+    ///
+    /// Network errors, timeouts (also while reading the page) and 5xx responses count as
+    /// unreachable rather than errors. A reachable SPOT is authenticated when the
+    /// dashboard shows the student profile; a redirect to SSO or the login form means it
+    /// is not. The active period is read from the same page's course list. Nothing is
+    /// cached.
+    pub async fn health_check(&self) -> Result<HealthStatus> {
+        let mut status = HealthStatus {
+            reachable: false,
+            authenticated: false,
+            active_period: None,
+        };
+
+        let url = format!("{}/mhs", self.base_url);
+        let response = match self.get_request(DelayAction::Navigation, &url).await {
+            Ok(response) => response,
+            Err(
+                ScraperError::RequestError(_)
+                | ScraperError::Timeout { .. }
+                | ScraperError::RetriesExhausted { .. },
+            ) => return Ok(status),
+            Err(ScraperError::HttpStatus { status: code, .. }) if code >= 500 => {
+                return Ok(status);
+            }
+            Err(e) => return Err(e),
+        };
+        if response.status().is_server_error() {
+            return Ok(status);
+        }
+        status.reachable = true;
+
+        if self.is_sso_url(response.url()) || !response.status().is_success() {
+            return Ok(status);
+        }
+        let html = match response_text(response).await {
+            Ok(html) => html,
+            Err(ScraperError::RequestError(_) | ScraperError::Timeout { .. }) => {
+                status.reachable = false;
+                return Ok(status);
+            }
+            Err(e) => return Err(e),
+        };
+        status.authenticated = !parsers::login::is_session_expired_page(&html)
+            && parsers::user::parse_user_from_html(&html).is_ok();
+        if status.authenticated {
+            status.active_period = parsers::courses::parse_courses_from_html(&html)
+                .ok()
+                .and_then(|courses| period_of_courses(&courses))
+                .map(|period| period.to_academic_year_string());
+        }
+        Ok(status)
+    }
+
+    /// Fetches the basic profile information of the currently logged-in user.
+    ///
+    /// Cached for `CacheTtls::profile_secs` if a `CacheBackend` is configured.
+    pub async fn get_user_profile(&self) -> Result<User> {
+        let cache_key = self.period_key("profile");
+        if let Some(user) = self.cache_get(&cache_key).await {
+            return Ok(user);
+        }
+
+        let html_content = self.get_html("/mhs").await?;
+        let user = parsers::user::parse_user_from_html(&html_content)?;
+        self.cache_put(&cache_key, &user, self.cache_ttls.profile_secs)
+            .await;
+        Ok(user)
+    }
+
+    /// Fetches the student's grades for every period from the KHS page.
+    ///
+    /// Courses that are not graded yet are included with an empty `letter_grade`
+    /// and do not count towards [`Transcript::gpa`].
+    pub async fn get_grades(&self) -> Result<Transcript> {
+        let html_content = self.get_html("/mhs/khs").await?;
+        parsers::grade::parse_grades_from_html(&html_content)
+    }
+
+    /// Fetches the weekly class timetable for the active period.
+    pub async fn get_schedule(&self) -> Result<Vec<ScheduleEntry>> {
+        let html_content = self.get_html("/mhs/jadwal").await?;
+        parsers::schedule::parse_schedule_from_html(&html_content)
+    }
+
+    /// Fetches the notifications from the SPOT notification bell, newest first.
+    ///
+    /// Never cached, since the point is to see new entries as they arrive.
+    pub async fn get_notifications(&self) -> Result<Vec<Notification>> {
+        let html_content = self.get_html("/mhs/notifikasi").await?;
+        parsers::notification::parse_notifications_from_html(&html_content)
+    }
+
+    /// Fetches the announcement board of a course, newest first.
+    ///
+    /// Relative timestamps ("2 hari yang lalu") are resolved against the time of the
+    /// request in WIB, SPOT's time zone. Never cached, like notifications.
+    pub async fn get_announcements(&self, course_id: u64) -> Result<Vec<Announcement>> {
+        let html_content = self
+            .get_html(&format!("/mhs/pengumuman/{}", course_id))
+            .await?;
+        parsers::announcement::parse_announcements_from_html(
+            &html_content,
+            parsers::datetime::now_wib(),
+        )
+    }
+
+    /// Fetches a course's attendance (presensi) records, one per meeting held so far.
+    ///
+    /// Use [`AttendanceSummary::from_records`](crate::AttendanceSummary::from_records) for
+    /// totals and the attendance percentage. Never cached, since records are added
+    /// after every meeting.
+    pub async fn get_attendance(&self, course_id: u64) -> Result<Vec<AttendanceRecord>> {
+        let html_content = self
+            .get_html(&format!("/mhs/presensi/{}", course_id))
+            .await?;
+        parsers::attendance::parse_attendance_from_html(&html_content)
+    }
+
+    /// Marks a notification as read.
+    ///
+    /// Returns `SessionExpired` if SPOT sent the request to SSO instead.
+    pub async fn mark_notification_read(&self, id: u64) -> Result<()> {
+        let path = format!("/mhs/notifikasi/baca/{}", id);
+        let response = self
+            .get_request(
+                DelayAction::Navigation,
+                &format!("{}{}", self.base_url, path),
+            )
+            .await?;
+
+        if self.is_sso_url(response.url()) {
+            return Err(ScraperError::SessionExpired);
+        }
+        let status = response.status();
+
+        // SPOT redirects to the notification's target page on success
+        if status.is_success() || status.is_redirection() {
+            return Ok(());
+        }
+
+        Err(ScraperError::NotificationUpdateFailed(format!(
+            "Server returned error status: {}",
+            status
+        )))
+    }
+
+    /// Fetches the list of all courses the user is enrolled in for the active period.
+    ///
+    /// If `set_prefer_json` is on, the JSON course-list endpoint is tried first, falling
+    /// back to scraping the dashboard HTML.
+    ///
+    /// Cached for `CacheTtls::courses_secs` (1 hour by default) if a `CacheBackend` is
+    /// configured.
+    pub async fn get_courses(&self) -> Result<Vec<Course>> {
+        let cache_key = self.period_key("courses");
+        if let Some(courses) = self.cache_get(&cache_key).await {
+            return Ok(courses);
+        }
+
+        let courses = self.fetch_courses().await?;
+        self.cache_put(&cache_key, &courses, self.cache_ttls.courses_secs)
+            .await;
+        Ok(courses)
+    }
+
+    /// Fetches the course list like [`get_courses`](Self::get_courses) and reports whether
+    /// it changed since it was last fetched.
+    ///
+    /// Changes are detected by hashing the parsed course list rather than the page, since
+    /// SPOT's HTML carries per-request tokens. The hash of the last fresh fetch is kept per
+    /// period: in the `CacheBackend` under `courses_hash` if one is configured, so other
+    /// clients and later processes sharing the cache compare against it too, and in this
+    /// client's memory otherwise. A period fetched for the first time counts as changed.
+    /// Use [`refresh_courses`] to bypass the cache's TTL and check SPOT right away.
+    ///
+    /// [`refresh_courses`]: Self::refresh_courses
+    pub async fn get_courses_checked(&self) -> Result<CourseListResult> {
+        if let Some(courses) = self.cache_get(&self.period_key("courses")).await {
+            return Ok(CourseListResult {
+                courses,
+                from_cache: true,
+                changed: false,
+            });
+        }
+        self.refresh_courses().await
+    }
+
+    /// Fetches the course list from SPOT, ignoring any cached copy, and reports whether
+    /// it changed; see [`get_courses_checked`](Self::get_courses_checked).
     ///
-    /// This allows for session persistence across different runs of the application.
-    pub async fn save_cookies(&self, path: &std::path::Path) -> Result<()> {
-        let spot_url = "https://spot.upi.edu".parse().unwrap();
-        let sso_url = "https://sso.upi.edu".parse().unwrap();
+    /// The cached list is only rewritten when it changed or is no longer cached.
+    pub async fn refresh_courses(&self) -> Result<CourseListResult> {
+        let cache_key = self.period_key("courses");
+        let hash_key = self.period_key("courses_hash");
+        let courses = self.fetch_courses().await?;
+        let hash = serde_json::to_vec(&courses)
+            .map(|json| crate::hash::fnv1a_64(&json))
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to hash courses: {}", e)))?;
+
+        let remembered = self
+            .course_list_hash
+            .lock()
+            .unwrap()
+            .insert(hash_key.clone(), hash);
+        let previous = match &self.cache {
+            Some(cache) => cache
+                .get(&self.get_cache_key(&hash_key))
+                .await
+                .and_then(|stored| serde_json::from_str::<u64>(&stored).ok()),
+            None => remembered,
+        };
+        let changed = previous != Some(hash);
 
-        let mut cookie_map = HashMap::new();
-        if let Some(c) = self.cookie_jar.cookies(&spot_url) {
-            cookie_map.insert("spot", c.to_str().unwrap_or_default().to_string());
-        }
-        if let Some(c) = self.cookie_jar.cookies(&sso_url) {
-            cookie_map.insert("sso", c.to_str().unwrap_or_default().to_string());
+        if let Some(cache) = &self.cache {
+            let cached = cache.get(&self.get_cache_key(&cache_key)).await.is_some();
+            if changed || !cached {
+                self.cache_put(&cache_key, &courses, self.cache_ttls.courses_secs)
+                    .await;
+                self.cache_put(&hash_key, &hash, COURSE_LIST_HASH_TTL_SECS)
+                    .await;
+            }
         }
+        Ok(CourseListResult {
+            courses,
+            from_cache: false,
+            changed,
+        })
+    }
 
-        let json = serde_json::to_string_pretty(&cookie_map).map_err(|e| {
-            ScraperError::ParsingError(format!("Failed to serialize cookies: {}", e))
-        })?;
-
-        tokio::fs::write(path, json).await.map_err(|e| {
-            ScraperError::ParsingError(format!("Failed to write cookie file: {}", e))
-        })?;
-
-        Ok(())
+    /// Fetches the course list of the active period, bypassing the cache.
+    async fn fetch_courses(&self) -> Result<Vec<Course>> {
+        match self.get_courses_json().await {
+            Some(courses) => Ok(courses),
+            None => self.fetch_course_pages().await,
+        }
     }
 
-    /// Loads session cookies from a JSON file.
+    /// Scrapes `/mhs`, following its pagination for up to `max_course_pages` pages.
     ///
-    /// This populates the internal cookie jar, allowing the client to resume a session
-    /// without re-authenticating through SSO.
-    pub async fn load_cookies(&self, path: &std::path::Path) -> Result<()> {
-        let json = tokio::fs::read_to_string(path).await.map_err(|e| {
-            ScraperError::ParsingError(format!("Failed to read cookie file: {}", e))
-        })?;
-
-        let cookie_map: HashMap<String, String> = serde_json::from_str(&json).map_err(|e| {
-            ScraperError::ParsingError(format!("Failed to deserialize cookies: {}", e))
-        })?;
-
-        let spot_url = "https://spot.upi.edu".parse().unwrap();
-        let sso_url = "https://sso.upi.edu".parse().unwrap();
-
-        if let Some(c) = cookie_map.get("spot") {
-            for cookie in c.split(';') {
-                self.cookie_jar.add_cookie_str(cookie.trim(), &spot_url);
-            }
-        }
-        if let Some(c) = cookie_map.get("sso") {
-            for cookie in c.split(';') {
-                self.cookie_jar.add_cookie_str(cookie.trim(), &sso_url);
+    /// Each page is a normal request, so the configured delay applies between pages.
+    async fn fetch_course_pages(&self) -> Result<Vec<Course>> {
+        let mut path = "/mhs".to_string();
+        let mut visited = vec![path.clone()];
+        let mut courses = Vec::new();
+
+        for _ in 0..self.max_course_pages.max(1) {
+            let html_content = self.get_html(&path).await?;
+            courses.extend(parsers::courses::parse_courses_from_html(&html_content)?);
+
+            let Some(next) = parsers::courses::parse_next_page_href(&html_content)
+                .and_then(|href| self.same_site_path(&path, &href))
+            else {
+                break;
+            };
+            if visited.contains(&next) {
+                break;
             }
+            visited.push(next.clone());
+            path = next;
         }
 
-        Ok(())
+        Ok(courses)
     }
 
-    /// Sets a new delay configuration for the client.
-    pub fn set_delay_config(&mut self, config: DelayConfig) {
-        self.delay_config = config;
+    /// Resolves `href` against the page at `path`, returning the path and query of the
+    /// result if it stays on the SPOT host.
+    fn same_site_path(&self, path: &str, href: &str) -> Option<String> {
+        let page = reqwest::Url::parse(&format!("{}{}", self.base_url, path)).ok()?;
+        let url = page.join(href).ok()?;
+        url.as_str()
+            .strip_prefix(&self.base_url)
+            .filter(|rest| rest.starts_with('/'))
+            .map(String::from)
     }
 
-    fn get_random_ua(&self) -> &'static str {
-        MODERN_USER_AGENTS[rand::rng().random_range(0..MODERN_USER_AGENTS.len())]
+    /// Retrieves the courses of `period`, switching to it and back to the active period.
+    ///
+    /// The active period is the one last selected with `change_period` (or restored by
+    /// `load_session`); if none was, it is read from the current course list first. It is
+    /// determined before anything is switched, and selected again even if fetching fails.
+    /// If it cannot be determined (the current course list is empty), nothing is switched
+    /// and `ScraperError::ParsingError` is returned; select a period with `change_period`
+    /// first in that case. Cached per period for
+    /// `CacheTtls::courses_secs` if a `CacheBackend` is configured.
+    pub async fn get_courses_for_period(&self, period: Period) -> Result<Vec<Course>> {
+        self.get_all_courses(&[period]).await
     }
 
-    /// Waits for a random duration based on the current `DelayConfig`.
-    async fn wait_random(&self) {
-        if !self.delay_config.enabled {
-            return;
+    /// Retrieves the courses of every period in `periods`, e.g. a student's full history.
+    ///
+    /// Periods are visited in order, then the original period is selected again, as in
+    /// [`get_courses_for_period`](Self::get_courses_for_period). A course listed in several
+    /// periods appears once, merged with [`Course::merge`], at its first position.
+    pub async fn get_all_courses(&self, periods: &[Period]) -> Result<Vec<Course>> {
+        let original = self.active_period().await?;
+        let guard = PeriodGuard::new(self, original);
+
+        let result = async {
+            let mut courses = Vec::new();
+            for &period in periods {
+                courses.extend(self.courses_in_period(period).await?);
+            }
+            Ok::<_, ScraperError>(courses)
         }
+        .await;
 
-        let ms = rand::rng()
-            .random_range(self.delay_config.min_delay_ms..=self.delay_config.max_delay_ms);
-        sleep(std::time::Duration::from_millis(ms)).await;
+        let restored = guard.restore().await;
+        let courses = result?;
+        restored?;
+        Ok(dedup_courses(courses))
     }
 
-    /// Helper to perform a GET request with randomized delay and rotated User-Agent.
-    async fn get_request(&self, url: &str) -> Result<reqwest::Response> {
-        self.wait_random().await;
-        let ua = self.get_random_ua();
-        self.client
-            .get(url)
-            .header(USER_AGENT, ua)
-            .send()
-            .await
-            .map_err(ScraperError::from)
+    /// Returns the period SPOT currently has selected for this session.
+    async fn active_period(&self) -> Result<Period> {
+        if let Some(period) = self.current_period() {
+            return Ok(period);
+        }
+        let courses = self.fetch_courses().await?;
+        period_of_courses(&courses).ok_or_else(|| {
+            ScraperError::ParsingError(
+                "Cannot tell which period is selected, so it could not be restored".to_string(),
+            )
+        })
     }
 
-    /// Helper to perform a POST request with randomized delay and rotated User-Agent.
-    async fn post_request<T: serde::Serialize + ?Sized>(
-        &self,
-        url: &str,
-        form: &T,
-    ) -> Result<reqwest::Response> {
-        self.wait_random().await;
-        let ua = self.get_random_ua();
-        self.client
-            .post(url)
-            .header(USER_AGENT, ua)
-            .form(form)
-            .send()
-            .await
-            .map_err(ScraperError::from)
+    /// Selects `period` (if it is not already active) and fetches its course list.
+    async fn courses_in_period(&self, period: Period) -> Result<Vec<Course>> {
+        // The same key `get_courses` uses while `period` is active.
+        let cache_key = format!("courses:{}", period.format());
+        if let Some(courses) = self.cache_get(&cache_key).await {
+            return Ok(courses);
+        }
+
+        let courses = if self.current_period() != Some(period) {
+            self.switch_period(period).await?
+        } else {
+            self.fetch_courses().await?
+        };
+        self.cache_put(&cache_key, &courses, self.cache_ttls.courses_secs)
+            .await;
+        Ok(courses)
     }
 
-    /// Logs into SPOT using a student ID (NIM) and password through the SSO system.
+    /// Tries the JSON course-list endpoint, returning `None` if it is disabled or unusable.
     ///
-    /// This involves a three-step process:
-    /// 1. Fetching the SSO login page to retrieve the execution token.
-    /// 2. Posting credentials to the SSO service.
-    /// 3. Validating the final redirection back to the SPOT platform.
-    pub async fn login(&self, nim: &str, password: &str) -> Result<()> {
-        // Step 1: GET the login page to retrieve the "execution" token
-        let response = self.get_request(SSO_LOGIN_PAGE_URL).await?;
-
-        // The service URL is now part of the request URL itself
-        let login_action_url = response.url().clone();
-
-        let response_text = response.text().await?;
-        let document = Html::parse_document(&response_text);
-
-        let token_selector = Selector::parse("input[name=\"execution\"]").unwrap();
-
-        let execution_token = document
-            .select(&token_selector)
-            .next()
-            .and_then(|element| element.value().attr("value"))
-            .ok_or(ScraperError::TokenNotFound)?;
-
-        // Step 2: POST credentials to the SSO service
-        let mut params = HashMap::new();
-        params.insert("username", nim);
-        params.insert("password", password);
-        params.insert("execution", execution_token);
-        params.insert("_eventId", "submit");
-
-        let response = self
-            .post_request(login_action_url.as_str(), &params)
-            .await?;
+    /// The endpoint is only marked unavailable when SPOT answers that it does not exist:
+    /// a 404, a redirect elsewhere (such as the login page) or a body that is not a JSON
+    /// course list. Network errors and 5xx responses fall back to HTML for this call only.
+    async fn get_courses_json(&self) -> Option<Vec<Course>> {
+        if !self.prefer_json || self.json_unavailable.load(Ordering::Relaxed) {
+            return None;
+        }
 
-        // Simulate human processing time after successful login
-        if self.delay_config.enabled {
-            let jitter_ms = rand::rng().random_range(2000..=5000);
-            sleep(std::time::Duration::from_millis(jitter_ms)).await;
+        let url = format!("{}{}", self.base_url, COURSES_JSON_PATH);
+        let response = self.get_request(DelayAction::Navigation, &url).await.ok()?;
+        let status = response.status();
+        if status.is_server_error() {
+            return None;
         }
+        let missing =
+            status == reqwest::StatusCode::NOT_FOUND || response.url().path() != COURSES_JSON_PATH;
+        let courses = if missing || !status.is_success() {
+            None
+        } else {
+            let body = response_text(response).await.ok()?;
+            parsers::courses::parse_courses_from_json(&body).ok()
+        };
 
-        // Step 3: Verify the final redirection to SPOT
-        let final_url = response.url().clone();
-        if final_url.host_str() != Some("spot.upi.edu") {
-            let error_body = response.text().await.unwrap_or_default();
-            std::fs::write("login_fail.html", error_body).ok();
+        if courses.is_none() && (missing || status.is_success()) {
+            self.json_unavailable.store(true, Ordering::Relaxed);
+        }
+        courses
+    }
 
-            return Err(ScraperError::AuthenticationFailed);
+    /// Fetches full details for a specific course: its description, RPS and topics.
+    ///
+    /// Use [`SpotifierCoreClient::get_course_topics`] when only the topic list is needed.
+    /// Cached for `CacheTtls::course_detail_secs` if a `CacheBackend` is configured.
+    pub async fn get_course_detail(&self, course: &Course) -> Result<DetailCourse> {
+        let cache_key = self.period_key(&format!("course_detail:{}", course.id));
+        if let Some(detail) = self.cache_get(&cache_key).await {
+            return Ok(detail);
         }
 
-        Ok(())
+        // The href for the detail page is already stored in the Course struct
+        let html_content: String = self.get_html(&course.href).await?;
+        let detail = parsers::course_detail::parse_course_detail_from_html(
+            &html_content,
+            course.clone(),
+            parsers::datetime::now_wib(),
+        )?;
+        self.cache_put(&cache_key, &detail, self.cache_ttls.course_detail_secs)
+            .await;
+        Ok(detail)
     }
 
-    /// Internal helper to fetch HTML content from a specific path.
-    async fn get_html(&self, path: &str) -> Result<String> {
-        let url = format!("{}{}", self.base_url, path);
-        let response = self.get_request(&url).await?;
-
-        if !response.url().path().starts_with(path) {
-            return Err(ScraperError::SessionExpired);
+    /// Fetches only the topic list of a course.
+    ///
+    /// Unlike [`SpotifierCoreClient::get_course_detail`] this needs just the course ID and
+    /// skips parsing the description and RPS. A cached course detail is reused if present.
+    pub async fn get_course_topics(&self, course_id: u64) -> Result<Vec<TopicInfo>> {
+        let cache_key = self.period_key(&format!("course_detail:{}", course_id));
+        if let Some(detail) = self.cache_get::<DetailCourse>(&cache_key).await {
+            return Ok(detail.topics);
         }
 
-        Ok(response.text().await?)
+        let html_content = self.get_html(&format!("/mhs/topik/{}", course_id)).await?;
+        parsers::course_detail::parse_course_topics_from_html(
+            &html_content,
+            course_id,
+            parsers::datetime::now_wib(),
+        )
     }
 
-    /// Fetches the basic profile information of the currently logged-in user.
-    pub async fn get_user_profile(&self) -> Result<User> {
-        let html_content = self.get_html("/mhs").await?;
-        parsers::user::parse_user_from_html(&html_content)
+    /// Fetches the RPS (syllabus) reference of a course.
+    ///
+    /// Returns `ScraperError::RpsNotAvailable` if the lecturer never uploaded one.
+    pub async fn get_rps(&self, course: &Course) -> Result<Rps> {
+        let detail = self.get_course_detail(course).await?;
+        detail.available_rps().cloned()
     }
 
-    /// Fetches the list of all courses the user is enrolled in for the active period.
+    /// Downloads the RPS (syllabus) file, usually a PDF, into memory.
     ///
-    /// This method is cached for 1 hour by default if a `CacheBackend` is configured.
-    pub async fn get_courses(&self) -> Result<Vec<Course>> {
-        let cache_key = self.get_cache_key("courses");
-        if let Some(cache) = &self.cache {
-            if let Some(cached_data) = cache.get(&cache_key).await {
-                if let Ok(courses) = serde_json::from_str(&cached_data) {
-                    return Ok(courses);
-                }
-            }
+    /// Returns `ScraperError::RpsNotAvailable` if `rps` has no download link, carrying
+    /// the RPS id (`0` when unknown, since an [`Rps`] does not record its course); use
+    /// [`get_rps`](Self::get_rps) to fetch a reference that is known to have one.
+    pub async fn download_rps(&self, rps: &Rps) -> Result<Vec<u8>> {
+        let href = rps
+            .href
+            .as_deref()
+            .ok_or(ScraperError::RpsNotAvailable(rps.id.unwrap_or_default()))?;
+        Ok(self.download_file(href).await?.to_vec())
+    }
+
+    /// Downloads the RPS (syllabus) file and extracts its text.
+    ///
+    /// Only PDFs are supported; any other file yields `ScraperError::ParsingError`, as
+    /// does a PDF the extractor cannot read. Extraction runs on Tokio's blocking pool,
+    /// so a large PDF does not stall the runtime and a malformed one that makes the
+    /// extractor panic is reported as a `ParsingError` instead of taking the task down.
+    #[cfg(feature = "pdf")]
+    pub async fn get_rps_text(&self, rps: &Rps) -> Result<String> {
+        let bytes = self.download_rps(rps).await?;
+        if infer::get(&bytes).map(|kind| kind.mime_type()) != Some("application/pdf") {
+            return Err(ScraperError::ParsingError(
+                "RPS file is not a PDF".to_string(),
+            ));
         }
+        tokio::task::spawn_blocking(move || pdf_extract::extract_text_from_mem(&bytes))
+            .await
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to read RPS PDF: {}", e)))?
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to read RPS PDF: {}", e)))
+    }
 
-        let html_content = self.get_html("/mhs").await?;
-        let courses = parsers::courses::parse_courses_from_html(&html_content)?;
+    /// Fetches a lightweight summary of a course (topic and pending-task counts).
+    ///
+    /// Building the summary visits every accessible topic. Since its pending-task count
+    /// goes stale as quickly as the topics themselves, the result is cached for
+    /// `CacheTtls::topic_detail_secs` if a `CacheBackend` is configured.
+    pub async fn get_course_summary(&self, course: &Course) -> Result<CourseSummary> {
+        let cache_key = self.period_key(&format!("course_summary:{}", course.id));
+        if let Some(summary) = self.cache_get(&cache_key).await {
+            return Ok(summary);
+        }
 
-        if let Some(cache) = &self.cache {
-            if let Ok(json) = serde_json::to_string(&courses) {
-                let _ = cache.set(&cache_key, &json, 3600).await; // 1-hour expiration
-            }
+        let detail = self.get_course_detail(course).await?;
+        let mut topics = Vec::new();
+        for topic_info in detail.topics_accessible() {
+            topics.push(self.get_topic_detail(topic_info).await?);
         }
+        let summary = CourseSummary::from_details(&detail, &topics);
 
-        Ok(courses)
+        self.cache_put(&cache_key, &summary, self.cache_ttls.topic_detail_secs)
+            .await;
+        Ok(summary)
     }
 
-    /// Fetches full details for a specific course, including its topics.
-    pub async fn get_course_detail(&self, course: &Course) -> Result<DetailCourse> {
-        // The href for the detail page is already stored in the Course struct
-        let html_content: String = self.get_html(&course.href).await?;
-        parsers::course_detail::parse_course_detail_from_html(&html_content, course.clone())
+    /// Returns the topics of a course that changed after `since`.
+    ///
+    /// A topic counts as changed when its `updated_at` timestamp is newer than `since`.
+    /// SPOT only shows an update timestamp on some topics, so for the rest this falls
+    /// back to `access_time`, which may miss edits made after the last visit.
+    pub async fn get_updated_topics_since(
+        &self,
+        course: &Course,
+        since: NaiveDateTime,
+    ) -> Result<Vec<TopicInfo>> {
+        let detail = self.get_course_detail(course).await?;
+        Ok(detail
+            .topics
+            .into_iter()
+            .filter(|topic| {
+                topic
+                    .updated_at
+                    .or(topic.access_time)
+                    .is_some_and(|changed_at| changed_at > since)
+            })
+            .collect())
     }
 
     /// Fetches detailed information for a specific topic, including associated tasks.
+    ///
+    /// Cached for `CacheTtls::topic_detail_secs` if a `CacheBackend` is configured. The
+    /// entry is dropped whenever a task in the topic is submitted or deleted.
     pub async fn get_topic_detail(&self, topic_info: &TopicInfo) -> Result<TopicDetail> {
         let href = topic_info.href.as_ref().ok_or_else(|| {
             ScraperError::ParsingError("TopicInfo does not have a valid href".to_string())
         })?;
 
-        let course_id = topic_info.course_id.ok_or_else(|| {
-            ScraperError::ParsingError("TopicInfo does not have a valid course_id".to_string())
-        })?;
-        let topic_id = topic_info.id.ok_or_else(|| {
-            ScraperError::ParsingError("TopicInfo does not have a valid topic_id".to_string())
+        let (course_id, topic_id) = topic_info.ids().ok_or_else(|| {
+            ScraperError::ParsingError(
+                "TopicInfo does not have a valid course_id and topic_id".to_string(),
+            )
         })?;
 
-        let html_content = self.get_html(href).await?;
-        parsers::topic_detail::parse_topic_detail_from_html(&html_content, topic_id, course_id)
+        self.fetch_topic_detail(href, course_id, topic_id).await
+    }
+
+    /// Fetches many topics at once, with at most `concurrency` requests in flight.
+    ///
+    /// Each request still goes through the configured `DelayConfig` jitter (and rate
+    /// limiter, if any). Results come back in the same order as `topics`, and a failing
+    /// topic only fails its own entry. A `concurrency` of 0 is treated as 1.
+    pub async fn get_topic_details_batch(
+        &self,
+        topics: &[TopicInfo],
+        concurrency: usize,
+    ) -> Vec<Result<TopicDetail>> {
+        let mut results: Vec<(usize, Result<TopicDetail>)> =
+            stream::iter(topics.iter().enumerate())
+                .map(|(index, topic_info)| async move {
+                    (index, self.get_topic_detail(topic_info).await)
+                })
+                .buffer_unordered(concurrency.max(1))
+                .collect()
+                .await;
+
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// Fetches topics one at a time, yielding each detail as soon as it arrives.
+    ///
+    /// Unlike [`get_topic_details_batch`](Self::get_topic_details_batch), nothing is
+    /// collected: a topic is only requested when the stream is polled for it, after the
+    /// usual `DelayConfig` jitter. Dropping the stream stops further requests. A failing
+    /// topic yields an `Err` item and the stream moves on to the next one.
+    pub fn stream_topic_details(
+        &self,
+        topics: Vec<TopicInfo>,
+    ) -> impl Stream<Item = Result<TopicDetail>> + '_ {
+        stream::iter(topics)
+            .then(move |topic_info| async move { self.get_topic_detail(&topic_info).await })
+    }
+
+    /// Fetches and caches a topic page, shared by the `get_topic_detail*` methods.
+    async fn fetch_topic_detail(
+        &self,
+        path: &str,
+        course_id: u64,
+        topic_id: u64,
+    ) -> Result<TopicDetail> {
+        let cache_key = self.period_key(&format!("topic_detail:{}:{}", course_id, topic_id));
+        if let Some(topic) = self.cache_get(&cache_key).await {
+            return Ok(topic);
+        }
+
+        let html_content = self.get_html(path).await?;
+        let topic = parsers::topic_detail::parse_topic_detail_from_html(
+            &html_content,
+            topic_id,
+            course_id,
+        )?;
+        self.cache_put(&cache_key, &topic, self.cache_ttls.topic_detail_secs)
+            .await;
+        Ok(topic)
     }
 
     /// Convenience method to get course details using its unique ID.
@@ -340,17 +2502,45 @@ impl SpotifierCoreClient {
         topic_id: u64,
     ) -> Result<TopicDetail> {
         let path = format!("/mhs/topik/{}/{}", course_id, topic_id);
-        let html_content = self.get_html(&path).await?;
-        parsers::topic_detail::parse_topic_detail_from_html(&html_content, topic_id, course_id)
+        self.fetch_topic_detail(&path, course_id, topic_id).await
     }
 
     /// Changes the active academic period/semester for the current session.
+    ///
+    /// SPOT accepts a switch to a period the student has no data for and keeps the old
+    /// one active, so the course list is read back afterwards, as
+    /// [`get_current_period_info`](Self::get_current_period_info) does. If it still shows
+    /// another period, `ScraperError::PeriodUnavailable` is returned; use
+    /// [`list_available_periods`](Self::list_available_periods) to offer only valid ones.
+    /// A period without any courses cannot be told apart and is accepted.
     pub async fn change_period(&self, year: u16, semester: Semester) -> Result<()> {
-        let period = Period::new(year, semester);
+        self.switch_period(Period::new(year, semester))
+            .await
+            .map(|_| ())
+    }
+
+    /// Selects `period` and returns the course list read back to confirm the switch.
+    ///
+    /// Gives up with `ScraperError::SessionExpired` when nested more than
+    /// [`MAX_PERIOD_SWITCH_DEPTH`] deep through re-logins.
+    async fn switch_period(&self, period: Period) -> Result<Vec<Course>> {
+        let depth = PERIOD_SWITCH_DEPTH.try_with(|depth| *depth).unwrap_or(0);
+        if depth >= MAX_PERIOD_SWITCH_DEPTH {
+            return Err(ScraperError::SessionExpired);
+        }
+        PERIOD_SWITCH_DEPTH
+            .scope(depth + 1, self.request_period_switch(period))
+            .await
+    }
+
+    async fn request_period_switch(&self, period: Period) -> Result<Vec<Course>> {
         let path = format!("/adm/semester/{}", period.format());
 
         let response = self
-            .get_request(&format!("{}{}", self.base_url, path))
+            .get_request(
+                DelayAction::Navigation,
+                &format!("{}{}", self.base_url, path),
+            )
             .await?;
 
         let status = response.status();
@@ -359,7 +2549,18 @@ impl SpotifierCoreClient {
         if status.is_success() || status.is_redirection() {
             let final_url = response.url();
             if final_url.path() == "/adm" || final_url.path().starts_with("/adm") {
-                return Ok(());
+                // SPOT has switched (or kept the old period, which the read-back below
+                // corrects), so a failed read-back must not leave the old period here.
+                *self.current_period.lock().unwrap() = Some(period);
+                let courses = self.fetch_courses().await?;
+                if let Some(active) = period_of_courses(&courses)
+                    && active != period
+                {
+                    *self.current_period.lock().unwrap() = Some(active);
+                    return Err(ScraperError::PeriodUnavailable(period));
+                }
+                *self.current_period.lock().unwrap() = Some(period);
+                return Ok(courses);
             }
         }
 
@@ -377,6 +2578,16 @@ impl SpotifierCoreClient {
         )))
     }
 
+    /// Lists the periods the student can switch to with `change_period`, as offered by
+    /// SPOT's period switcher on the dashboard.
+    ///
+    /// Periods keep the order SPOT lists them in. Always fetched fresh, so a newly
+    /// opened semester shows up as soon as SPOT offers it.
+    pub async fn list_available_periods(&self) -> Result<Vec<Period>> {
+        let html_content = self.get_html("/mhs").await?;
+        parsers::period::parse_available_periods_from_html(&html_content)
+    }
+
     /// Retrieves the raw string representation of the current academic period.
     ///
     /// Example: "2025/2026 - Ganjil"
@@ -392,33 +2603,140 @@ impl SpotifierCoreClient {
         }
     }
 
-    /// Internal helper to perform a POST request with a multipart form (used for file uploads).
-    async fn multipart_request(
-        &self,
-        url: &str,
-        form: multipart::Form,
-    ) -> Result<reqwest::Response> {
-        self.wait_random().await;
-        let ua = self.get_random_ua();
-        self.client
-            .post(url)
-            .header(USER_AGENT, ua)
-            .multipart(form)
-            .send()
+    /// Downloads a file such as `Task::file` or `Answer::file_href` into memory.
+    ///
+    /// Hrefs are resolved with [`resolve_href`](Self::resolve_href). If SPOT answers with
+    /// an HTML page instead (its way of redirecting an expired session to the login
+    /// page), `ScraperError::SessionExpired` is returned rather than the page's bytes.
+    pub async fn download_file(&self, href: &str) -> Result<bytes::Bytes> {
+        let (url, response) = self.file_response(href).await?;
+        let sent = sent_at(&response);
+        response
+            .bytes()
+            .await
+            .map_err(|e| ScraperError::from_request(e, url.as_str(), sent))
+    }
+
+    /// Downloads a file and streams it to disk, returning the path it was written to.
+    ///
+    /// If `path` is an existing directory, the file is saved inside it under the name
+    /// from the `Content-Disposition` header, falling back to the last segment of the
+    /// URL. Either name is stripped of directory components and control characters, so a
+    /// server-provided name can never escape the directory.
+    pub async fn download_file_to(&self, href: &str, path: &Path) -> Result<PathBuf> {
+        use tokio::io::AsyncWriteExt;
+
+        let (url, mut response) = self.file_response(href).await?;
+
+        let target = if path.is_dir() {
+            let name = response
+                .headers()
+                .get(CONTENT_DISPOSITION)
+                .and_then(|value| value.to_str().ok())
+                .and_then(content_disposition_file_name)
+                .or_else(|| {
+                    url.path_segments()
+                        .and_then(|mut segments| segments.next_back())
+                        .map(String::from)
+                })
+                .unwrap_or_default();
+            path.join(sanitize_file_name(&name))
+        } else {
+            path.to_path_buf()
+        };
+
+        let io_error =
+            |e: std::io::Error| ScraperError::ParsingError(format!("Failed to write file: {}", e));
+        let mut file = tokio::fs::File::create(&target).await.map_err(io_error)?;
+        let sent = sent_at(&response);
+        while let Some(chunk) = response
+            .chunk()
             .await
-            .map_err(ScraperError::from)
+            .map_err(|e| ScraperError::from_request(e, url.as_str(), sent))?
+        {
+            file.write_all(&chunk).await.map_err(io_error)?;
+        }
+        file.flush().await.map_err(io_error)?;
+
+        Ok(target)
+    }
+
+    /// Requests a file and checks that the response is the file rather than a login page.
+    async fn file_response(&self, href: &str) -> Result<(reqwest::Url, reqwest::Response)> {
+        let url = reqwest::Url::parse(&self.resolve_href(href)).map_err(|e| {
+            ScraperError::ParsingError(format!("Invalid file href {}: {}", href, e))
+        })?;
+
+        let response = self
+            .get_request(DelayAction::Navigation, url.as_str())
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            let final_url = response.url().to_string();
+            let body = response_text(response).await?;
+            return Err(self.http_status_error(status.as_u16(), &final_url, &body));
+        }
+
+        let is_html = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.starts_with("text/html"));
+        let wants_html = url.path().ends_with(".html") || url.path().ends_with(".htm");
+        if self.is_sso_url(response.url()) || (is_html && !wants_html) {
+            return Err(ScraperError::SessionExpired);
+        }
+
+        Ok((url, response))
+    }
+
+    /// Internal helper to perform a POST request with a multipart form (used for file uploads).
+    async fn multipart_request(&self, url: &str, form: MultipartForm) -> Result<reqwest::Response> {
+        let content_type = form.content_type();
+        let request_body = match self.recording_dir {
+            Some(_) => form.summary(),
+            None => None,
+        };
+        let body = bytes::Bytes::from(form.into_body());
+        let response = self
+            .send_with_retry(false, || {
+                let body = body.clone();
+                let content_type = content_type.clone();
+                async move {
+                    self.wait_random(DelayAction::Submission).await;
+                    let ua = self.next_user_agent();
+                    self.execute(
+                        self.with_referer(self.client.post(url).header(USER_AGENT, ua), url)
+                            .header(CONTENT_TYPE, content_type)
+                            .body(body),
+                    )
+                    .await
+                }
+            })
+            .await?;
+        self.remember_page(&response);
+        self.record("POST", url, request_body, response).await
     }
 
     /// Submits a task to the SPOT platform.
     ///
+    /// A thin wrapper around [`submit_task_multi`](Self::submit_task_multi) for at most
+    /// one attached file.
+    ///
+    /// `content` is not checked against the task's character limits, which only a parsed
+    /// [`Task`] knows; prefer [`submit_task_for`](Self::submit_task_for), which checks them
+    /// before uploading.
+    ///
     /// # Arguments
     /// * `course_id` - The unique identifier for the course.
     /// * `topic_id` - The unique identifier for the topic.
     /// * `task_id` - The unique identifier for the specific task.
     /// * `token` - The CSRF token required for the submission (extracted from the topic detail).
     /// * `content` - The text content or description for the submission.
-    /// * `file_name` - Optional name for an attached file.
+    /// * `file_name` - Optional name for an attached file (sanitized as for
+    ///   [`submit_task_multi`](Self::submit_task_multi)).
     /// * `file_data` - Optional bytes for the attached file.
+    #[allow(clippy::too_many_arguments)]
     pub async fn submit_task(
         &self,
         course_id: u64,
@@ -429,27 +2747,143 @@ impl SpotifierCoreClient {
         file_name: Option<String>,
         file_data: Option<Vec<u8>>,
     ) -> Result<()> {
-        let mut form = multipart::Form::new()
-            .text("_token", token.to_string())
-            .text("id_pn", course_id.to_string())
-            .text("id_pt", topic_id.to_string())
-            .text("id_tg", task_id.to_string())
-            .text("isi", content.to_string());
+        let files = file_name.zip(file_data).into_iter().collect();
+        self.submit_task_multi(course_id, topic_id, task_id, token, content, files)
+            .await
+    }
+
+    /// Submits a task with any number of attached files.
+    ///
+    /// File names lose any directory components and control characters but keep non-ASCII
+    /// characters: the part header sends the name as UTF-8 in `filename` and, for non-ASCII
+    /// names, RFC 5987-encoded in `filename*` as well. Each part's `Content-Type`
+    /// is sniffed from the file's bytes (see [`detect_mime_type`](crate::detect_mime_type))
+    /// rather than trusted from its extension. Every file is sent as its own
+    /// `filename` part, like SPOT's upload form.
+    ///
+    /// Returns `ScraperError::UploadTooLarge` without sending anything if the files
+    /// together exceed the cap set with [`set_max_upload_bytes`](Self::set_max_upload_bytes).
+    /// With [`set_allowed_upload_types`](Self::set_allowed_upload_types), a file of
+    /// another type fails with `ScraperError::UnsupportedFileType`, also before sending.
+    /// If SPOT rejects the upload (HTTP 413, or the page it returns shows an error alert),
+    /// the reason is reported as `ScraperError::TaskSubmissionFailed`. Like
+    /// [`submit_task`](Self::submit_task), this does not check `content` against the
+    /// task's character limits.
+    pub async fn submit_task_multi(
+        &self,
+        course_id: u64,
+        topic_id: u64,
+        task_id: u64,
+        token: &str,
+        content: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> Result<()> {
+        self.post_submission(course_id, topic_id, task_id, token, content, files)
+            .await
+            .map(|_| ())
+    }
+
+    /// Submits a task like [`submit_task`](Self::submit_task) and returns the new
+    /// submission.
+    ///
+    /// The answer is read from SPOT's response to the upload, which is normally the
+    /// topic page it redirects back to. If that page does not show it, the topic is
+    /// fetched once more. A confirmation page that only links the new submission yields
+    /// an `Answer` with just its `id` set. Fails with `ScraperError::TaskSubmissionFailed`
+    /// if the submission cannot be found at all.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn submit_task_returning(
+        &self,
+        course_id: u64,
+        topic_id: u64,
+        task_id: u64,
+        token: &str,
+        content: &str,
+        file_name: Option<String>,
+        file_data: Option<Vec<u8>>,
+    ) -> Result<Answer> {
+        let files = file_name.zip(file_data).into_iter().collect();
+        let html = self
+            .post_submission(course_id, topic_id, task_id, token, content, files)
+            .await?;
+        if let Some(answer) =
+            parsers::topic_detail::parse_submitted_answer(&html, course_id, topic_id, task_id)
+        {
+            return Ok(answer);
+        }
+
+        let topic = self.get_topic_detail_by_id(course_id, topic_id).await?;
+        topic
+            .tasks
+            .into_iter()
+            .find(|task| task.id == Some(task_id))
+            .and_then(|task| task.answer)
+            .ok_or_else(|| {
+                ScraperError::TaskSubmissionFailed(format!(
+                    "SPOT accepted the upload but task {} shows no submission",
+                    task_id
+                ))
+            })
+    }
 
-        if let (Some(name), Some(data)) = (file_name, file_data) {
-            let part = multipart::Part::bytes(data).file_name(name);
-            form = form.part("filename", part);
+    /// Uploads a submission and returns the page SPOT answered with.
+    async fn post_submission(
+        &self,
+        course_id: u64,
+        topic_id: u64,
+        task_id: u64,
+        token: &str,
+        content: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> Result<String> {
+        let total_bytes: u64 = files.iter().map(|(_, data)| data.len() as u64).sum();
+        if total_bytes > self.max_upload_bytes {
+            return Err(ScraperError::UploadTooLarge {
+                total_bytes,
+                limit: self.max_upload_bytes,
+            });
         }
 
+        let files = files
+            .into_iter()
+            .map(|(name, data)| {
+                let name = sanitize_file_name(&name);
+                let mime = check_upload_type(&name, &data, self.allowed_upload_types.as_deref())?;
+                Ok((name, data, mime))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let form = MultipartForm::new()
+            .text("_token", token)
+            .text("id_pn", &course_id.to_string())
+            .text("id_pt", &topic_id.to_string())
+            .text("id_tg", &task_id.to_string())
+            .text("isi", content);
+        let form = files.iter().fold(form, |form, (name, data, mime)| {
+            form.file("filename", name, data, *mime)
+        });
+
         let response = self
             .multipart_request(&format!("{}/mhs/tugas_store", self.base_url), form)
             .await?;
 
         let status = response.status();
+        if status == reqwest::StatusCode::PAYLOAD_TOO_LARGE {
+            return Err(ScraperError::TaskSubmissionFailed(format!(
+                "SPOT rejected the upload of {} bytes as too large",
+                total_bytes
+            )));
+        }
 
         // Successful submission usually results in a redirect back to the topic page
         if status.is_success() || status.is_redirection() {
-            return Ok(());
+            // Invalidated first: the upload may have gone through even if reading the
+            // answer fails.
+            self.invalidate_topic(course_id, topic_id).await;
+            let html = response_text(response).await?;
+            if let Some(message) = parsers::topic_detail::parse_submission_error(&html) {
+                return Err(ScraperError::TaskSubmissionFailed(message));
+            }
+            return Ok(html);
         }
 
         Err(ScraperError::TaskSubmissionFailed(format!(
@@ -458,12 +2892,45 @@ impl SpotifierCoreClient {
         )))
     }
 
+    /// Submits a task using the identifiers and token parsed into a `Task`.
+    ///
+    /// This is the recommended way to submit. Unlike `submit_task`, this first validates `content` against the task's character
+    /// limits and returns `ScraperError::ContentLengthInvalid` without uploading anything
+    /// if they are not met.
+    pub async fn submit_task_for(
+        &self,
+        task: &Task,
+        content: &str,
+        file_name: Option<String>,
+        file_data: Option<Vec<u8>>,
+    ) -> Result<()> {
+        task.validate_content(content)?;
+
+        let task_id = task.id.ok_or_else(|| {
+            ScraperError::TaskSubmissionFailed("Task does not have a valid id".to_string())
+        })?;
+
+        self.submit_task(
+            task.course_id,
+            task.topic_id,
+            task_id,
+            &task.token,
+            content,
+            file_name,
+            file_data,
+        )
+        .await
+    }
+
     /// Deletes an existing task submission from the SPOT platform.
     ///
     /// # Arguments
     /// * `course_id` - The unique identifier for the course.
     /// * `topic_id` - The unique identifier for the topic.
     /// * `answer_id` - The ID of the submission to delete (found in the `Task`'s `answer` field).
+    ///
+    /// The page SPOT answers with is checked as well: an error alert, or a page that still
+    /// shows the submission, fails with `ScraperError::TaskDeletionFailed`.
     pub async fn delete_task_submission(
         &self,
         course_id: u64,
@@ -472,13 +2939,27 @@ impl SpotifierCoreClient {
     ) -> Result<()> {
         let path = format!("/mhs/tugas_del/{}/{}/{}", course_id, topic_id, answer_id);
         let response = self
-            .get_request(&format!("{}{}", self.base_url, path))
+            .get_request(
+                DelayAction::Submission,
+                &format!("{}{}", self.base_url, path),
+            )
             .await?;
 
         let status = response.status();
 
         // Successful deletion usually results in a redirect back to the topic page
         if status.is_success() || status.is_redirection() {
+            self.invalidate_topic(course_id, topic_id).await;
+            let html = response_text(response).await?;
+            if let Some(message) = parsers::topic_detail::parse_submission_error(&html) {
+                return Err(ScraperError::TaskDeletionFailed(message));
+            }
+            if parsers::topic_detail::has_answer(&html, answer_id) {
+                return Err(ScraperError::TaskDeletionFailed(format!(
+                    "Submission {} is still shown after deletion",
+                    answer_id
+                )));
+            }
             return Ok(());
         }
 
@@ -487,4 +2968,30 @@ impl SpotifierCoreClient {
             status
         )))
     }
+
+    /// Deletes a task submission like [`delete_task_submission`](Self::delete_task_submission),
+    /// then fetches the topic again to make sure no task still carries the submission.
+    pub async fn delete_task_submission_verified(
+        &self,
+        course_id: u64,
+        topic_id: u64,
+        answer_id: u64,
+    ) -> Result<()> {
+        self.delete_task_submission(course_id, topic_id, answer_id)
+            .await?;
+
+        let topic = self.get_topic_detail_by_id(course_id, topic_id).await?;
+        let still_present = topic
+            .tasks
+            .iter()
+            .filter_map(|task| task.answer.as_ref())
+            .any(|answer| answer.id == Some(answer_id));
+        if still_present {
+            return Err(ScraperError::TaskDeletionFailed(format!(
+                "Submission {} is still listed on topic {}",
+                answer_id, topic_id
+            )));
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,35 @@
+use reqwest::Url;
+use reqwest::cookie::{CookieStore, Jar};
+use reqwest::header::HeaderValue;
+use std::sync::RwLock;
+
+/// The client's cookie store: a `reqwest` [`Jar`] that can also be emptied.
+///
+/// `Jar` has no way to drop cookies once added, which `logout` needs, so clearing swaps
+/// in a fresh jar behind the lock instead.
+#[derive(Debug, Default)]
+pub(crate) struct SessionJar {
+    jar: RwLock<Jar>,
+}
+
+impl SessionJar {
+    /// Adds a cookie in `Set-Cookie` form as if `url` had sent it.
+    pub(crate) fn add_cookie_str(&self, cookie: &str, url: &Url) {
+        self.jar.read().unwrap().add_cookie_str(cookie, url);
+    }
+
+    /// Drops every stored cookie.
+    pub(crate) fn clear(&self) {
+        *self.jar.write().unwrap() = Jar::default();
+    }
+}
+
+impl CookieStore for SessionJar {
+    fn set_cookies(&self, cookie_headers: &mut dyn Iterator<Item = &HeaderValue>, url: &Url) {
+        self.jar.read().unwrap().set_cookies(cookie_headers, url);
+    }
+
+    fn cookies(&self, url: &Url) -> Option<HeaderValue> {
+        self.jar.read().unwrap().cookies(url)
+    }
+}
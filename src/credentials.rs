@@ -0,0 +1,73 @@
+use crate::error::{Result, ScraperError};
+use zeroize::Zeroize;
+
+/// Environment variable holding the student ID read by [`Credentials::from_env`].
+const NIM_ENV: &str = "SPOT_NIM";
+/// Environment variable holding the password read by [`Credentials::from_env`].
+const PASSWORD_ENV: &str = "SPOT_PASSWORD";
+
+/// A student ID (NIM) and SSO password, for [`SpotifierCoreClient::login_with`].
+///
+/// The password is overwritten with zeros when the value is dropped, and `Debug` never
+/// prints it.
+///
+/// [`SpotifierCoreClient::login_with`]: crate::SpotifierCoreClient::login_with
+#[derive(Clone)]
+pub struct Credentials {
+    /// The student identification number.
+    pub nim: String,
+    /// The SSO password; read it with [`password`](Self::password).
+    password: String,
+}
+
+impl Credentials {
+    /// Creates credentials from a NIM and password.
+    pub fn new(nim: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            nim: nim.into(),
+            password: password.into(),
+        }
+    }
+
+    /// Reads the credentials from the `SPOT_NIM` and `SPOT_PASSWORD` environment variables.
+    ///
+    /// Returns `ScraperError::MissingCredentials` naming the first variable that is unset
+    /// or empty. Load a `.env` file beforehand (e.g. with `dotenvy`) if that is where
+    /// they live.
+    pub fn from_env() -> Result<Self> {
+        Self::from_env_with(|key| std::env::var(key).ok())
+    }
+
+    /// Like [`from_env`](Self::from_env), but looks the variables up with `var` instead
+    /// of the process environment, e.g. in tests or from a secrets store.
+    pub fn from_env_with(mut var: impl FnMut(&str) -> Option<String>) -> Result<Self> {
+        let mut read = |name: &'static str| {
+            var(name)
+                .filter(|value| !value.trim().is_empty())
+                .ok_or(ScraperError::MissingCredentials(name))
+        };
+        let nim = read(NIM_ENV)?;
+        let password = read(PASSWORD_ENV)?;
+        Ok(Self::new(nim.trim(), password))
+    }
+
+    /// The SSO password.
+    pub fn password(&self) -> &str {
+        &self.password
+    }
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("nim", &self.nim)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Drop for Credentials {
+    fn drop(&mut self) {
+        self.password.zeroize();
+    }
+}
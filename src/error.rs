@@ -1,19 +1,88 @@
 // src/error.rs
+use crate::models::Period;
+use std::time::Duration;
+#[cfg(feature = "client")]
+use std::time::Instant;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum ScraperError {
+    #[cfg(feature = "client")]
     #[error("Request to SPOT failed: {0}")]
     RequestError(#[from] reqwest::Error),
 
+    #[error("Failed to build the HTTP client: {0}")]
+    ClientBuild(String),
+
+    #[error("Request to {url} timed out after {elapsed:?}")]
+    Timeout {
+        /// The URL that was being requested.
+        url: String,
+        /// How long the request ran before timing out.
+        elapsed: Duration,
+    },
+
+    #[error("SPOT returned HTTP {status} for {url}")]
+    HttpStatus {
+        /// The HTTP status code of the response.
+        status: u16,
+        /// The URL that returned the status (after redirects), with query values redacted.
+        url: String,
+        /// The start of the response body, with whitespace collapsed. Empty unless
+        /// enabled with `SpotifierCoreClientBuilder::error_body_snippets`.
+        body_snippet: String,
+    },
+
+    #[error("SPOT is rate limiting requests (retry after {retry_after:?})")]
+    RateLimited {
+        /// How long SPOT asked to wait, from its `Retry-After` header.
+        retry_after: Option<Duration>,
+    },
+
+    #[error("Giving up after {attempts} attempts: {last}")]
+    RetriesExhausted {
+        /// How many times the request was sent.
+        attempts: u32,
+        /// The error of the final attempt.
+        last: Box<ScraperError>,
+    },
+
     #[error("Failed to parse HTML: {0}")]
     ParsingError(String),
 
     #[error("The SPOT session appears to have expired")]
     SessionExpired,
 
-    #[error("Authentication failed. Please check your credentials.")]
-    AuthenticationFailed,
+    #[error("Authentication failed: {step} returned {status} and ended at {final_url}")]
+    AuthenticationFailed {
+        /// The login step that failed (e.g. `"POST credentials"`).
+        step: &'static str,
+        /// The HTTP status code of that step's final response.
+        status: u16,
+        /// The URL the request ended up at after following redirects.
+        final_url: String,
+    },
+
+    #[error("SSO rejected the NIM or password: {0}")]
+    InvalidCredentials(String),
+
+    #[error("SSO is unavailable (HTTP {status})")]
+    SsoUnavailable {
+        /// The 5xx status code returned by SSO.
+        status: u16,
+    },
+
+    #[error("Missing credentials: {0} must not be empty")]
+    MissingCredentials(&'static str),
+
+    #[error("Login succeeded but SSO requires a password change before continuing")]
+    PasswordChangeRequired,
+
+    #[error("SSO requires a one-time password; complete the login with submit_otp")]
+    TwoFactorRequired {
+        /// The URL of the OTP challenge page.
+        challenge_url: String,
+    },
 
     #[error("Could not find the login CSRF token on the page")]
     TokenNotFound,
@@ -21,14 +90,153 @@ pub enum ScraperError {
     #[error("Could not find required element on the page: {0}")]
     ElementNotFound(String),
 
+    #[error("Selector `{selector}` matched nothing on the {context}")]
+    SelectorMissing {
+        /// The CSS selector that failed to match.
+        selector: String,
+        /// The page (or part of it) that was being parsed, e.g. `"dashboard"`.
+        context: String,
+        /// The start of the page's `<body>`, whitespace-collapsed and cut to 200 characters.
+        snippet: String,
+    },
+
+    #[error("Course {0} has no RPS uploaded")]
+    RpsNotAvailable(u64),
+
     #[error("Invalid period format: {0}")]
     InvalidPeriod(String),
 
+    #[error("Period {} is not available for this student", .0.to_academic_year_string())]
+    PeriodUnavailable(Period),
+
     #[error("Task submission failed: {0}")]
     TaskSubmissionFailed(String),
 
+    #[error(
+        "Submission content has {actual} characters, outside the allowed range (min: {min:?}, max: {max:?})"
+    )]
+    ContentLengthInvalid {
+        /// The minimum number of characters, if enforced.
+        min: Option<u32>,
+        /// The maximum number of characters, if enforced.
+        max: Option<u32>,
+        /// The number of characters in the rejected content.
+        actual: u32,
+    },
+
+    #[error("Upload of {total_bytes} bytes exceeds the limit of {limit} bytes")]
+    UploadTooLarge {
+        /// The combined size of all attached files.
+        total_bytes: u64,
+        /// The configured cap, see `SpotifierCoreClient::set_max_upload_bytes`.
+        limit: u64,
+    },
+
+    #[error("File {file_name:?} has an unsupported type ({})", detected.as_deref().unwrap_or("unknown"))]
+    UnsupportedFileType {
+        /// The (sanitized) name of the rejected file.
+        file_name: String,
+        /// The MIME type sniffed from the file's contents, if it has a known signature.
+        detected: Option<String>,
+    },
+
     #[error("Task deletion failed: {0}")]
     TaskDeletionFailed(String),
+
+    #[error("Marking notification as read failed: {0}")]
+    NotificationUpdateFailed(String),
+
+    #[error("Dry run: {method} {url} was not sent")]
+    DryRun {
+        /// The HTTP method of the request that was skipped.
+        method: String,
+        /// The URL of the request that was skipped.
+        url: String,
+    },
+}
+
+impl ScraperError {
+    /// Returns `true` for transient failures that are worth retrying:
+    /// timeouts, connection errors, rate limiting and 5xx responses from SPOT.
+    ///
+    /// `RetriesExhausted` is not retryable: its retries have already been spent.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            ScraperError::Timeout { .. }
+            | ScraperError::SsoUnavailable { .. }
+            | ScraperError::RateLimited { .. } => true,
+            ScraperError::HttpStatus { status, .. } => *status >= 500,
+            #[cfg(feature = "client")]
+            ScraperError::RequestError(err) => {
+                err.is_timeout()
+                    || err.is_connect()
+                    || err.status().is_some_and(|status| status.is_server_error())
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns a short, stable name for the variant, e.g. `"timeout"` or `"http_status"`.
+    ///
+    /// Meant for grouping errors in logs and metrics without matching on every variant.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "client")]
+            ScraperError::RequestError(_) => "request",
+            ScraperError::ClientBuild(_) => "client_build",
+            ScraperError::Timeout { .. } => "timeout",
+            ScraperError::HttpStatus { .. } => "http_status",
+            ScraperError::RateLimited { .. } => "rate_limited",
+            ScraperError::RetriesExhausted { .. } => "retries_exhausted",
+            ScraperError::ParsingError(_) => "parsing",
+            ScraperError::SessionExpired => "session_expired",
+            ScraperError::AuthenticationFailed { .. } => "authentication_failed",
+            ScraperError::InvalidCredentials(_) => "invalid_credentials",
+            ScraperError::SsoUnavailable { .. } => "sso_unavailable",
+            ScraperError::MissingCredentials(_) => "missing_credentials",
+            ScraperError::PasswordChangeRequired => "password_change_required",
+            ScraperError::TwoFactorRequired { .. } => "two_factor_required",
+            ScraperError::TokenNotFound => "token_not_found",
+            ScraperError::ElementNotFound(_) => "element_not_found",
+            ScraperError::SelectorMissing { .. } => "selector_missing",
+            ScraperError::RpsNotAvailable(_) => "rps_not_available",
+            ScraperError::InvalidPeriod(_) => "invalid_period",
+            ScraperError::PeriodUnavailable(_) => "period_unavailable",
+            ScraperError::TaskSubmissionFailed(_) => "task_submission_failed",
+            ScraperError::ContentLengthInvalid { .. } => "content_length_invalid",
+            ScraperError::UploadTooLarge { .. } => "upload_too_large",
+            ScraperError::UnsupportedFileType { .. } => "unsupported_file_type",
+            ScraperError::TaskDeletionFailed(_) => "task_deletion_failed",
+            ScraperError::NotificationUpdateFailed(_) => "notification_update_failed",
+            ScraperError::DryRun { .. } => "dry_run",
+        }
+    }
+
+    /// Builds `HttpStatus` from an unsuccessful response's status, final URL and body.
+    ///
+    /// Query values are redacted from the URL, since some of them (e.g. the CAS
+    /// `ticket`) are credentials.
+    #[cfg(feature = "client")]
+    pub(crate) fn http_status(status: u16, url: &str, body: &str) -> Self {
+        ScraperError::HttpStatus {
+            status,
+            url: crate::recording::redact_url(url),
+            body_snippet: crate::parsers::snippet(body),
+        }
+    }
+
+    /// Wraps a `reqwest::Error`, promoting timeouts to `ScraperError::Timeout`.
+    #[cfg(feature = "client")]
+    pub(crate) fn from_request(err: reqwest::Error, url: &str, started: Instant) -> Self {
+        if err.is_timeout() {
+            ScraperError::Timeout {
+                url: url.to_string(),
+                elapsed: started.elapsed(),
+            }
+        } else {
+            ScraperError::RequestError(err)
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, ScraperError>;
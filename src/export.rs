@@ -0,0 +1,236 @@
+use crate::error::{Result, ScraperError};
+#[cfg(feature = "csv")]
+use crate::models::{AttendanceRecord, Transcript};
+use crate::models::{Period, ScheduleEntry, TopicDetail};
+use chrono::{Datelike, Duration, NaiveDate, Utc};
+use serde::Serialize;
+use std::io::Write;
+
+/// Serializes any model (or collection of models) as indented JSON.
+pub fn to_json_pretty<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    serde_json::to_string_pretty(value)
+        .map_err(|e| ScraperError::ParsingError(format!("Failed to serialize JSON: {}", e)))
+}
+
+/// Serializes items as JSON Lines (NDJSON): one JSON object per line, each ending in `\n`.
+pub fn to_ndjson<T: Serialize>(items: &[T]) -> Result<String> {
+    let mut out = String::new();
+    for item in items {
+        let line = serde_json::to_string(item).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to serialize NDJSON record: {}", e))
+        })?;
+        out.push_str(&line);
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Writes topics as JSON Lines (NDJSON), one record at a time.
+///
+/// Each topic is flushed to `writer` as soon as it is serialized, so records can be
+/// streamed out while they are still being scraped instead of buffered in memory.
+pub fn write_topics_ndjson<'a, W: Write>(
+    mut writer: W,
+    topics: impl Iterator<Item = &'a TopicDetail>,
+) -> Result<()> {
+    for topic in topics {
+        serde_json::to_writer(&mut writer, topic).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to write NDJSON record: {}", e))
+        })?;
+        writer
+            .write_all(b"\n")
+            .and_then(|_| writer.flush())
+            .map_err(|e| {
+                ScraperError::ParsingError(format!("Failed to write NDJSON record: {}", e))
+            })?;
+    }
+
+    Ok(())
+}
+
+/// Quotes a CSV field (RFC 4180) when it contains a separator, quote or line break.
+#[cfg(feature = "csv")]
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Appends one CSV record terminated with CRLF.
+#[cfg(feature = "csv")]
+fn push_csv_row(out: &mut String, fields: &[&str]) {
+    let row: Vec<String> = fields.iter().map(|field| csv_field(field)).collect();
+    out.push_str(&row.join(","));
+    out.push_str("\r\n");
+}
+
+/// Renders the transcript as CSV, one row per course after a header row.
+///
+/// Columns follow [`Grade`](crate::models::Grade): `course_code`, `course_name`,
+/// `credits`, `letter_grade`, `grade_point` and `period`, the latter written as its
+/// academic year string (e.g. `2025/2026 - Ganjil`).
+#[cfg(feature = "csv")]
+pub fn grades_to_csv(transcript: &Transcript) -> String {
+    let mut out = String::new();
+    push_csv_row(
+        &mut out,
+        &[
+            "course_code",
+            "course_name",
+            "credits",
+            "letter_grade",
+            "grade_point",
+            "period",
+        ],
+    );
+    for grade in &transcript.grades {
+        push_csv_row(
+            &mut out,
+            &[
+                &grade.course_code,
+                &grade.course_name,
+                &grade.credits.to_string(),
+                &grade.letter_grade,
+                &grade.grade_point.to_string(),
+                &grade.period.to_academic_year_string(),
+            ],
+        );
+    }
+    out
+}
+
+/// Renders attendance records as CSV, one row per meeting after a header row.
+///
+/// Columns are `meeting_number`, `date` (`YYYY-MM-DD`, empty when unknown) and `status`,
+/// written as its Indonesian label (e.g. `Hadir`).
+#[cfg(feature = "csv")]
+pub fn attendance_to_csv(records: &[AttendanceRecord]) -> String {
+    let mut out = String::new();
+    push_csv_row(&mut out, &["meeting_number", "date", "status"]);
+    for record in records {
+        let date = record
+            .date
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        push_csv_row(
+            &mut out,
+            &[
+                &record.meeting_number.to_string(),
+                &date,
+                record.status.label(),
+            ],
+        );
+    }
+    out
+}
+
+/// Escapes a TEXT value for iCalendar (RFC 5545, section 3.3.11).
+fn ical_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Appends a content line, folded at 75 octets and terminated with CRLF.
+fn push_ical_line(out: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+/// Renders the weekly timetable as an iCalendar (`.ics`) document.
+///
+/// Every entry becomes a VEVENT in the `Asia/Jakarta` time zone. It starts on the first
+/// matching weekday of the period and repeats weekly until the period's last day (see
+/// [`Period::date_range`]). `SUMMARY` is the course name, `LOCATION` the room and
+/// `DESCRIPTION` the course code and lecturer.
+pub fn to_ics(entries: &[ScheduleEntry], period: Period) -> String {
+    let (first_day, last_day) = period.date_range();
+    // 23:59:59 WIB on the last day, expressed in UTC as RRULE requires with a TZID start.
+    let until = last_day.and_hms_opt(16, 59, 59).expect("valid time");
+    let stamp = Utc::now().format("%Y%m%dT%H%M%SZ");
+
+    let mut out = String::new();
+    for line in [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        "PRODID:-//spotifier-core//Jadwal//ID",
+        "CALSCALE:GREGORIAN",
+        "BEGIN:VTIMEZONE",
+        "TZID:Asia/Jakarta",
+        "BEGIN:STANDARD",
+        "DTSTART:19700101T000000",
+        "TZOFFSETFROM:+0700",
+        "TZOFFSETTO:+0700",
+        "TZNAME:WIB",
+        "END:STANDARD",
+        "END:VTIMEZONE",
+    ] {
+        push_ical_line(&mut out, line);
+    }
+
+    for entry in entries {
+        let days_ahead = (7 + entry.day_of_week.num_days_from_monday()
+            - first_day.weekday().num_days_from_monday())
+            % 7;
+        let date: NaiveDate = first_day + Duration::days(i64::from(days_ahead));
+        let start = date.and_time(entry.start_time);
+        let end = date.and_time(entry.end_time);
+
+        push_ical_line(&mut out, "BEGIN:VEVENT");
+        push_ical_line(
+            &mut out,
+            &format!(
+                "UID:{}-{}-{}-{}@spotifier-core",
+                period.format(),
+                entry.course_code,
+                entry.day_of_week,
+                entry.start_time.format("%H%M")
+            ),
+        );
+        push_ical_line(&mut out, &format!("DTSTAMP:{}", stamp));
+        push_ical_line(
+            &mut out,
+            &format!(
+                "DTSTART;TZID=Asia/Jakarta:{}",
+                start.format("%Y%m%dT%H%M%S")
+            ),
+        );
+        push_ical_line(
+            &mut out,
+            &format!("DTEND;TZID=Asia/Jakarta:{}", end.format("%Y%m%dT%H%M%S")),
+        );
+        push_ical_line(
+            &mut out,
+            &format!("RRULE:FREQ=WEEKLY;UNTIL={}Z", until.format("%Y%m%dT%H%M%S")),
+        );
+        push_ical_line(
+            &mut out,
+            &format!("SUMMARY:{}", ical_escape(&entry.course_name)),
+        );
+        if !entry.room.is_empty() {
+            push_ical_line(&mut out, &format!("LOCATION:{}", ical_escape(&entry.room)));
+        }
+        push_ical_line(
+            &mut out,
+            &format!(
+                "DESCRIPTION:{}",
+                ical_escape(&format!("{}\nDosen: {}", entry.course_code, entry.lecturer))
+            ),
+        );
+        push_ical_line(&mut out, "END:VEVENT");
+    }
+
+    push_ical_line(&mut out, "END:VCALENDAR");
+    out
+}
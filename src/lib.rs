@@ -1,10 +1,59 @@
+#[cfg(feature = "client")]
 mod cache;
+#[cfg(feature = "client")]
 mod client;
+#[cfg(feature = "client")]
+mod cookies;
+#[cfg(feature = "client")]
+mod credentials;
 mod error;
+#[cfg(feature = "export")]
+mod export;
+mod hash;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "client")]
+mod metrics;
 mod models;
-mod parsers;
+pub mod parsers;
+pub mod prelude;
+#[cfg(feature = "client")]
+mod rate_limit;
+#[cfg(feature = "client")]
+mod recording;
+#[cfg(feature = "client")]
+mod retry;
+#[cfg(feature = "client")]
+mod transport;
+#[cfg(feature = "client")]
+mod upload;
 
-pub use cache::{CacheBackend, FileCache};
-pub use client::SpotifierCoreClient;
+#[cfg(feature = "redis")]
+pub use cache::RedisCache;
+#[cfg(feature = "client")]
+pub use cache::{CacheBackend, FileCache, MemoryCache};
+#[cfg(feature = "client")]
+pub use client::{
+    AutoReloginConfig, CourseListResult, CredentialProvider, HealthStatus, SpotifierCoreClient,
+    SpotifierCoreClientBuilder,
+};
+#[cfg(feature = "client")]
+pub use credentials::Credentials;
 pub use error::{Result, ScraperError};
+#[cfg(feature = "csv")]
+pub use export::{attendance_to_csv, grades_to_csv};
+#[cfg(feature = "export")]
+pub use export::{to_ics, to_json_pretty, to_ndjson, write_topics_ndjson};
+#[cfg(feature = "client")]
+pub use metrics::ClientMetrics;
 pub use models::*;
+#[cfg(feature = "client")]
+pub use rate_limit::RateLimiter;
+#[cfg(feature = "client")]
+pub use recording::{DryRunRequest, Recording};
+#[cfg(feature = "client")]
+pub use retry::{parse_retry_after, with_retry};
+#[cfg(feature = "client")]
+pub use transport::{HttpTransport, ReplayTransport, ReqwestTransport};
+#[cfg(feature = "client")]
+pub use upload::{DEFAULT_UPLOAD_TYPES, detect_mime_type};
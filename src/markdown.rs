@@ -0,0 +1,169 @@
+use crate::models::Content;
+use crate::parsers::{DEFAULT_BASE_URL, parse_youtube_url};
+use html2md::common::get_tag_attr;
+use html2md::iframes::IframeHandler;
+use html2md::{Handle, StructuredPrinter, TagHandler, TagHandlerFactory};
+use std::collections::HashMap;
+use url::Url;
+
+/// Renders embedded YouTube players as a plain `[title](watch_url)` link.
+///
+/// Other iframes are delegated to html2md's default iframe handling.
+struct YoutubeLinkHandler;
+
+impl TagHandler for YoutubeLinkHandler {
+    fn handle(&mut self, tag: &Handle, printer: &mut StructuredPrinter) {
+        let video = get_tag_attr(tag, "src").and_then(|src| parse_youtube_url(&src));
+
+        let Some((youtube_id, youtube_start_secs)) = video else {
+            IframeHandler.handle(tag, printer);
+            return;
+        };
+
+        let title = get_tag_attr(tag, "title")
+            .map(|title| title.trim().to_string())
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| "YouTube video".to_string());
+
+        printer.insert_newline();
+        printer.insert_newline();
+        let video = Content {
+            id: 0,
+            youtube_id: Some(youtube_id),
+            youtube_start_secs,
+            raw_html: String::new(),
+            page_href: String::new(),
+        };
+        if let Some(watch_url) = video.youtube_watch_url() {
+            printer.append_str(&format!("[{}]({})", title, watch_url));
+        }
+    }
+
+    fn after_handle(&mut self, _printer: &mut StructuredPrinter) {}
+
+    fn skip_descendants(&self) -> bool {
+        true
+    }
+}
+
+struct YoutubeLinkHandlerFactory;
+
+impl TagHandlerFactory for YoutubeLinkHandlerFactory {
+    fn instantiate(&self) -> Box<dyn TagHandler> {
+        Box::new(YoutubeLinkHandler)
+    }
+}
+
+/// Resolves a possibly relative `href`/`src` against the SPOT base URL.
+fn resolve(base_url: Option<&Url>, link: &str) -> String {
+    base_url
+        .and_then(|base| base.join(link.trim()).ok())
+        .map(String::from)
+        .unwrap_or_else(|| link.trim().to_string())
+}
+
+/// Renders `<a>` as a Markdown link with an absolute URL.
+///
+/// Anchors without an `href` keep only their text.
+struct AnchorHandler {
+    base_url: Option<Url>,
+    start_pos: usize,
+    href: Option<String>,
+}
+
+impl TagHandler for AnchorHandler {
+    fn handle(&mut self, tag: &Handle, printer: &mut StructuredPrinter) {
+        self.start_pos = printer.data.len();
+        self.href = get_tag_attr(tag, "href")
+            .filter(|href| !href.trim().is_empty())
+            .map(|href| resolve(self.base_url.as_ref(), &href));
+    }
+
+    fn after_handle(&mut self, printer: &mut StructuredPrinter) {
+        if let Some(href) = &self.href {
+            printer.insert_str(self.start_pos, "[");
+            printer.append_str(&format!("]({})", href));
+        }
+    }
+}
+
+/// Renders `<img>` as `![alt](src)` with an absolute URL.
+///
+/// Size and style attributes are dropped rather than falling back to inline HTML.
+struct ImageHandler {
+    base_url: Option<Url>,
+}
+
+impl TagHandler for ImageHandler {
+    fn handle(&mut self, tag: &Handle, printer: &mut StructuredPrinter) {
+        let Some(src) = get_tag_attr(tag, "src").filter(|src| !src.trim().is_empty()) else {
+            return;
+        };
+
+        let alt = get_tag_attr(tag, "alt").unwrap_or_default();
+        let src = resolve(self.base_url.as_ref(), &src).replace(' ', "%20");
+        printer.append_str(&format!("![{}]({})", alt.trim(), src));
+    }
+
+    fn after_handle(&mut self, _printer: &mut StructuredPrinter) {}
+}
+
+struct LinkHandlerFactory {
+    base_url: Option<Url>,
+    image: bool,
+}
+
+impl TagHandlerFactory for LinkHandlerFactory {
+    fn instantiate(&self) -> Box<dyn TagHandler> {
+        let base_url = self.base_url.clone();
+        if self.image {
+            Box::new(ImageHandler { base_url })
+        } else {
+            Box::new(AnchorHandler {
+                base_url,
+                start_pos: 0,
+                href: None,
+            })
+        }
+    }
+}
+
+impl Content {
+    /// Converts the content's raw HTML into Markdown.
+    ///
+    /// Headings, lists, links (including attachments), images and emphasis are
+    /// preserved, while wrapper `<div>`s and inline styles are dropped. Relative links
+    /// are resolved against SPOT's default base URL. Embedded YouTube videos are
+    /// rendered as a `[title](watch_url)` link.
+    pub fn to_markdown(&self) -> String {
+        self.to_markdown_with_base(DEFAULT_BASE_URL)
+    }
+
+    /// Like [`Content::to_markdown`], but resolves relative links against `base_url`
+    /// (e.g. `SpotifierCoreClient::base_url`). Links are left as-is if `base_url` is
+    /// not a valid URL.
+    pub fn to_markdown_with_base(&self, base_url: &str) -> String {
+        let base_url = Url::parse(base_url).ok();
+
+        let mut handlers: HashMap<String, Box<dyn TagHandlerFactory>> = HashMap::new();
+        handlers.insert("iframe".to_string(), Box::new(YoutubeLinkHandlerFactory));
+        handlers.insert(
+            "a".to_string(),
+            Box::new(LinkHandlerFactory {
+                base_url: base_url.clone(),
+                image: false,
+            }),
+        );
+        handlers.insert(
+            "img".to_string(),
+            Box::new(LinkHandlerFactory {
+                base_url,
+                image: true,
+            }),
+        );
+
+        html2md::parse_html_custom(&self.raw_html, &handlers)
+            .trim()
+            .to_string()
+    }
+}
@@ -0,0 +1,124 @@
+use bytes::Bytes;
+use http_body::{Body, Frame, SizeHint};
+use std::collections::BTreeMap;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+/// A snapshot of a client's request counters, taken with
+/// [`SpotifierCoreClient::metrics`](crate::SpotifierCoreClient::metrics).
+///
+/// The counters are shared by a client and its clones and start at zero when the
+/// client is built. A rising `failures` count is usually the first sign of SPOT
+/// blocking the session; a low cache hit rate means the cache is not doing much.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClientMetrics {
+    /// HTTP requests sent, counting every retry attempt.
+    pub requests: u64,
+    /// Failed requests by [`ScraperError::kind`](crate::ScraperError::kind): transport
+    /// errors, and 4xx/5xx responses as `"http_status"` (`"rate_limited"` for 429).
+    pub failures: BTreeMap<&'static str, u64>,
+    /// Requests sent again after a transient failure.
+    pub retries: u64,
+    /// Lookups answered from the cache.
+    pub cache_hits: u64,
+    /// Lookups that found nothing usable in the cache.
+    pub cache_misses: u64,
+    /// Response body bytes read from SPOT and SSO.
+    pub bytes_downloaded: u64,
+}
+
+impl ClientMetrics {
+    /// Returns the total number of failed requests, across all kinds.
+    pub fn total_failures(&self) -> u64 {
+        self.failures.values().sum()
+    }
+}
+
+/// The live counters behind [`ClientMetrics`].
+#[derive(Debug, Default)]
+pub(crate) struct MetricsRecorder {
+    requests: AtomicU64,
+    failures: Mutex<BTreeMap<&'static str, u64>>,
+    retries: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    bytes_downloaded: Arc<AtomicU64>,
+}
+
+impl MetricsRecorder {
+    pub(crate) fn record_request(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_failure(&self, kind: &'static str) {
+        *self.failures.lock().unwrap().entry(kind).or_default() += 1;
+    }
+
+    pub(crate) fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_cache_lookup(&self, hit: bool) {
+        let counter = if hit {
+            &self.cache_hits
+        } else {
+            &self.cache_misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Wraps a response body so the bytes are counted as they are read.
+    ///
+    /// Counting on read rather than buffering keeps streamed downloads streaming.
+    pub(crate) fn count_body(&self, body: reqwest::Body) -> reqwest::Body {
+        reqwest::Body::wrap(CountingBody {
+            inner: body,
+            counter: Arc::clone(&self.bytes_downloaded),
+        })
+    }
+
+    pub(crate) fn snapshot(&self) -> ClientMetrics {
+        ClientMetrics {
+            requests: self.requests.load(Ordering::Relaxed),
+            failures: self.failures.lock().unwrap().clone(),
+            retries: self.retries.load(Ordering::Relaxed),
+            cache_hits: self.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.cache_misses.load(Ordering::Relaxed),
+            bytes_downloaded: self.bytes_downloaded.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// A response body that adds the size of every data frame to `counter`.
+struct CountingBody {
+    inner: reqwest::Body,
+    counter: Arc<AtomicU64>,
+}
+
+impl Body for CountingBody {
+    type Data = Bytes;
+    type Error = reqwest::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Bytes>, reqwest::Error>>> {
+        let poll = Pin::new(&mut self.inner).poll_frame(cx);
+        if let Poll::Ready(Some(Ok(frame))) = &poll
+            && let Some(data) = frame.data_ref()
+        {
+            self.counter.fetch_add(data.len() as u64, Ordering::Relaxed);
+        }
+        poll
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
 
 /// Represents a user profile on the SPOT platform.
@@ -10,6 +10,13 @@ pub struct User {
     pub nim: String,
 }
 
+impl std::fmt::Display for User {
+    /// Formats as `Budi Santoso (2101234)`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({})", self.name, self.nim)
+    }
+}
+
 /// Represents a course the student is currently or was previously enrolled in.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Course {
@@ -20,17 +27,135 @@ pub struct Course {
     /// The full name of the course.
     pub name: String,
     /// Number of academic credits (SKS) awarded for the course.
+    ///
+    /// `0` when SPOT shows no readable credit count for the course.
     pub credits: u8,
-    /// The name of the primary lecturer for the course.
+    /// The name of the primary lecturer for the course, i.e. the first of `lecturers`.
     pub lecturer: String,
+    /// Every lecturer listed for the course, in the order SPOT shows them.
+    #[serde(default)]
+    pub lecturers: Vec<String>,
     /// The academic year and semester string (e.g., "2025/2026 - Ganjil").
     pub academic_year: String,
     /// The URL path to the course's detail page.
+    ///
+    /// Like every href in the models, this is normalized with
+    /// [`normalize_href`](crate::parsers::normalize_href): a path for pages on SPOT, an
+    /// absolute URL for other hosts.
     pub href: String,
 }
 
+impl Course {
+    /// Parses the course ID from the trailing numeric segment of `href`.
+    ///
+    /// Accepts relative paths and absolute URLs, ignoring trailing slashes, query
+    /// strings and fragments, so `/mhs/topik/2510009532/?tab=rps` yields `2510009532`.
+    /// Returns `None` if the last segment is not a number. Use it to call the by-ID
+    /// methods, such as [`get_course_detail_by_id`](crate::SpotifierCoreClient::get_course_detail_by_id).
+    pub fn detail_id(&self) -> Option<u64> {
+        self.href
+            .split(['?', '#'])
+            .next()?
+            .trim_end_matches('/')
+            .rsplit('/')
+            .next()?
+            .parse()
+            .ok()
+    }
+
+    /// Merges two records of the same course seen in different periods.
+    ///
+    /// The record from the latest academic period wins for every field; values it is
+    /// missing (empty strings or zero credits) are filled in from the older record.
+    /// When the periods are equal or cannot be parsed, `other` is treated as the latest.
+    pub fn merge(&self, other: &Course) -> Course {
+        let self_period = Period::from_academic_year_string(&self.academic_year).ok();
+        let other_period = Period::from_academic_year_string(&other.academic_year).ok();
+
+        let (latest, older) = match (self_period, other_period) {
+            (Some(a), Some(b)) if a > b => (self, other),
+            (Some(_), None) => (self, other),
+            _ => (other, self),
+        };
+
+        let pick = |new: &String, old: &String| {
+            if new.trim().is_empty() {
+                old.clone()
+            } else {
+                new.clone()
+            }
+        };
+
+        Course {
+            id: latest.id,
+            code: pick(&latest.code, &older.code),
+            name: pick(&latest.name, &older.name),
+            credits: if latest.credits == 0 {
+                older.credits
+            } else {
+                latest.credits
+            },
+            lecturer: pick(&latest.lecturer, &older.lecturer),
+            lecturers: if latest.lecturers.is_empty() {
+                older.lecturers.clone()
+            } else {
+                latest.lecturers.clone()
+            },
+            academic_year: pick(&latest.academic_year, &older.academic_year),
+            href: pick(&latest.href, &older.href),
+        }
+    }
+}
+
+impl std::fmt::Display for Course {
+    /// Formats as `IK410 - Pemrograman Web (3 SKS) — Dr. Budi`, leaving out the credits
+    /// when unknown and the lecturers when none are listed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.code, self.name)?;
+        if self.credits > 0 {
+            write!(f, " ({} SKS)", self.credits)?;
+        }
+        if !self.lecturers.is_empty() {
+            write!(f, " — {}", self.lecturers.join(", "))?;
+        } else if !self.lecturer.is_empty() {
+            write!(f, " — {}", self.lecturer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Courses are identified by `id` alone, so the same course scraped in different periods
+/// (or with an updated lecturer list) compares equal. Courses whose ID could not be
+/// parsed (`id == 0`) are identified by `href` and `code` instead.
+impl PartialEq for Course {
+    fn eq(&self, other: &Self) -> bool {
+        self.identity() == other.identity()
+    }
+}
+
+impl Eq for Course {}
+
+impl std::hash::Hash for Course {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.identity().hash(state);
+    }
+}
+
+impl Course {
+    /// What `Eq` and `Hash` compare: the ID, or `href` and `code` when the ID is unknown.
+    fn identity(&self) -> (u64, &str, &str) {
+        match self.id {
+            0 => (0, self.href.as_str(), self.code.as_str()),
+            id => (id, "", ""),
+        }
+    }
+}
+
 /// Information about the Rencana Pembelajaran Semester (RPS/Syllabus).
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// Some courses never upload an RPS; in that case both fields are `None`
+/// (see [`Rps::is_available`]).
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq)]
 pub struct Rps {
     /// Unique identifier for the RPS document.
     pub id: Option<u64>,
@@ -38,6 +163,35 @@ pub struct Rps {
     pub href: Option<String>,
 }
 
+impl Rps {
+    /// Returns `true` if the course has an RPS document to download.
+    pub fn is_available(&self) -> bool {
+        self.href.is_some()
+    }
+}
+
+/// Whether a topic can be opened and, if not, why.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Accessibility {
+    /// The topic can be opened.
+    #[default]
+    Open,
+    /// The topic is locked until the given (future) time, in WIB.
+    LockedUntil(NaiveDateTime),
+    /// The topic opens once an earlier topic (or its task) is completed.
+    LockedPrerequisite,
+    /// The topic is locked without a stated reason, e.g. closed by the lecturer.
+    Closed,
+}
+
+impl Accessibility {
+    /// Whether the topic can be opened right now.
+    pub fn is_open(&self) -> bool {
+        matches!(self, Accessibility::Open)
+    }
+}
+
 /// Summary information for a learning topic within a course.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct TopicInfo {
@@ -47,24 +201,134 @@ pub struct TopicInfo {
     pub course_id: Option<u64>,
     /// The timestamp when the user last accessed this topic.
     pub access_time: Option<NaiveDateTime>,
-    /// Whether the topic is currently open for students.
+    /// The timestamp when the topic was last updated, if SPOT shows one.
+    #[serde(default)]
+    pub updated_at: Option<NaiveDateTime>,
+    /// Whether the topic is currently open for students; the same as
+    /// `accessibility.is_open()`.
     pub is_accessible: bool,
+    /// Whether the topic is open and, if not, why it is locked.
+    ///
+    /// Data stored before this field existed reads as `Open`; rely on `is_accessible`
+    /// for it.
+    #[serde(default)]
+    pub accessibility: Accessibility,
     /// URL path to the topic's detail page.
     pub href: Option<String>,
 }
 
+impl TopicInfo {
+    /// Returns `(course_id, topic_id)` when both identifiers are known.
+    pub fn ids(&self) -> Option<(u64, u64)> {
+        Some((self.course_id?, self.id?))
+    }
+}
+
+/// Topics are identified by `course_id` and `id`.
+///
+/// A topic without an `id` (a locked topic, whose link SPOT hides) has nothing that
+/// identifies it, so it only equals itself and a `HashSet` never merges two of them.
+impl PartialEq for TopicInfo {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.id, other.id) {
+            (Some(a), Some(b)) => self.course_id == other.course_id && a == b,
+            (None, None) => std::ptr::eq(self, other),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for TopicInfo {}
+
+impl std::hash::Hash for TopicInfo {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.course_id.hash(state);
+        self.id.hash(state);
+    }
+}
+
 /// Detailed data for a specific course, including its list of topics.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DetailCourse {
     /// Basic identification and meta-data for the course.
     #[serde(flatten)]
     pub course_info: Course,
-    /// A textual description or overview of the course.
+    /// The course description as raw HTML, exactly as rendered by SPOT.
+    ///
+    /// Links and formatting are preserved; use [`DetailCourse::description_text`]
+    /// when plain text is needed.
     pub description: String,
     /// Syllabus information.
     pub rps: Rps,
     /// List of learning topics available in this course.
     pub topics: Vec<TopicInfo>,
+    /// The lecturer's email address, if the course page shows one.
+    #[serde(default)]
+    pub lecturer_email: Option<String>,
+    /// Link to the lecturer's profile, if the course page shows one.
+    ///
+    /// A path (e.g. `/dosen/profil/123`) for pages on SPOT itself, or an absolute URL for
+    /// profiles hosted elsewhere.
+    #[serde(default)]
+    pub lecturer_profile_href: Option<String>,
+}
+
+impl DetailCourse {
+    /// Returns the course description with all HTML markup stripped.
+    pub fn description_text(&self) -> String {
+        crate::parsers::html_to_text(&self.description)
+    }
+
+    /// Iterates over the topics the student can currently open.
+    pub fn topics_accessible(&self) -> impl Iterator<Item = &TopicInfo> {
+        self.topics.iter().filter(|topic| topic.is_accessible)
+    }
+
+    /// Returns the course's RPS, or `ScraperError::RpsNotAvailable` if none was uploaded.
+    pub fn available_rps(&self) -> crate::error::Result<&Rps> {
+        if self.rps.is_available() {
+            Ok(&self.rps)
+        } else {
+            Err(crate::error::ScraperError::RpsNotAvailable(
+                self.course_info.id,
+            ))
+        }
+    }
+}
+
+/// A lightweight overview of a course, cheap to cache and to send to list screens.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CourseSummary {
+    /// Unique internal identifier for the course.
+    pub course_id: u64,
+    /// The official course code (e.g., "IK410").
+    pub code: String,
+    /// The full name of the course.
+    pub name: String,
+    /// Total number of topics listed in the course.
+    pub topic_count: usize,
+    /// Number of topics currently open for students.
+    pub accessible_topic_count: usize,
+    /// Number of tasks across the accessible topics that have no submission yet.
+    pub pending_task_count: usize,
+}
+
+impl CourseSummary {
+    /// Builds a summary from a course detail and the details of its accessible topics.
+    pub fn from_details(detail: &DetailCourse, topics: &[TopicDetail]) -> Self {
+        Self {
+            course_id: detail.course_info.id,
+            code: detail.course_info.code.clone(),
+            name: detail.course_info.name.clone(),
+            topic_count: detail.topics.len(),
+            accessible_topic_count: detail.topics.iter().filter(|t| t.is_accessible).count(),
+            pending_task_count: topics
+                .iter()
+                .flat_map(|topic| &topic.tasks)
+                .filter(|task| task.answer.is_none())
+                .count(),
+        }
+    }
 }
 
 /// A specific content item (e.g., video or reading material) within a topic.
@@ -74,31 +338,126 @@ pub struct Content {
     pub id: u32,
     /// The YouTube video ID, if the content is an embedded video.
     pub youtube_id: Option<String>,
+    /// Where the embedded video starts playing, in seconds, if the lecturer set one.
+    #[serde(default)]
+    pub youtube_start_secs: Option<u32>,
     /// Raw HTML content for text/article-based materials.
     pub raw_html: String,
+    /// The path of the topic page the content was parsed from. Relative links in
+    /// `raw_html` are resolved against it; empty means the site root.
+    #[serde(default)]
+    pub page_href: String,
+}
+
+/// What a [`Content`] item is, as classified by [`Content::kind`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ContentKind {
+    /// An embedded YouTube video.
+    Video {
+        /// The YouTube video ID.
+        youtube_id: String,
+    },
+    /// Reading material, as raw HTML.
+    Article {
+        /// The HTML of the content block.
+        html: String,
+    },
+    /// A downloadable attachment, such as slides or a module PDF.
+    File {
+        /// The href of the file, normalized with [`normalize_href`](crate::parsers::normalize_href).
+        href: String,
+        /// The link text, or the last path segment of `href` if the link has none.
+        name: String,
+    },
+    /// A link to another page, with nothing else in the block.
+    Link {
+        /// The link target, normalized like every other href in the models.
+        url: String,
+    },
+    /// An empty block or an embed this crate does not recognize yet.
+    Unknown,
+}
+
+impl Content {
+    /// Classifies the content item.
+    ///
+    /// A YouTube embed is a `Video`. Otherwise a block with text besides its links is an
+    /// `Article`; a block holding only a link is a `File` if the link points to a
+    /// document (by extension or SPOT's download paths) and a `Link` if not.
+    pub fn kind(&self) -> ContentKind {
+        match &self.youtube_id {
+            Some(youtube_id) => ContentKind::Video {
+                youtube_id: youtube_id.clone(),
+            },
+            None => crate::parsers::topic_detail::classify_content(&self.raw_html, &self.page_href),
+        }
+    }
+
+    /// Returns the `youtube.com/watch` link of the embedded video, keeping its start time.
+    pub fn youtube_watch_url(&self) -> Option<String> {
+        let id = self.youtube_id.as_ref()?;
+        Some(match self.youtube_start_secs {
+            Some(start) => format!("https://www.youtube.com/watch?v={}&t={}s", id, start),
+            None => format!("https://www.youtube.com/watch?v={}", id),
+        })
+    }
+
+    /// Returns the `youtube.com/embed` link of the embedded video, keeping its start time.
+    pub fn youtube_embed_url(&self) -> Option<String> {
+        let id = self.youtube_id.as_ref()?;
+        Some(match self.youtube_start_secs {
+            Some(start) => format!("https://www.youtube.com/embed/{}?start={}", id, start),
+            None => format!("https://www.youtube.com/embed/{}", id),
+        })
+    }
 }
 
 /// The current status of a student's task submission.
-#[derive(Debug, Serialize, Deserialize, Clone)]
+///
+/// The serialized form is part of the cache format and must not change. Each variant
+/// is written as a `snake_case` string: `"pending"`, `"submitted"`, `"graded"` and
+/// `"not_submitted"`. The variant names written by older versions (e.g. `"Submitted"`)
+/// are still accepted when reading.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
 pub enum TaskStatus {
     /// Task has not been interacted with yet.
+    #[serde(alias = "Pending")]
     Pending,
     /// Task has been successfully uploaded but not yet graded.
+    #[serde(alias = "Submitted")]
     Submitted,
     /// Task has been reviewed and assigned a score.
+    #[serde(alias = "Graded")]
     Graded,
     /// The submission deadline has passed without a submission.
+    #[serde(alias = "NotSubmitted")]
     NotSubmitted,
 }
 
+impl std::fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            TaskStatus::Pending => "pending",
+            TaskStatus::Submitted => "submitted",
+            TaskStatus::Graded => "graded",
+            TaskStatus::NotSubmitted => "not submitted",
+        })
+    }
+}
+
 /// Represents a student's submission for a specific task.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Answer {
     /// Unique identifier for the submission.
     pub id: Option<u64>,
-    /// The text content or description provided by the student.
+    /// The text submitted by the student, as HTML exactly as SPOT renders it.
+    ///
+    /// Plain-text submissions are HTML-escaped text; formatted ones keep their markup.
+    /// Use [`Answer::content_text`] when plain text is needed.
     pub content: String,
-    /// URL to the uploaded file, if any.
+    /// URL path to the uploaded file, if any.
     pub file_href: Option<String>,
     /// Whether the lecturer has graded this submission.
     pub is_graded: bool,
@@ -108,6 +467,49 @@ pub struct Answer {
     pub score: f32,
     /// The timestamp when the submission was uploaded.
     pub date_submitted: Option<NaiveDateTime>,
+    /// Per-criterion score breakdown, when the lecturer graded with a rubric.
+    ///
+    /// Empty for plain grades.
+    #[serde(default)]
+    pub rubric: Vec<RubricItem>,
+}
+
+/// A single criterion of a rubric-graded [`Answer`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RubricItem {
+    /// The criterion name as shown in the grading table.
+    pub name: String,
+    /// Points awarded for this criterion.
+    pub points: f32,
+    /// Maximum points available for this criterion.
+    pub max_points: f32,
+}
+
+impl Answer {
+    /// Returns the submitted content with all HTML markup stripped.
+    pub fn content_text(&self) -> String {
+        crate::parsers::html_to_text(&self.content)
+    }
+
+    /// Returns the number of characters in the plain-text content, counted the same
+    /// way as [`Task::validate_content`].
+    pub fn char_count(&self) -> usize {
+        self.content_text().chars().count()
+    }
+}
+
+/// How pressing a task is, as returned by [`Task::urgency`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Urgency {
+    /// The deadline has passed.
+    Overdue,
+    /// The deadline is within the "due soon" threshold.
+    DueSoon,
+    /// The task is open and the deadline (if any) is further away.
+    Upcoming,
+    /// Submissions are not open yet.
+    NotStarted,
 }
 
 /// Represents a task or assignment within a topic.
@@ -125,7 +527,7 @@ pub struct Task {
     pub title: String,
     /// Detailed instructions for the task.
     pub description: String,
-    /// Name of the reference file provided by the lecturer, if any.
+    /// URL path to the reference file provided by the lecturer, if any.
     pub file: Option<String>,
     /// When the task becomes available for submission.
     pub start_date: Option<NaiveDateTime>,
@@ -135,6 +537,310 @@ pub struct Task {
     pub status: TaskStatus,
     /// The student's submission data, if they have uploaded anything.
     pub answer: Option<Answer>,
+    /// Minimum number of characters required in the submission text, if enforced.
+    #[serde(default)]
+    pub min_chars: Option<u32>,
+    /// Maximum number of characters allowed in the submission text, if enforced.
+    #[serde(default)]
+    pub max_chars: Option<u32>,
+}
+
+/// A possibly open-ended window of time.
+///
+/// A missing `start` means the range has been open since the beginning of time, and a
+/// missing `end` means it never closes. Both bounds are inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateRange {
+    /// The first moment inside the range, if bounded.
+    pub start: Option<NaiveDateTime>,
+    /// The last moment inside the range, if bounded.
+    pub end: Option<NaiveDateTime>,
+}
+
+impl DateRange {
+    /// Returns `true` if `at` falls within the range.
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        self.start.is_none_or(|start| at >= start) && self.end.is_none_or(|end| at <= end)
+    }
+}
+
+impl Task {
+    /// Returns the window in which the task accepts submissions.
+    ///
+    /// A missing `start_date` means the task is open from the beginning and a missing
+    /// `due_date` means it has no deadline.
+    pub fn submission_window(&self) -> DateRange {
+        DateRange {
+            start: self.start_date,
+            end: self.due_date,
+        }
+    }
+
+    /// Returns `true` if the task accepts submissions at `now` (deadline inclusive).
+    pub fn is_open(&self, now: NaiveDateTime) -> bool {
+        self.submission_window().contains(now)
+    }
+
+    /// Returns the time left until the deadline, negative once it has passed.
+    ///
+    /// Returns `None` if the task has no deadline.
+    pub fn time_until_due(&self, now: NaiveDateTime) -> Option<chrono::Duration> {
+        self.due_date.map(|due| due - now)
+    }
+
+    /// Returns `true` if the deadline has passed. Tasks without a deadline are never
+    /// overdue. Only dates are considered, not whether the task was submitted.
+    pub fn is_overdue(&self, now: NaiveDateTime) -> bool {
+        self.due_date.is_some_and(|due| now > due)
+    }
+
+    /// Classifies how pressing the task is at `now`.
+    ///
+    /// A task is `DueSoon` when its deadline is at most `due_soon` away. Tasks without
+    /// a deadline are `Upcoming` once started. Like [`Task::is_overdue`], this looks at
+    /// dates only.
+    pub fn urgency(&self, now: NaiveDateTime, due_soon: chrono::Duration) -> Urgency {
+        if self.start_date.is_some_and(|start| now < start) {
+            Urgency::NotStarted
+        } else if self.is_overdue(now) {
+            Urgency::Overdue
+        } else if self
+            .time_until_due(now)
+            .is_some_and(|remaining| remaining <= due_soon)
+        {
+            Urgency::DueSoon
+        } else {
+            Urgency::Upcoming
+        }
+    }
+
+    /// Checks `content` against the task's character limits.
+    ///
+    /// Returns `ScraperError::ContentLengthInvalid` when the content is too short or too
+    /// long. Limits that are `None` are not enforced.
+    pub fn validate_content(&self, content: &str) -> crate::error::Result<()> {
+        let actual = content.chars().count() as u32;
+        let too_short = self.min_chars.is_some_and(|min| actual < min);
+        let too_long = self.max_chars.is_some_and(|max| actual > max);
+
+        if too_short || too_long {
+            return Err(crate::error::ScraperError::ContentLengthInvalid {
+                min: self.min_chars,
+                max: self.max_chars,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Task {
+    /// Formats as `Esai Refleksi [submitted, due 2025-09-08 23:59]`; a graded task also
+    /// shows its score, e.g. `[graded 85, ...]`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} [{}", self.title, self.status)?;
+        if let Some(answer) = self.answer.as_ref().filter(|a| a.is_graded) {
+            write!(f, " {}", answer.score)?;
+        }
+        if let Some(due) = self.due_date {
+            write!(f, ", due {}", due.format("%Y-%m-%d %H:%M"))?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// Tasks are identified by `id`.
+///
+/// A task without an `id` (one whose form could not be parsed) only equals itself, so a
+/// `HashSet` never merges two of them.
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.id, other.id) {
+            (Some(a), Some(b)) => a == b,
+            (None, None) => std::ptr::eq(self, other),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Task {}
+
+impl std::hash::Hash for Task {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// Represents a quiz (kuis) within a topic.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Quiz {
+    /// Unique identifier for the quiz.
+    pub id: Option<u64>,
+    /// The title of the quiz.
+    pub title: String,
+    /// The deadline for attempting the quiz.
+    pub due_date: Option<NaiveDateTime>,
+    /// Maximum number of attempts, or `None` if SPOT does not state a limit.
+    pub attempts_allowed: Option<u32>,
+    /// Number of attempts the student has already made.
+    pub attempts_used: u32,
+    /// The best score across attempts, or `None` if the quiz has not been attempted.
+    pub best_score: Option<f32>,
+    /// URL path to the quiz page.
+    pub href: Option<String>,
+}
+
+/// The category of a SPOT notification.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// A task or quiz was graded.
+    Grade,
+    /// New learning material was published.
+    Material,
+    /// Someone replied in a discussion forum.
+    Forum,
+    /// An announcement from a lecturer or the faculty.
+    Announcement,
+    /// Anything SPOT does not categorize as above.
+    Other,
+}
+
+/// An entry from the SPOT notification bell.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Notification {
+    /// Unique identifier for the notification.
+    pub id: u64,
+    /// The category of the notification.
+    pub kind: NotificationKind,
+    /// The notification text.
+    pub message: String,
+    /// URL path of the page the notification refers to.
+    pub link: Option<String>,
+    /// When the notification was created.
+    pub created_at: Option<NaiveDateTime>,
+    /// Whether the notification has been opened already.
+    pub is_read: bool,
+}
+
+/// How a student was recorded for one meeting on a course's attendance (presensi) page.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AttendanceStatus {
+    /// Attended the meeting ("Hadir").
+    Present,
+    /// Missed the meeting without notice ("Alpa").
+    Absent,
+    /// Missed the meeting with permission ("Izin").
+    Excused,
+    /// Missed the meeting due to illness ("Sakit").
+    Sick,
+    /// Attended, but arrived late ("Terlambat").
+    Late,
+}
+
+impl AttendanceStatus {
+    /// Maps an Indonesian attendance label, or its one-letter code, to a status.
+    ///
+    /// Matching ignores case; returns `None` for empty or unknown labels, such as those
+    /// of meetings that have not taken place yet.
+    pub fn from_label(label: &str) -> Option<Self> {
+        Some(match label.trim().to_lowercase().as_str() {
+            "hadir" | "h" => Self::Present,
+            "alpa" | "alpha" | "alfa" | "tidak hadir" | "a" => Self::Absent,
+            "izin" | "ijin" | "i" => Self::Excused,
+            "sakit" | "s" => Self::Sick,
+            "terlambat" | "telat" | "t" => Self::Late,
+            _ => return None,
+        })
+    }
+
+    /// Returns the Indonesian label for the status, e.g. `Hadir` or `Sakit`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Present => "Hadir",
+            Self::Absent => "Alpa",
+            Self::Excused => "Izin",
+            Self::Sick => "Sakit",
+            Self::Late => "Terlambat",
+        }
+    }
+
+    /// Whether the student attended the meeting (`Present` or `Late`).
+    pub fn is_attended(&self) -> bool {
+        matches!(self, Self::Present | Self::Late)
+    }
+}
+
+/// A single meeting on a course's attendance page.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AttendanceRecord {
+    /// The meeting number (pertemuan ke-), starting at 1.
+    pub meeting_number: u32,
+    /// The date of the meeting, if shown.
+    pub date: Option<NaiveDate>,
+    /// How the student was recorded.
+    pub status: AttendanceStatus,
+}
+
+/// Attendance counts for a course, computed with [`AttendanceSummary::from_records`].
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AttendanceSummary {
+    /// Number of recorded meetings.
+    pub total: u32,
+    /// Meetings attended on time.
+    pub present: u32,
+    /// Meetings missed without notice.
+    pub absent: u32,
+    /// Meetings missed with permission.
+    pub excused: u32,
+    /// Meetings missed due to illness.
+    pub sick: u32,
+    /// Meetings attended late.
+    pub late: u32,
+}
+
+impl AttendanceSummary {
+    /// Counts the statuses of `records`.
+    pub fn from_records(records: &[AttendanceRecord]) -> Self {
+        records.iter().fold(Self::default(), |mut summary, record| {
+            summary.total += 1;
+            match record.status {
+                AttendanceStatus::Present => summary.present += 1,
+                AttendanceStatus::Absent => summary.absent += 1,
+                AttendanceStatus::Excused => summary.excused += 1,
+                AttendanceStatus::Sick => summary.sick += 1,
+                AttendanceStatus::Late => summary.late += 1,
+            }
+            summary
+        })
+    }
+
+    /// Returns the share of meetings attended, late arrivals included, from 0 to 100.
+    ///
+    /// Returns `None` when no meeting has been recorded yet.
+    pub fn present_percentage(&self) -> Option<f32> {
+        (self.total > 0).then(|| (self.present + self.late) as f32 * 100.0 / self.total as f32)
+    }
+}
+
+/// A post from a course's announcement (pengumuman) board.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Announcement {
+    /// Unique identifier for the announcement.
+    pub id: u64,
+    /// The announcement title.
+    pub title: String,
+    /// The announcement body as raw HTML, exactly as rendered by SPOT.
+    pub body_html: String,
+    /// The name of the lecturer (or admin) who posted it.
+    pub author: String,
+    /// When the announcement was posted.
+    ///
+    /// SPOT sometimes shows relative times ("2 hari yang lalu"); these are resolved
+    /// against the time the page was fetched, so they are only as precise as their unit.
+    pub posted_at: Option<NaiveDateTime>,
 }
 
 /// Full details of a topic, including all instructional content and assignments.
@@ -154,10 +860,86 @@ pub struct TopicDetail {
     pub contents: Vec<Content>,
     /// List of assignments/tasks associated with this topic.
     pub tasks: Vec<Task>,
+    /// List of quizzes associated with this topic.
+    #[serde(default)]
+    pub quizzes: Vec<Quiz>,
+}
+
+impl TopicDetail {
+    /// Returns a hash of the topic's meaningful content, for cheap change detection.
+    ///
+    /// The hash covers the serialized topic: its description, content blocks, tasks
+    /// (answers included) and quizzes. It deliberately ignores `access_time`, which
+    /// changes on every visit, and each task's CSRF `token`, which changes on every page
+    /// load.
+    ///
+    /// Fields are serialized in declaration order, so the value is stable across runs
+    /// and can be persisted. A crate version that adds fields to these structs may
+    /// change it once.
+    pub fn content_hash(&self) -> u64 {
+        let mut canonical = self.clone();
+        canonical.access_time = None;
+        for task in &mut canonical.tasks {
+            task.token.clear();
+        }
+        // Serializing plain structs of strings, numbers and dates cannot fail.
+        let json = serde_json::to_vec(&canonical).unwrap_or_default();
+        crate::hash::fnv1a_64(&json)
+    }
+
+    /// Converts the detail back into the list-shaped `TopicInfo` used by `DetailCourse`.
+    ///
+    /// `TopicDetail` does not record its parent course, so `course_id` must be supplied.
+    pub fn to_topic_info(&self, course_id: u64) -> TopicInfo {
+        TopicInfo {
+            id: Some(self.id),
+            course_id: Some(course_id),
+            access_time: self.access_time,
+            updated_at: None,
+            is_accessible: self.is_accessible,
+            accessibility: if self.is_accessible {
+                Accessibility::Open
+            } else {
+                Accessibility::Closed
+            },
+            href: Some(self.href.clone()),
+        }
+    }
+}
+
+impl std::fmt::Display for TopicDetail {
+    /// Formats as `Topic 1358801: Pengantar (2 contents, 1 task, 0 quizzes)`, using the
+    /// first line of the description and marking inaccessible topics as `locked`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural =
+            |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+
+        write!(f, "Topic {}", self.id)?;
+        if let Some(title) = self
+            .description
+            .as_deref()
+            .and_then(|d| d.lines().map(str::trim).find(|l| !l.is_empty()))
+        {
+            write!(f, ": {}", title)?;
+        }
+        write!(
+            f,
+            " ({}, {}, {})",
+            plural(self.contents.len(), "content", "contents"),
+            plural(self.tasks.len(), "task", "tasks"),
+            plural(self.quizzes.len(), "quiz", "quizzes")
+        )?;
+        if !self.is_accessible {
+            f.write_str(" [locked]")?;
+        }
+        Ok(())
+    }
 }
 
 /// Represents the type of academic semester.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Variants are ordered chronologically within an academic year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Semester {
     /// Odd semester (Ganjil) - typically September to January.
     Odd = 1,
@@ -172,10 +954,21 @@ impl Semester {
     pub fn as_num(&self) -> u8 {
         *self as u8
     }
+
+    /// Returns the Indonesian label SPOT uses for the semester: `Ganjil`, `Genap` or `SP`.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Semester::Odd => "Ganjil",
+            Semester::Even => "Genap",
+            Semester::Short => "SP",
+        }
+    }
 }
 
 /// A helper for representing and formatting academic periods (Year + Semester).
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Periods are ordered chronologically (by year, then semester).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct Period {
     /// The starting year of the academic period (e.g., 2025).
     pub year: u16,
@@ -189,6 +982,43 @@ impl Period {
         Self { year, semester }
     }
 
+    /// Returns the academic period that is most likely active today.
+    ///
+    /// See [`Period::current_with_clock`] for how dates map to periods.
+    pub fn current() -> Self {
+        Self::current_with_clock(chrono::Local::now().date_naive())
+    }
+
+    /// Returns the academic period that is most likely active on `date`.
+    ///
+    /// Follows the Indonesian academic calendar, where an academic year starts in September:
+    /// - September to January -> `Odd` (January still belongs to the previous year's Odd semester)
+    /// - February to June -> `Even` of the academic year that started the previous September
+    /// - July to August -> `Short` of the academic year that started the previous September
+    pub fn current_with_clock(date: NaiveDate) -> Self {
+        let year = date.year() as u16;
+        match date.month() {
+            9..=12 => Self::new(year, Semester::Odd),
+            1 => Self::new(year - 1, Semester::Odd),
+            2..=6 => Self::new(year - 1, Semester::Even),
+            _ => Self::new(year - 1, Semester::Short),
+        }
+    }
+
+    /// Returns the first and last day of the period, using the same calendar as
+    /// [`Period::current_with_clock`]: `Odd` runs September to January, `Even` February
+    /// to June and `Short` July to August of the following calendar year.
+    pub fn date_range(&self) -> (NaiveDate, NaiveDate) {
+        let year = i32::from(self.year);
+        let (start, end) = match self.semester {
+            Semester::Odd => ((year, 9, 1), (year + 1, 1, 31)),
+            Semester::Even => ((year + 1, 2, 1), (year + 1, 6, 30)),
+            Semester::Short => ((year + 1, 7, 1), (year + 1, 8, 31)),
+        };
+        let date = |(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).expect("valid calendar date");
+        (date(start), date(end))
+    }
+
     /// Formats the period into the "YYYYN" format expected by the SPOT URL scheme.
     ///
     /// Example: `Period { year: 2025, semester: Semester::Odd }.format()` returns `"20251"`.
@@ -196,6 +1026,42 @@ impl Period {
         format!("{}{}", self.year, self.semester.as_num())
     }
 
+    /// Parses a period from the "YYYYN" format, the inverse of [`Period::format`].
+    ///
+    /// Surrounding whitespace and a trailing slash (as in `/adm/semester/20251/`) are
+    /// ignored. Example: `Period::from_code("20243")` returns
+    /// `Period { year: 2024, semester: Semester::Short }`.
+    pub fn from_code(code: &str) -> crate::error::Result<Self> {
+        use crate::error::ScraperError;
+
+        let invalid = || ScraperError::ParsingError(format!("Invalid period code: {}", code));
+        let trimmed = code.trim().trim_end_matches('/');
+        if trimmed.len() != 5 || !trimmed.chars().all(|c| c.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let year = trimmed[..4].parse().map_err(|_| invalid())?;
+        let semester = match &trimmed[4..] {
+            "1" => Semester::Odd,
+            "2" => Semester::Even,
+            "3" => Semester::Short,
+            _ => return Err(invalid()),
+        };
+        Ok(Period::new(year, semester))
+    }
+
+    /// Formats the period as a human-readable academic year string, the inverse of
+    /// [`Period::from_academic_year_string`].
+    ///
+    /// Example: `Period { year: 2025, semester: Semester::Odd }` returns `"2025/2026 - Ganjil"`.
+    pub fn to_academic_year_string(&self) -> String {
+        format!(
+            "{}/{} - {}",
+            self.year,
+            self.year + 1,
+            self.semester.label()
+        )
+    }
+
     /// Parses a human-readable academic year string into a `Period`.
     ///
     /// # Expected Formats:
@@ -247,6 +1113,94 @@ impl std::fmt::Display for Period {
     }
 }
 
+/// A final grade for one course, as listed on the KHS (Kartu Hasil Studi).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Grade {
+    /// The official course code (e.g., "IK410").
+    pub course_code: String,
+    /// The full name of the course.
+    pub course_name: String,
+    /// Number of academic credits (SKS) awarded for the course.
+    pub credits: u8,
+    /// The letter grade (e.g., "A-"), or an empty string if not graded yet.
+    pub letter_grade: String,
+    /// The grade point on a 4.0 scale; `0.0` while the course is not graded.
+    pub grade_point: f32,
+    /// The academic period the course was taken in.
+    pub period: Period,
+}
+
+impl Grade {
+    /// Returns `true` once the lecturer has published a letter grade.
+    pub fn is_graded(&self) -> bool {
+        !self.letter_grade.is_empty()
+    }
+}
+
+/// All grades from the KHS, across every period the student has taken.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct Transcript {
+    /// Every listed course, including those that are not graded yet.
+    pub grades: Vec<Grade>,
+}
+
+impl Transcript {
+    /// Returns the cumulative GPA (IPK), weighted by credits.
+    ///
+    /// Ungraded courses are left out. Returns `None` if nothing has been graded yet.
+    pub fn gpa(&self) -> Option<f32> {
+        let (points, credits) = self.grades.iter().filter(|grade| grade.is_graded()).fold(
+            (0.0, 0u32),
+            |(points, credits), grade| {
+                (
+                    points + grade.grade_point * f32::from(grade.credits),
+                    credits + u32::from(grade.credits),
+                )
+            },
+        );
+        (credits > 0).then(|| points / credits as f32)
+    }
+}
+
+/// One weekly class meeting from the timetable (jadwal).
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ScheduleEntry {
+    /// The official course code (e.g., "IK410").
+    pub course_code: String,
+    /// The full name of the course.
+    pub course_name: String,
+    /// The day of the week the class meets.
+    pub day_of_week: Weekday,
+    /// When the class starts.
+    pub start_time: NaiveTime,
+    /// When the class ends.
+    pub end_time: NaiveTime,
+    /// The room the class meets in.
+    pub room: String,
+    /// The lecturer teaching the class.
+    pub lecturer: String,
+}
+
+/// The shape of the random delay drawn between requests.
+///
+/// Every sample is clamped to `[min_delay_ms, max_delay_ms]` of the owning [`DelayConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
+pub enum JitterDistribution {
+    /// Every delay in the range is equally likely.
+    #[default]
+    Uniform,
+    /// Delays cluster `mean_ms` above the range's minimum, like a person who reads at a
+    /// steady pace.
+    ///
+    /// The mean is relative, like the `Exponential` offset, so the same distribution
+    /// keeps its shape in every [`DelayProfile`] range.
+    Normal { mean_ms: f64, stddev_ms: f64 },
+    /// Mostly short pauses with an occasional long one, starting at `min_delay_ms`.
+    ///
+    /// `lambda` is the rate per millisecond, so the mean extra delay is `1 / lambda` ms.
+    Exponential { lambda: f64 },
+}
+
 /// Configuration for simulating human browsing behavior via randomized delays.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DelayConfig {
@@ -256,15 +1210,288 @@ pub struct DelayConfig {
     pub max_delay_ms: u64,
     /// Whether the randomized delay logic is active.
     pub enabled: bool,
+    /// How delays are distributed between the minimum and maximum.
+    #[serde(default)]
+    pub distribution: JitterDistribution,
+    /// Ranges for specific kinds of requests, overriding `min_delay_ms`/`max_delay_ms`.
+    #[serde(default)]
+    pub profile: DelayProfile,
+}
+
+/// The kind of request a delay is drawn for; see [`DelayProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DelayAction {
+    /// Requests made while logging in through SSO, including the OTP step.
+    Login,
+    /// Page fetches, period changes and file downloads.
+    Navigation,
+    /// Task submissions and deletions.
+    Submission,
+}
+
+/// A delay range in milliseconds, used by [`DelayProfile`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DelayRange {
+    /// Minimum sleep duration in milliseconds.
+    pub min_delay_ms: u64,
+    /// Maximum sleep duration in milliseconds.
+    pub max_delay_ms: u64,
+}
+
+/// Per-action delay ranges ("action-specific jitter").
+///
+/// Each range is optional; actions without one use the base range of their
+/// [`DelayConfig`]. The default profile sets none, so every request is delayed alike.
+/// The `login` range also sets the pause after a successful login, which is 2-5 s
+/// without one.
+/// For bulk scrapes, a short `navigation` range with a longer `submission` range keeps
+/// reading fast while writes stay cautious.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct DelayProfile {
+    /// Range for [`DelayAction::Login`].
+    #[serde(default)]
+    pub login: Option<DelayRange>,
+    /// Range for [`DelayAction::Navigation`].
+    #[serde(default)]
+    pub navigation: Option<DelayRange>,
+    /// Range for [`DelayAction::Submission`].
+    #[serde(default)]
+    pub submission: Option<DelayRange>,
+}
+
+impl DelayConfig {
+    /// Returns the range used for `action`: its profile range, or the base range.
+    pub fn range_for(&self, action: DelayAction) -> DelayRange {
+        let range = match action {
+            DelayAction::Login => self.profile.login,
+            DelayAction::Navigation => self.profile.navigation,
+            DelayAction::Submission => self.profile.submission,
+        };
+        range.unwrap_or(DelayRange {
+            min_delay_ms: self.min_delay_ms,
+            max_delay_ms: self.max_delay_ms,
+        })
+    }
+
+    /// Computes how long to sleep before a request of kind `action`.
+    ///
+    /// This is the pure part of the client's delay logic: it returns `Duration::ZERO`
+    /// when delays are disabled and otherwise draws from `rng` without sleeping, so a
+    /// seeded RNG yields the exact same delays on every run.
+    #[cfg(feature = "client")]
+    pub fn compute_delay<R: rand::Rng + ?Sized>(
+        &self,
+        action: DelayAction,
+        rng: &mut R,
+    ) -> std::time::Duration {
+        if !self.enabled {
+            return std::time::Duration::ZERO;
+        }
+        std::time::Duration::from_millis(self.sample_delay_ms_for(action, rng))
+    }
+
+    /// Draws a delay in milliseconds from the configured distribution, using the base
+    /// range.
+    #[cfg(feature = "client")]
+    pub fn sample_delay_ms<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        self.sample_in(self.min_delay_ms, self.max_delay_ms, rng)
+    }
+
+    /// Draws a delay in milliseconds for `action` from the configured distribution.
+    #[cfg(feature = "client")]
+    pub fn sample_delay_ms_for<R: rand::Rng + ?Sized>(
+        &self,
+        action: DelayAction,
+        rng: &mut R,
+    ) -> u64 {
+        let range = self.range_for(action);
+        self.sample_in(range.min_delay_ms, range.max_delay_ms, rng)
+    }
+
+    #[cfg(feature = "client")]
+    fn sample_in<R: rand::Rng + ?Sized>(&self, min: u64, max: u64, rng: &mut R) -> u64 {
+        let (min, max) = (min, max.max(min));
+        let sample = match self.distribution {
+            JitterDistribution::Uniform => return rng.random_range(min..=max),
+            JitterDistribution::Normal { mean_ms, stddev_ms } => {
+                // Box-Muller transform
+                let u1: f64 = 1.0 - rng.random::<f64>();
+                let u2: f64 = rng.random();
+                let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+                min as f64 + mean_ms + stddev_ms * z
+            }
+            JitterDistribution::Exponential { lambda } => {
+                // Inverse transform sampling
+                let u: f64 = 1.0 - rng.random::<f64>();
+                min as f64 - u.ln() / lambda
+            }
+        };
+
+        if sample.is_nan() {
+            return min;
+        }
+        (sample.round() as u64).clamp(min, max)
+    }
 }
 
 impl Default for DelayConfig {
-    /// Default configuration: 1000ms - 3000ms, enabled.
+    /// Default configuration: uniformly distributed 1000ms - 3000ms for every action, enabled.
     fn default() -> Self {
         Self {
             min_delay_ms: 1000,
             max_delay_ms: 3000,
             enabled: true,
+            distribution: JitterDistribution::Uniform,
+            profile: DelayProfile::default(),
+        }
+    }
+}
+
+/// How long each kind of resource stays in the cache, in seconds.
+///
+/// Only used when a `CacheBackend` is configured on the client.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheTtls {
+    /// TTL for the course list.
+    pub courses_secs: u64,
+    /// TTL for course detail pages (description, RPS and topic list).
+    pub course_detail_secs: u64,
+    /// TTL for topic pages, which carry deadlines and submission status.
+    pub topic_detail_secs: u64,
+    /// TTL for the user profile.
+    pub profile_secs: u64,
+}
+
+impl Default for CacheTtls {
+    /// Default configuration: courses and course details for 1 hour, topics for
+    /// 5 minutes, the profile for 1 day.
+    fn default() -> Self {
+        Self {
+            courses_secs: 3600,
+            course_detail_secs: 3600,
+            topic_detail_secs: 300,
+            profile_secs: 86400,
+        }
+    }
+}
+
+/// How the client picks a User-Agent from its pool for each request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum UserAgentRotation {
+    /// Always send the first User-Agent in the pool.
+    Fixed,
+    /// Pick a random User-Agent for every request.
+    #[default]
+    RandomPerRequest,
+    /// Cycle through the pool in order.
+    RoundRobin,
+}
+
+/// The pool of User-Agent strings the client presents, and how it rotates them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAgentConfig {
+    /// The User-Agent strings to choose from. Must not be empty.
+    pub user_agents: Vec<String>,
+    /// How a User-Agent is chosen for each request.
+    pub rotation: UserAgentRotation,
+}
+
+impl UserAgentConfig {
+    /// Returns the User-Agent for the `request_number`-th request (starting at 0).
+    ///
+    /// # Panics
+    /// Panics if `user_agents` is empty.
+    #[cfg(feature = "client")]
+    pub fn pick<R: rand::Rng + ?Sized>(&self, request_number: usize, rng: &mut R) -> &str {
+        let index = match self.rotation {
+            UserAgentRotation::Fixed => 0,
+            UserAgentRotation::RandomPerRequest => rng.random_range(0..self.user_agents.len()),
+            UserAgentRotation::RoundRobin => request_number % self.user_agents.len(),
+        };
+        &self.user_agents[index]
+    }
+}
+
+impl Default for UserAgentConfig {
+    /// Default configuration: current desktop Chrome, Firefox and Safari, a random one
+    /// per request.
+    fn default() -> Self {
+        Self {
+            user_agents: vec![
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36".to_string(),
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/131.0.0.0 Safari/537.36".to_string(),
+                "Mozilla/5.0 (Windows NT 10.0; Win64; x64; rv:133.0) Gecko/20100101 Firefox/133.0".to_string(),
+                "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.1 Safari/605.1.15".to_string(),
+            ],
+            rotation: UserAgentRotation::RandomPerRequest,
+        }
+    }
+}
+
+/// Configuration for retrying operations that failed with a transient error.
+///
+/// See [`ScraperError::is_retryable`](crate::ScraperError::is_retryable) for which
+/// errors are considered transient. Installed on a client with
+/// `SpotifierCoreClient::set_retry_config`, it also retries responses whose status is
+/// listed in `retry_on_status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetryConfig {
+    /// How many times to retry after the first failed attempt.
+    pub max_retries: u32,
+    /// Backoff in milliseconds before the first retry; doubled on every subsequent retry.
+    pub initial_backoff_ms: u64,
+    /// Upper bound in milliseconds for a single backoff.
+    ///
+    /// Also caps how long a `Retry-After` from SPOT is honoured: a longer one ends the
+    /// retries with `ScraperError::RateLimited`, so the caller can decide when to resume.
+    pub max_backoff_ms: u64,
+    /// HTTP statuses the client treats as transient (default: 502, 503 and 504).
+    #[serde(default = "default_retry_statuses")]
+    pub retry_on_status: Vec<u16>,
+    /// Whether the client also retries POST requests such as logins and task
+    /// submissions (default: `false`). Only enable this if a duplicate submission is
+    /// acceptable, since a request that timed out may still have reached SPOT.
+    #[serde(default)]
+    pub retry_non_idempotent: bool,
+}
+
+fn default_retry_statuses() -> Vec<u16> {
+    vec![502, 503, 504]
+}
+
+impl RetryConfig {
+    /// Returns the backoff to wait before retry number `retry` (starting at 0).
+    pub fn backoff(&self, retry: u32) -> std::time::Duration {
+        let ms = self
+            .initial_backoff_ms
+            .saturating_mul(2u64.saturating_pow(retry))
+            .min(self.max_backoff_ms);
+        std::time::Duration::from_millis(ms)
+    }
+
+    /// Returns a randomized backoff between half and all of [`RetryConfig::backoff`], so
+    /// that clients failing at the same moment do not retry in lockstep.
+    #[cfg(feature = "client")]
+    pub fn jittered_backoff<R: rand::Rng + ?Sized>(
+        &self,
+        retry: u32,
+        rng: &mut R,
+    ) -> std::time::Duration {
+        let ms = self.backoff(retry).as_millis() as u64;
+        std::time::Duration::from_millis(rng.random_range(ms / 2..=ms))
+    }
+}
+
+impl Default for RetryConfig {
+    /// Default configuration: 3 retries, starting at 1000ms and capped at 10000ms, on
+    /// 502/503/504 responses and only for GET requests.
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff_ms: 1000,
+            max_backoff_ms: 10_000,
+            retry_on_status: default_retry_statuses(),
+            retry_non_idempotent: false,
         }
     }
 }
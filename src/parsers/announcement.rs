@@ -0,0 +1,63 @@
+// src/parsers/announcement.rs
+
+use super::datetime::{parse_id_datetime, parse_relative_dt};
+use crate::error::Result;
+use crate::models::Announcement;
+use chrono::NaiveDateTime;
+use scraper::{ElementRef, Html, Selector};
+
+/// Parses a course's announcement board (`/mhs/pengumuman/{course_id}`).
+///
+/// Each `#pengumuman .panel[data-id]` is one announcement. Timestamps may be absolute
+/// (`10-09-2025 13:30`) or relative (`2 hari yang lalu`); relative ones are resolved
+/// against `now`, and anything else leaves `posted_at` empty. The result is sorted
+/// newest first, with undated announcements last.
+///
+/// Returns `ScraperError::SelectorMissing` if the page has no `#pengumuman` board at
+/// all; an empty board is an empty list.
+pub fn parse_announcements_from_html(html: &str, now: NaiveDateTime) -> Result<Vec<Announcement>> {
+    let document = Html::parse_document(html);
+    let board_selector = Selector::parse("#pengumuman").unwrap();
+    if document.select(&board_selector).next().is_none() {
+        return Err(super::selector_missing(
+            &document,
+            "#pengumuman",
+            "announcement board",
+        ));
+    }
+    let item_selector = Selector::parse("#pengumuman .panel[data-id]").unwrap();
+    let title_selector = Selector::parse(".panel-heading .panel-title, .panel-heading h4").unwrap();
+    let body_selector = Selector::parse(".panel-body").unwrap();
+    let author_selector = Selector::parse(".author").unwrap();
+    let time_selector = Selector::parse(".time").unwrap();
+
+    let text_of = |el: ElementRef, selector: &Selector| {
+        el.select(selector)
+            .next()
+            .map(|e| e.text().collect::<String>().trim().to_string())
+            .unwrap_or_default()
+    };
+
+    let mut announcements: Vec<Announcement> = document
+        .select(&item_selector)
+        .filter_map(|item| {
+            let id = item.value().attr("data-id")?.trim().parse().ok()?;
+            let time = text_of(item, &time_selector);
+            Some(Announcement {
+                id,
+                title: text_of(item, &title_selector),
+                body_html: item
+                    .select(&body_selector)
+                    .next()
+                    .map(|body| body.inner_html().trim().to_string())
+                    .unwrap_or_default(),
+                author: text_of(item, &author_selector),
+                posted_at: parse_id_datetime(&time).or_else(|| parse_relative_dt(&time, now)),
+            })
+        })
+        .collect();
+
+    // `None` sorts before `Some`, so reversing puts undated entries last.
+    announcements.sort_by_key(|a| std::cmp::Reverse(a.posted_at));
+    Ok(announcements)
+}
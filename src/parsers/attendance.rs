@@ -0,0 +1,73 @@
+// src/parsers/attendance.rs
+
+use super::datetime::parse_id_date;
+use crate::error::Result;
+use crate::models::{AttendanceRecord, AttendanceStatus};
+use scraper::{Html, Selector};
+
+/// Parses a course's attendance page.
+///
+/// The attendance table is recognized by its header: a status column (`Status`,
+/// `Kehadiran`, `Keterangan` or `Presensi`) is required, while the meeting
+/// (`Pertemuan`/`No`) and `Tanggal` columns are optional. Rows with an empty or
+/// unknown status (meetings that have not happened yet) are skipped.
+pub fn parse_attendance_from_html(html: &str) -> Result<Vec<AttendanceRecord>> {
+    let document = Html::parse_document(html);
+    let table_selector = Selector::parse("table").unwrap();
+    let header_selector = Selector::parse("thead th").unwrap();
+    let row_selector = Selector::parse("tbody > tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    for table in document.select(&table_selector) {
+        let headers: Vec<String> = table
+            .select(&header_selector)
+            .map(|th| th.text().collect::<String>().trim().to_lowercase())
+            .collect();
+        // Column names are tried in order, so "Status" wins over "Keterangan"
+        let column = |names: &[&str]| {
+            names
+                .iter()
+                .find_map(|name| headers.iter().position(|header| header.starts_with(name)))
+        };
+
+        let Some(status_col) = column(&["status", "kehadiran", "keterangan", "presensi"]) else {
+            continue;
+        };
+        let meeting_col = column(&["pertemuan", "no"]);
+        let date_col = column(&["tanggal"]);
+
+        let records = table
+            .select(&row_selector)
+            .enumerate()
+            .filter_map(|(index, row)| {
+                let cells: Vec<String> = row
+                    .select(&cell_selector)
+                    .map(|td| td.text().collect::<String>().trim().to_string())
+                    .collect();
+                let status = AttendanceStatus::from_label(cells.get(status_col)?)?;
+                let meeting_number = meeting_col
+                    .and_then(|col| {
+                        cells
+                            .get(col)?
+                            .split(|c: char| !c.is_ascii_digit())
+                            .find(|part| !part.is_empty())?
+                            .parse()
+                            .ok()
+                    })
+                    .unwrap_or(index as u32 + 1);
+                Some(AttendanceRecord {
+                    meeting_number,
+                    date: date_col.and_then(|col| parse_id_date(cells.get(col)?)),
+                    status,
+                })
+            })
+            .collect();
+        return Ok(records);
+    }
+
+    Err(super::selector_missing(
+        &document,
+        "table",
+        "attendance page",
+    ))
+}
@@ -0,0 +1,85 @@
+// src/parsers/course.rs
+
+use super::datetime::parse_id_datetime;
+use crate::error::Result;
+use crate::models::{Accessibility, Course, DetailCourse};
+use chrono::NaiveDateTime;
+use scraper::{ElementRef, Selector};
+
+/// Selector for the "open topic" button inside a topic block (`.block4`) of a course page.
+pub const TOPIC_LINK_SELECTOR: &str = ".panel-body a.btn-info";
+
+/// Parses a course detail page into a `DetailCourse`.
+///
+/// This is the same parser used by `SpotifierCoreClient::get_course_detail`, exposed so
+/// HTML obtained elsewhere (e.g. from a headless browser) can be parsed directly.
+/// See [`topic_accessibility`] for how `TopicInfo::accessibility` is derived from `now`,
+/// the current time in WIB.
+pub fn parse_course_detail(
+    html: &str,
+    course: &Course,
+    now: NaiveDateTime,
+) -> Result<DetailCourse> {
+    super::course_detail::parse_course_detail_from_html(html, course.clone(), now)
+}
+
+/// Returns the "open topic" button of a topic block, if there is one.
+pub fn topic_link(topic_el: ElementRef<'_>) -> Option<ElementRef<'_>> {
+    let link_selector = Selector::parse(TOPIC_LINK_SELECTOR).unwrap();
+    topic_el.select(&link_selector).next()
+}
+
+/// Works out whether a topic block is open and, if it is locked, why.
+///
+/// A topic with the "open topic" button is `Open` (see [`is_topic_accessible`]). A
+/// locked block whose lock label (the text next to the lock icon) mentions a
+/// prerequisite ("prasyarat", "selesaikan", "topik sebelumnya") is
+/// `LockedPrerequisite`. One that shows an opening date after `now` (in WIB), such as
+/// "Waktu Akses" or "Dibuka: 15 September 2025 08:00", is `LockedUntil` that date. SPOT
+/// shows "Waktu Akses" on open topics too, so a locked block whose date has passed was
+/// closed by the lecturer and is `Closed`, like any other locked block.
+pub fn topic_accessibility(topic_el: ElementRef<'_>, now: NaiveDateTime) -> Accessibility {
+    if is_topic_accessible(topic_el) {
+        return Accessibility::Open;
+    }
+
+    let lock_selector = Selector::parse(".fa-lock").unwrap();
+    let lock_label = topic_el
+        .select(&lock_selector)
+        .filter_map(|icon| icon.parent().and_then(ElementRef::wrap))
+        .flat_map(|label| label.text())
+        .collect::<String>()
+        .to_lowercase();
+    if ["prasyarat", "selesaikan", "topik sebelumnya"]
+        .iter()
+        .any(|hint| lock_label.contains(hint))
+    {
+        return Accessibility::LockedPrerequisite;
+    }
+
+    topic_el
+        .text()
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|text| !text.is_empty() && !text.to_lowercase().contains("diperbarui"))
+        .find_map(|text| find_datetime(&text))
+        .filter(|opens_at| *opens_at > now)
+        .map_or(Accessibility::Closed, Accessibility::LockedUntil)
+}
+
+/// Finds a date in `text`, skipping a leading label such as "Dibuka pada" or "Waktu Akses:".
+fn find_datetime(text: &str) -> Option<NaiveDateTime> {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    (0..words.len()).find_map(|start| {
+        let candidate = words[start..].join(" ");
+        parse_id_datetime(candidate.trim_start_matches(':'))
+    })
+}
+
+/// Decides whether a topic block is open for students.
+///
+/// SPOT only renders the "open topic" button ([`TOPIC_LINK_SELECTOR`]) for topics that
+/// can be visited. Locked topics show a disabled button and a lock icon instead, with no
+/// link, so a topic is accessible exactly when that button is present.
+pub fn is_topic_accessible(topic_el: ElementRef<'_>) -> bool {
+    topic_link(topic_el).is_some()
+}
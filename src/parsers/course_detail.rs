@@ -1,23 +1,19 @@
 // src/parsers/course_detail.rs
 
+use super::datetime::parse_id_datetime;
 use crate::error::Result;
 use crate::models::{Course, DetailCourse, Rps, TopicInfo};
 use chrono::NaiveDateTime;
 use scraper::{ElementRef, Html, Selector};
 
-fn parse_dt(s: &str) -> Option<NaiveDateTime> {
-    let t = s.trim();
-    [
-        "%d-%m-%Y %H:%M",
-        "%d-%m-%Y %H:%M:%S",
-        "%d/%m/%Y %H:%M",
-        "%d/%m/%Y %H:%M:%S",
-    ]
-    .iter()
-    .find_map(|fmt| NaiveDateTime::parse_from_str(t, fmt).ok())
-}
-
-pub fn parse_course_detail_from_html(html: &str, course: Course) -> Result<DetailCourse> {
+/// Parses a course page. `now` is the current time in WIB; it decides whether a locked
+/// topic's opening date is still ahead (see
+/// [`topic_accessibility`](super::course::topic_accessibility)).
+pub fn parse_course_detail_from_html(
+    html: &str,
+    course: Course,
+    now: NaiveDateTime,
+) -> Result<DetailCourse> {
     let document = Html::parse_document(html);
 
     // Handle cases where the course is not yet configured by the lecturer
@@ -26,11 +22,10 @@ pub fn parse_course_detail_from_html(html: &str, course: Course) -> Result<Detai
         return Ok(DetailCourse {
             course_info: course,
             description: "Course has not been set by the lecturer.".to_string(),
-            rps: Rps {
-                id: None,
-                href: None,
-            },
+            rps: Rps::default(),
             topics: Vec::new(),
+            lecturer_email: None,
+            lecturer_profile_href: None,
         });
     }
 
@@ -39,21 +34,24 @@ pub fn parse_course_detail_from_html(html: &str, course: Course) -> Result<Detai
     let rps_anchor = document.select(&rps_link_selector).next();
 
     let (rps, description) = if let Some(anchor) = rps_anchor {
-        let href = anchor.value().attr("href").map(String::from);
+        let href = anchor
+            .value()
+            .attr("href")
+            .and_then(|href| super::normalize_href_on(&course.href, href));
         let rps_data = Rps {
             id: href
                 .as_deref()
-                .and_then(|h| h.split('/').last())
+                .and_then(|h| h.split('/').next_back())
                 .and_then(|v| v.parse::<u64>().ok()),
             href,
         };
 
         let white_box_selector = Selector::parse(".white-box").unwrap();
         let description_container = anchor.ancestors().find_map(|ancestor_node| {
-            if let Some(element_ref) = ElementRef::wrap(ancestor_node) {
-                if white_box_selector.matches(&element_ref) {
-                    return Some(element_ref);
-                }
+            if let Some(element_ref) = ElementRef::wrap(ancestor_node)
+                && white_box_selector.matches(&element_ref)
+            {
+                return Some(element_ref);
             }
             None
         });
@@ -65,29 +63,119 @@ pub fn parse_course_detail_from_html(html: &str, course: Course) -> Result<Detai
                     .select(&p_selector)
                     .find(|p| p.select(&rps_link_selector).next().is_none())
             })
-            .map(|p| p.text().collect::<String>().trim().to_string())
+            .map(|p| p.inner_html().trim().to_string())
             .unwrap_or_default();
 
         (rps_data, desc)
     } else {
-        (
-            Rps {
-                id: None,
-                href: None,
-            },
-            String::new(),
-        )
+        // Not every course uploads an RPS; that is not a parse failure.
+        (Rps::default(), String::new())
     };
 
-    // --- Topic Parsing Logic ---
+    Ok(DetailCourse {
+        description,
+        rps,
+        topics: parse_topics(&document, &course.href, now),
+        lecturer_email: parse_lecturer_email(&document),
+        lecturer_profile_href: parse_lecturer_profile_href(&document, &course.href),
+        course_info: course,
+    })
+}
+
+/// Reads the lecturer's email from a `mailto:` link, or from email-shaped text, in the
+/// course info panel (`.white-box`). Links in the navbar or footer are not the
+/// lecturer's and are ignored.
+fn parse_lecturer_email(document: &Html) -> Option<String> {
+    let mailto_selector = Selector::parse("a[href^='mailto:' i]").unwrap();
+    let panels = info_panels(document);
+    let mailto = panels
+        .iter()
+        .flat_map(|panel| panel.select(&mailto_selector))
+        .filter_map(|a| a.value().attr("href"))
+        .map(|href| {
+            let address = &href["mailto:".len()..];
+            address.split('?').next().unwrap_or_default()
+        })
+        .find_map(valid_email);
+    mailto.or_else(|| {
+        panels
+            .iter()
+            .flat_map(|el| el.text())
+            .flat_map(str::split_whitespace)
+            .map(|word| word.trim_matches(|c: char| "()<>[],.;:'\"".contains(c)))
+            .find_map(valid_email)
+    })
+}
+
+/// Returns the address in lowercase if it has the shape `local@domain.tld`.
+fn valid_email(candidate: &str) -> Option<String> {
+    let candidate = candidate.trim();
+    let (local, domain) = candidate.split_once('@')?;
+    let local_ok = !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_ok = labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    (local_ok && domain_ok).then(|| candidate.to_ascii_lowercase())
+}
+
+/// Reads the lecturer's profile link (an href containing `dosen` or `profil`) from the
+/// course info panel, normalized against the course page with
+/// [`normalize_href_on`](super::normalize_href_on). The student's own profile link in
+/// the navbar is ignored.
+fn parse_lecturer_profile_href(document: &Html, page: &str) -> Option<String> {
+    let link_selector = Selector::parse("a[href]").unwrap();
+    info_panels(document)
+        .into_iter()
+        .flat_map(|panel| panel.select(&link_selector))
+        .filter_map(|a| a.value().attr("href"))
+        .filter(|href| {
+            let href = href.to_ascii_lowercase();
+            href.contains("dosen") || href.contains("profil")
+        })
+        .find_map(|href| super::normalize_href_on(page, href))
+}
+
+/// The course info panels (`.white-box`) that hold the description and lecturer.
+fn info_panels(document: &Html) -> Vec<ElementRef<'_>> {
+    let white_box_selector = Selector::parse(".white-box").unwrap();
+    document.select(&white_box_selector).collect()
+}
+
+/// Parses only the topic list of a course detail page.
+///
+/// Yields the same topics as [`parse_course_detail_from_html`] without extracting the
+/// description and RPS. A course that has not been set up yet has no topics.
+/// `course_id` identifies the page (`/mhs/topik/{course_id}`) relative links are
+/// resolved against, and `now` is the current time in WIB.
+pub fn parse_course_topics_from_html(
+    html: &str,
+    course_id: u64,
+    now: NaiveDateTime,
+) -> Result<Vec<TopicInfo>> {
+    let document = Html::parse_document(html);
+    Ok(parse_topics(
+        &document,
+        &format!("/mhs/topik/{}", course_id),
+        now,
+    ))
+}
+
+fn parse_topics(document: &Html, page: &str, now: NaiveDateTime) -> Vec<TopicInfo> {
     let topic_selector = Selector::parse(".container-fluid .block4").unwrap();
-    let topics: Vec<TopicInfo> = document
+    document
         .select(&topic_selector)
         .map(|topic_el| {
-            let link_selector = Selector::parse(".panel-body a.btn-info").unwrap();
-            let link_element = topic_el.select(&link_selector).next();
-
-            let is_accessible = link_element.is_some();
+            let link_element = super::course::topic_link(topic_el);
+            let accessibility = super::course::topic_accessibility(topic_el, now);
+            let is_accessible = accessibility.is_open();
 
             // Get href attribute (may be absolute or relative).
             let raw_href = if is_accessible {
@@ -96,41 +184,37 @@ pub fn parse_course_detail_from_html(html: &str, course: Course) -> Result<Detai
                 None
             };
 
-            // Normalize into a relative path string.
-            let relative_path = raw_href.and_then(|url_str| {
-                if url_str.starts_with("http://") || url_str.starts_with("https://") {
-                    reqwest::Url::parse(url_str)
-                        .ok()
-                        .map(|url| url.path().to_string())
-                } else {
-                    Some(url_str.to_string())
-                }
-            });
+            let relative_path = raw_href.and_then(|href| super::normalize_href_on(page, href));
 
             let time_selector = Selector::parse(".panel-body div div button.disabled").unwrap();
+            let time_labels: Vec<String> = topic_el
+                .select(&time_selector)
+                .map(|btn| btn.text().collect::<String>())
+                .collect();
 
             TopicInfo {
                 id: relative_path
                     .as_deref()
-                    .and_then(|path| path.split('/').last())
+                    .and_then(|path| path.split('/').next_back())
                     .and_then(|v| v.parse::<u64>().ok()),
                 course_id: relative_path
                     .as_deref()
                     .and_then(|path| path.split('/').nth(3))
                     .and_then(|v| v.parse::<u64>().ok()),
-                access_time: topic_el.select(&time_selector).next().and_then(|btn| {
-                    parse_dt(&btn.text().collect::<String>().replace("Waktu Akses:", ""))
-                }),
+                access_time: time_labels
+                    .iter()
+                    .find(|label| !label.contains("Diperbarui"))
+                    .and_then(|label| parse_id_datetime(&label.replace("Waktu Akses:", ""))),
+                // Only present on topics where SPOT renders a "Diperbarui: ..." badge.
+                updated_at: time_labels
+                    .iter()
+                    .find(|label| label.contains("Diperbarui"))
+                    .and_then(|label| label.split_once(':'))
+                    .and_then(|(_, value)| parse_id_datetime(value)),
                 is_accessible,
+                accessibility,
                 href: relative_path,
             }
         })
-        .collect();
-
-    Ok(DetailCourse {
-        course_info: course,
-        description,
-        rps,
-        topics,
-    })
+        .collect()
 }
@@ -1,6 +1,7 @@
 use crate::error::{Result, ScraperError};
 use crate::models::Course;
 use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
 
 fn extract_course_from_row(row: ElementRef) -> Result<Course> {
     let cell_selector = Selector::parse("td").unwrap();
@@ -18,21 +19,69 @@ fn extract_course_from_row(row: ElementRef) -> Result<Course> {
         .and_then(|a| a.value().attr("href"))
         .ok_or_else(|| ScraperError::ElementNotFound("Link mata kuliah (href)".to_string()))?;
 
-    let id = href.split('/').last().and_then(|s| s.parse::<u64>().ok()).unwrap_or(0);
-
-    let course = Course {
-        id,
+    let mut course = Course {
+        id: 0,
         code: cells[0].text().collect::<String>().trim().to_string(),
         name: cells[1].text().collect::<String>().trim().to_string(),
-        credits: cells[2].text().collect::<String>().trim().parse().unwrap_or(0),
-        lecturer: cells[3].text().collect::<String>().trim().to_string(),
+        credits: parse_credits(&cells[2].text().collect::<String>()),
+        lecturer: String::new(),
+        lecturers: split_lecturers(&cell_lines(cells[3])),
         academic_year: cells[4].text().collect::<String>().trim().to_string(),
-        href: href.to_string(),
+        href: super::normalize_href(href).unwrap_or_else(|| href.to_string()),
     };
+    course.id = course.detail_id().unwrap_or(0);
+    course.lecturer = course.lecturers.first().cloned().unwrap_or_default();
 
     Ok(course)
 }
 
+/// Reads the credit count from cells such as "3", "3 SKS" or "SKS: 3".
+///
+/// The first run of digits wins; a cell without one (empty or "-") yields 0 rather than
+/// dropping the course from the list.
+fn parse_credits(text: &str) -> u8 {
+    text.split(|c: char| !c.is_ascii_digit())
+        .find(|digits| !digits.is_empty())
+        .and_then(|digits| digits.parse().ok())
+        .unwrap_or(0)
+}
+
+/// The text of a cell with `<br>` line breaks kept as newlines.
+fn cell_lines(cell: ElementRef) -> String {
+    let mut text = String::new();
+    for node in cell.descendants() {
+        if let Some(fragment) = node.value().as_text() {
+            text.push_str(fragment);
+        } else if node.value().as_element().is_some_and(|e| e.name() == "br") {
+            text.push('\n');
+        }
+    }
+    text
+}
+
+/// Splits a lecturer cell listing several names by comma, semicolon or line.
+///
+/// Academic titles written after a comma ("Dr. Budi Santoso, M.Kom.") are not names:
+/// a piece without spaces that contains a dot is kept with the name before it.
+fn split_lecturers(text: &str) -> Vec<String> {
+    let mut lecturers: Vec<String> = Vec::new();
+    for line in text.split(['\n', ';']) {
+        let mut line_start = true;
+        for piece in line.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let is_title = piece.contains('.') && !piece.contains(char::is_whitespace);
+            match lecturers.last_mut() {
+                Some(name) if is_title && !line_start => {
+                    name.push_str(", ");
+                    name.push_str(piece);
+                }
+                _ => lecturers.push(piece.to_string()),
+            }
+            line_start = false;
+        }
+    }
+    lecturers
+}
+
 pub fn parse_courses_from_html(html: &str) -> Result<Vec<Course>> {
     let document = Html::parse_document(html);
     let row_selector = Selector::parse("table > tbody > tr").unwrap();
@@ -52,3 +101,92 @@ pub fn parse_courses_from_html(html: &str) -> Result<Vec<Course>> {
 
     Ok(courses)
 }
+
+/// Finds the link to the next page of a paginated course list.
+///
+/// Recognizes, in order:
+/// - a `rel="next"` link (Laravel's default pagination),
+/// - the link following the active item of a `.pagination` list,
+/// - a "Halaman X dari Y" indicator, which yields `?page=X+1` while X < Y.
+///
+/// Returns `None` on the last page and on listings without pagination controls.
+/// The href is returned as found and may be relative to the current page.
+pub fn parse_next_page_href(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+
+    let rel_next = Selector::parse(".pagination a[rel='next'], a.page-link[rel='next']").unwrap();
+    if let Some(href) = document
+        .select(&rel_next)
+        .find_map(|a| a.value().attr("href"))
+    {
+        return Some(href.to_string());
+    }
+
+    let active = Selector::parse(".pagination li.active").unwrap();
+    if let Some(item) = document.select(&active).next() {
+        let link = Selector::parse("a[href]").unwrap();
+        return item
+            .next_siblings()
+            .filter_map(ElementRef::wrap)
+            .next()
+            .filter(|next| !next.value().classes().any(|c| c == "disabled"))
+            .and_then(|next| next.select(&link).next())
+            .and_then(|a| a.value().attr("href"))
+            .map(String::from);
+    }
+
+    let text = document.root_element().text().collect::<String>();
+    let (_, rest) = text.split_once("Halaman ")?;
+    let mut numbers = rest
+        .split_whitespace()
+        .take(3)
+        .map(|word| word.parse::<u32>().ok());
+    let current = numbers.next()??;
+    let total = match (numbers.next()?, numbers.next()?) {
+        (None, Some(total)) => total,
+        _ => return None,
+    };
+    (current < total).then(|| format!("?page={}", current + 1))
+}
+
+/// The shapes accepted from the JSON course-list endpoint.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CoursesPayload {
+    Wrapped { data: Vec<Course> },
+    Bare(Vec<Course>),
+}
+
+/// Parses the course list returned by SPOT's JSON endpoint.
+///
+/// Accepts either a bare array of courses or an object wrapping it in a `data` field.
+/// Any other shape is reported as a `ParsingError` so callers can fall back to HTML.
+pub fn parse_courses_from_json(json: &str) -> Result<Vec<Course>> {
+    let payload: CoursesPayload = serde_json::from_str(json).map_err(|e| {
+        ScraperError::ParsingError(format!("Unexpected course list JSON shape: {}", e))
+    })?;
+
+    let mut courses = match payload {
+        CoursesPayload::Wrapped { data } => data,
+        CoursesPayload::Bare(courses) => courses,
+    };
+    for course in &mut courses {
+        if let Some(href) = super::normalize_href(&course.href) {
+            course.href = href;
+        }
+        if course.lecturers.is_empty() {
+            course.lecturers = split_lecturers(&course.lecturer);
+        }
+        if let Some(first) = course.lecturers.first() {
+            course.lecturer = first.clone();
+        }
+    }
+
+    if courses.is_empty() {
+        return Err(ScraperError::ElementNotFound(
+            "Tidak ada mata kuliah di dalam respons JSON".to_string(),
+        ));
+    }
+
+    Ok(courses)
+}
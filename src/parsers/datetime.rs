@@ -0,0 +1,170 @@
+// src/parsers/datetime.rs
+
+//! Parsing of the date formats SPOT renders, numeric or written out in Indonesian.
+//!
+//! Every function returns `None` for input it does not recognize, so a single odd
+//! cell never fails the whole page.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Indonesian month names (and their abbreviations), starting with January.
+const MONTHS: [&[&str]; 12] = [
+    &["januari", "jan"],
+    &["februari", "feb", "pebruari"],
+    &["maret", "mar"],
+    &["april", "apr"],
+    &["mei"],
+    &["juni", "jun"],
+    &["juli", "jul"],
+    &["agustus", "agu", "agt", "ags", "agus"],
+    &["september", "sep", "sept"],
+    &["oktober", "okt"],
+    &["november", "nov", "nop"],
+    &["desember", "des"],
+];
+
+/// Indonesian weekday names, which may prefix a date ("Senin, 12 Februari 2025").
+const WEEKDAYS: &[&str] = &[
+    "senin", "selasa", "rabu", "kamis", "jumat", "jum'at", "sabtu", "minggu", "ahad",
+];
+
+/// Returns the current wall-clock time in WIB (UTC+7), the zone SPOT renders every
+/// timestamp in, whatever the machine's local zone is.
+#[cfg(feature = "client")]
+pub(crate) fn now_wib() -> NaiveDateTime {
+    let wib = chrono::FixedOffset::east_opt(7 * 3600).expect("UTC+7 is a valid offset");
+    chrono::Utc::now().with_timezone(&wib).naive_local()
+}
+
+/// Words around the time of day that carry no information ("pukul 14.30 WIB").
+const FILLER_WORDS: &[&str] = &["pukul", "jam", "at", "-", "wib", "wita", "wit"];
+
+const DATETIME_FORMATS: &[&str] = &[
+    "%d-%m-%Y %H:%M",
+    "%d-%m-%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d/%m/%Y %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%dT%H:%M:%S",
+];
+
+const DATE_FORMATS: &[&str] = &["%d-%m-%Y", "%d/%m/%Y", "%Y-%m-%d"];
+
+const TIME_FORMATS: &[&str] = &["%H:%M", "%H:%M:%S", "%H.%M", "%H.%M.%S"];
+
+/// Parses a date with an optional time of day, e.g. `"12-02-2025 14:30"` or
+/// `"Senin, 12 Februari 2025 pukul 14.30 WIB"`.
+///
+/// Accepts the numeric `dd-mm-yyyy`, `dd/mm/yyyy` and ISO forms, and written-out dates
+/// with full or abbreviated Indonesian month names, an optional weekday prefix and an
+/// optional `HH:MM[:SS]` (or `HH.MM`) time. A date without a time is midnight.
+pub fn parse_id_datetime(s: &str) -> Option<NaiveDateTime> {
+    parse_with_default_time(s, NaiveTime::MIN)
+}
+
+/// Parses a deadline like [`parse_id_datetime`], except that a date without a time is
+/// the end of that day (23:59:59), so the whole day still counts as before the deadline.
+pub fn parse_id_deadline(s: &str) -> Option<NaiveDateTime> {
+    parse_with_default_time(s, NaiveTime::from_hms_opt(23, 59, 59)?)
+}
+
+fn parse_with_default_time(s: &str, default_time: NaiveTime) -> Option<NaiveDateTime> {
+    let text = s.trim();
+    if let Some(datetime) = DATETIME_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(text, fmt).ok())
+    {
+        return Some(datetime);
+    }
+
+    let (date, time) = split_date_time(text)?;
+    Some(date.and_time(time.unwrap_or(default_time)))
+}
+
+/// Parses a date, e.g. `"08-09-2025"` or `"Senin, 8 September 2025"`.
+///
+/// Accepts the same forms as [`parse_id_datetime`]; a time of day, if present, is
+/// ignored.
+pub fn parse_id_date(s: &str) -> Option<NaiveDate> {
+    let text = s.trim();
+    if let Some(date) = DATE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(text, fmt).ok())
+    {
+        return Some(date);
+    }
+    parse_id_datetime(text).map(|datetime| datetime.date())
+}
+
+/// Splits written-out text into its date and optional time of day.
+fn split_date_time(text: &str) -> Option<(NaiveDate, Option<NaiveTime>)> {
+    let text = text.to_lowercase().replace(',', " ");
+    let mut words = text
+        .split_whitespace()
+        .filter(|word| !FILLER_WORDS.contains(word))
+        .peekable();
+    if words.peek().is_some_and(|word| WEEKDAYS.contains(word)) {
+        words.next();
+    }
+
+    let first = words.next()?;
+    let date = match DATE_FORMATS
+        .iter()
+        .find_map(|fmt| NaiveDate::parse_from_str(first, fmt).ok())
+    {
+        Some(date) => date,
+        None => {
+            let day = first.parse().ok()?;
+            let month_name = words.next()?.trim_end_matches('.');
+            let month = MONTHS
+                .iter()
+                .position(|names| names.contains(&month_name))?;
+            let year = words.next()?.parse().ok()?;
+            NaiveDate::from_ymd_opt(year, month as u32 + 1, day)?
+        }
+    };
+
+    let time = match words.next() {
+        Some(word) => Some(
+            TIME_FORMATS
+                .iter()
+                .find_map(|fmt| NaiveTime::parse_from_str(word, fmt).ok())?,
+        ),
+        None => None,
+    };
+    if words.next().is_some() {
+        return None;
+    }
+    Some((date, time))
+}
+
+/// Parses an Indonesian relative timestamp such as "2 hari yang lalu" against `now`.
+///
+/// Understands `detik`, `menit`, `jam`, `hari`, `minggu`, `bulan` (30 days) and `tahun`
+/// (365 days), as well as "baru saja" and "kemarin".
+pub(crate) fn parse_relative_dt(s: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let text = s.trim().to_lowercase();
+    match text.as_str() {
+        "baru saja" | "sekarang" => return Some(now),
+        "kemarin" => return Some(now - Duration::days(1)),
+        _ => {}
+    }
+
+    let mut words = text.strip_suffix("yang lalu")?.split_whitespace();
+    let amount: i64 = words.next()?.parse().ok()?;
+    let unit = match words.next()? {
+        "detik" => Duration::seconds(1),
+        "menit" => Duration::minutes(1),
+        "jam" => Duration::hours(1),
+        "hari" => Duration::days(1),
+        "minggu" => Duration::weeks(1),
+        "bulan" => Duration::days(30),
+        "tahun" => Duration::days(365),
+        _ => return None,
+    };
+    if words.next().is_some() {
+        return None;
+    }
+    now.checked_sub_signed(unit.checked_mul(i32::try_from(amount).ok()?)?)
+}
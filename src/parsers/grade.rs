@@ -0,0 +1,114 @@
+// src/parsers/grade.rs
+
+use crate::error::Result;
+use crate::models::{Grade, Period, Transcript};
+use scraper::{ElementRef, Html, Selector};
+
+/// Converts a UPI letter grade to its grade point, for rows without a "Bobot" column.
+fn grade_point_for(letter: &str) -> Option<f32> {
+    Some(match letter {
+        "A" => 4.0,
+        "A-" => 3.7,
+        "B+" => 3.3,
+        "B" => 3.0,
+        "B-" => 2.7,
+        "C+" => 2.3,
+        "C" => 2.0,
+        "D" => 1.0,
+        "E" => 0.0,
+        _ => return None,
+    })
+}
+
+/// Finds the academic period in the heading of the panel that contains `table`.
+fn period_of(table: ElementRef) -> Option<Period> {
+    let heading_selector = Selector::parse(".panel-heading, h3, h4").unwrap();
+    table
+        .ancestors()
+        .filter_map(ElementRef::wrap)
+        .find_map(|ancestor| ancestor.select(&heading_selector).next())
+        .and_then(|heading| {
+            let text = heading.text().collect::<String>();
+            // Headings look like "Semester: 2024/2025 - Genap" or just "2024/2025 - Genap".
+            let text = text.rsplit(':').next().unwrap_or_default();
+            Period::from_academic_year_string(text.trim()).ok()
+        })
+}
+
+/// Parses the KHS (Kartu Hasil Studi) page into a `Transcript`.
+///
+/// Each period is a panel whose heading names the period and whose table lists the
+/// courses. Columns are located by their header (`Kode`, `Mata Kuliah`, `SKS`,
+/// `Nilai`/`Huruf`, `Bobot`/`Angka`). Courses without a letter grade are kept with an
+/// empty `letter_grade`; when the `Bobot` column is missing, the grade point is derived
+/// from the letter.
+pub fn parse_grades_from_html(html: &str) -> Result<Transcript> {
+    let document = Html::parse_document(html);
+    let table_selector = Selector::parse("table").unwrap();
+    let header_selector = Selector::parse("thead th").unwrap();
+    let row_selector = Selector::parse("tbody > tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    let mut grades = Vec::new();
+    let mut found_table = false;
+
+    for table in document.select(&table_selector) {
+        let headers: Vec<String> = table
+            .select(&header_selector)
+            .map(|th| th.text().collect::<String>().trim().to_lowercase())
+            .collect();
+        let column = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|header| names.iter().any(|name| header == name))
+        };
+
+        let (Some(code_col), Some(name_col), Some(credits_col), Some(letter_col)) = (
+            column(&["kode", "kode mk"]),
+            column(&["mata kuliah", "nama mata kuliah"]),
+            column(&["sks"]),
+            column(&["nilai", "huruf", "nilai huruf"]),
+        ) else {
+            continue;
+        };
+        let point_col = column(&["bobot", "angka", "nilai angka"]);
+
+        let Some(period) = period_of(table) else {
+            continue;
+        };
+        found_table = true;
+
+        for row in table.select(&row_selector) {
+            let cells: Vec<String> = row
+                .select(&cell_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+            let cell = |index: usize| cells.get(index).map(String::as_str).unwrap_or_default();
+
+            if cell(code_col).is_empty() {
+                continue;
+            }
+
+            let letter_grade = cell(letter_col).to_uppercase();
+            let grade_point = point_col
+                .and_then(|col| cell(col).replace(',', ".").parse().ok())
+                .or_else(|| grade_point_for(&letter_grade))
+                .unwrap_or(0.0);
+
+            grades.push(Grade {
+                course_code: cell(code_col).to_string(),
+                course_name: cell(name_col).to_string(),
+                credits: cell(credits_col).parse().unwrap_or(0),
+                letter_grade,
+                grade_point,
+                period,
+            });
+        }
+    }
+
+    if !found_table {
+        return Err(super::selector_missing(&document, "table", "KHS page"));
+    }
+
+    Ok(Transcript { grades })
+}
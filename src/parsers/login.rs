@@ -0,0 +1,152 @@
+use scraper::{Html, Selector};
+
+/// Returns the message of the error banner CAS renders for a rejected login, if any.
+///
+/// CAS shows invalid credentials (and locked accounts) in an `.alert-danger` element,
+/// or in `#msg.errors` on older themes.
+pub fn parse_cas_error(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let selector = Selector::parse(".alert-danger, #msg.errors").unwrap();
+    document
+        .select(&selector)
+        .map(|el| el.text().collect::<Vec<_>>().join(" "))
+        .map(|text| text.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|text| !text.is_empty())
+}
+
+/// Detects a page served instead of the requested one because the session expired.
+///
+/// That is the CAS login form (a form holding both a password field and the
+/// `execution` token), which SPOT sometimes serves with a 200 at the requested path.
+/// Only the form counts: pages that merely mention logging in, in a heading or an
+/// alert, are ordinary pages.
+pub fn is_session_expired_page(html: &str) -> bool {
+    let document = Html::parse_document(html);
+
+    let form_selector = Selector::parse("form").unwrap();
+    let execution_selector = Selector::parse("input[name='execution']").unwrap();
+    let password_selector = Selector::parse("input[type='password']").unwrap();
+    document.select(&form_selector).any(|form| {
+        form.select(&execution_selector).next().is_some()
+            && form.select(&password_selector).next().is_some()
+    })
+}
+
+/// Markers (lowercase) shown by SSO when it forces a password change after login.
+const PASSWORD_CHANGE_MARKERS: &[&str] = &[
+    "ganti password",
+    "ubah password",
+    "ganti kata sandi",
+    "ubah kata sandi",
+    "change password",
+    "password anda telah kedaluwarsa",
+];
+
+/// Detects the SSO "ganti password" interstitial that is shown instead of redirecting to SPOT.
+///
+/// The page is recognized either by its URL (a path mentioning a password change) or by
+/// a heading/title carrying one of the known markers alongside a new-password form field.
+pub fn is_password_change_page(final_url: &str, html: &str) -> bool {
+    let url = final_url.to_lowercase();
+    if url.contains("changepassword")
+        || url.contains("change-password")
+        || url.contains("gantipassword")
+        || url.contains("ganti-password")
+    {
+        return true;
+    }
+
+    let document = Html::parse_document(html);
+    let heading_selector = Selector::parse("title, h1, h2, h3, h4, .alert, legend").unwrap();
+    let has_marker = document.select(&heading_selector).any(|el| {
+        let text = el.text().collect::<String>().to_lowercase();
+        PASSWORD_CHANGE_MARKERS
+            .iter()
+            .any(|marker| text.contains(marker))
+    });
+
+    let new_password_selector = Selector::parse(
+        "input[type='password'][name*='new'], input[type='password'][name*='baru'], input[type='password'][name*='confirm']",
+    )
+    .unwrap();
+
+    has_marker && document.select(&new_password_selector).next().is_some()
+}
+
+/// Markers (lowercase) shown by SSO on a one-time-password / two-factor challenge.
+const OTP_MARKERS: &[&str] = &[
+    "otp",
+    "kode verifikasi",
+    "kode autentikasi",
+    "verification code",
+    "authenticator",
+    "two-factor",
+    "2fa",
+];
+
+/// The form SSO shows when it asks for a one-time password after the credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OtpForm {
+    /// The form's `action`, if set (relative to the challenge page URL).
+    pub action: Option<String>,
+    /// The name of the input the code goes into.
+    pub code_field: String,
+    /// Hidden inputs (e.g. the CAS `execution` token) that must be posted back.
+    pub hidden_fields: Vec<(String, String)>,
+}
+
+/// Detects an OTP/2FA challenge page and extracts the form needed to answer it.
+///
+/// The page is recognized by its URL (`mfa`, `otp`, `2fa`, ...) or by a heading/label
+/// carrying one of the known markers, and must contain a single-line code input.
+pub fn parse_otp_challenge(final_url: &str, html: &str) -> Option<OtpForm> {
+    let url = final_url.to_lowercase();
+    let url_marker = ["mfa", "otp", "2fa", "gauth", "two-factor"]
+        .iter()
+        .any(|marker| url.contains(marker));
+
+    let document = Html::parse_document(html);
+    let heading_selector = Selector::parse("title, h1, h2, h3, h4, .alert, legend, label").unwrap();
+    let has_marker = url_marker
+        || document.select(&heading_selector).any(|el| {
+            let text = el.text().collect::<String>().to_lowercase();
+            OTP_MARKERS.iter().any(|marker| text.contains(marker))
+        });
+    if !has_marker {
+        return None;
+    }
+
+    let code_selector = Selector::parse(
+        "input[name='otp'], input[name='token'], input[name='code'], input[name='kode'], input[name='gauthCode'], input[autocomplete='one-time-code']",
+    )
+    .unwrap();
+    let code_input = document
+        .select(&code_selector)
+        .find(|input| input.value().attr("type") != Some("hidden"))?;
+
+    let form = code_input
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .find(|el| el.value().name() == "form");
+    let hidden_selector = Selector::parse("input[type='hidden'][name]").unwrap();
+    let hidden_fields = form
+        .map(|form| {
+            form.select(&hidden_selector)
+                .filter_map(|input| {
+                    let name = input.value().attr("name")?;
+                    let value = input.value().attr("value").unwrap_or_default();
+                    Some((name.to_string(), value.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(OtpForm {
+        action: form
+            .and_then(|form| form.value().attr("action"))
+            .filter(|action| !action.is_empty())
+            .map(String::from),
+        code_field: code_input.value().attr("name")?.to_string(),
+        hidden_fields,
+    })
+}
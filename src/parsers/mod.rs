@@ -1,4 +1,186 @@
+//! HTML parsers that turn raw SPOT pages into the crate's models.
+//!
+//! These are pure functions over HTML strings, so they can be used (and tested)
+//! without a live session. They are available without the `client` feature, e.g. to
+//! parse pages fetched by a browser when compiled to `wasm32-unknown-unknown`.
+
+pub mod announcement;
+pub mod attendance;
+pub mod course;
 pub mod course_detail;
 pub mod courses;
+pub mod datetime;
+pub mod grade;
+pub mod login;
+pub mod notification;
+pub mod period;
+pub mod quiz;
+pub mod schedule;
+pub mod sso;
 pub mod topic_detail;
 pub mod user;
+
+use crate::error::ScraperError;
+use scraper::{Html, Selector};
+
+/// The SPOT deployment the client talks to unless configured otherwise; relative hrefs
+/// returned by the parsers are paths on this host.
+pub const DEFAULT_BASE_URL: &str = "https://spot.upi.edu";
+
+/// Normalizes an href found on a SPOT page into the form every parsed model uses.
+///
+/// Links to SPOT become root-relative paths with their query (`/mhs/topik/1/2`), whether
+/// the page wrote them relative, root-relative or as absolute `spot.upi.edu` URLs.
+/// Links to other hosts stay absolute URLs. Page-relative hrefs are resolved against
+/// the site root; use [`normalize_href_on`] when the page the href came from is known.
+/// Fragments are dropped. Returns `None` for empty hrefs, bare fragments and non-http(s)
+/// links such as `mailto:` or `javascript:`.
+///
+/// Use [`SpotifierCoreClient::resolve_href`](crate::SpotifierCoreClient::resolve_href)
+/// to turn the result into an absolute URL for the configured SPOT instance.
+pub fn normalize_href(href: &str) -> Option<String> {
+    normalize_href_on("/", href)
+}
+
+/// Like [`normalize_href`], but resolves page-relative hrefs against `page`, the path
+/// (or absolute URL) of the page the href was found on.
+///
+/// On the topic page `/mhs/topik/1/2`, `tugas/5` becomes `/mhs/topik/1/tugas/5`.
+pub fn normalize_href_on(page: &str, href: &str) -> Option<String> {
+    let href = href.trim();
+    if href.is_empty() || href.starts_with('#') {
+        return None;
+    }
+    let base = url::Url::parse(DEFAULT_BASE_URL).ok()?;
+    let mut url = base.join(page).ok()?.join(href).ok()?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return None;
+    }
+    url.set_fragment(None);
+    if url.host_str() != base.host_str() {
+        return Some(url.to_string());
+    }
+    Some(match url.query() {
+        Some(query) => format!("{}?{}", url.path(), query),
+        None => url.path().to_string(),
+    })
+}
+
+/// How much of the page is kept in error snippets (`SelectorMissing`, `HttpStatus`).
+const SNIPPET_CHARS: usize = 200;
+
+/// Strips all markup from an HTML fragment, returning its text with whitespace collapsed.
+pub fn html_to_text(html: &str) -> String {
+    Html::parse_fragment(html)
+        .root_element()
+        .text()
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Builds a `ScraperError::SelectorMissing` for a selector that matched nothing.
+///
+/// The snippet is taken from the document's `<body>` so that markup changes on SPOT's
+/// side can be spotted from the error alone.
+pub(crate) fn selector_missing(document: &Html, selector: &str, context: &str) -> ScraperError {
+    let body_selector = Selector::parse("body").unwrap();
+    let html = document
+        .select(&body_selector)
+        .next()
+        .map(|body| body.inner_html())
+        .unwrap_or_else(|| document.root_element().html());
+
+    ScraperError::SelectorMissing {
+        selector: selector.to_string(),
+        context: context.to_string(),
+        snippet: snippet(&html),
+    }
+}
+
+/// Collapses whitespace in `html` and truncates it to a short snippet for error messages.
+pub(crate) fn snippet(html: &str) -> String {
+    html.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(SNIPPET_CHARS)
+        .collect()
+}
+
+/// Extracts the video ID and optional start time (in seconds) from a YouTube URL.
+///
+/// Accepts the forms SPOT embeds videos with: `youtube.com/embed/ID`,
+/// `youtube-nocookie.com/embed/ID`, `youtu.be/ID` and `youtube.com/watch?v=ID`, with or
+/// without a scheme. The start time is read from a `start` or `t` parameter (`90`, `90s`
+/// or `1m30s`); every other parameter is dropped. Returns `None` for other hosts and for
+/// IDs that are not 11 characters of `[A-Za-z0-9_-]`.
+pub fn parse_youtube_url(url: &str) -> Option<(String, Option<u32>)> {
+    let url = url.trim();
+    let url = if url.starts_with("//") {
+        url::Url::parse(&format!("https:{}", url))
+    } else if url.contains("://") {
+        url::Url::parse(url)
+    } else {
+        url::Url::parse(&format!("https://{}", url))
+    }
+    .ok()?;
+
+    let host = url
+        .host_str()?
+        .trim_start_matches("www.")
+        .trim_start_matches("m.");
+    let mut segments = url.path_segments()?.filter(|segment| !segment.is_empty());
+    let id = match host {
+        "youtu.be" => segments.next().map(String::from),
+        "youtube.com" | "youtube-nocookie.com" => match segments.next()? {
+            "embed" | "v" | "shorts" | "live" => segments.next().map(String::from),
+            "watch" => url
+                .query_pairs()
+                .find(|(key, _)| key == "v")
+                .map(|(_, value)| value.into_owned()),
+            _ => None,
+        },
+        _ => None,
+    }?;
+
+    let is_valid_id = id.len() == 11
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !is_valid_id {
+        return None;
+    }
+
+    let start = url
+        .query_pairs()
+        .find(|(key, _)| key == "start" || key == "t")
+        .and_then(|(_, value)| parse_start_time(&value));
+    Some((id, start))
+}
+
+/// Parses a YouTube start time such as `90`, `90s` or `1h2m3s` into seconds.
+fn parse_start_time(value: &str) -> Option<u32> {
+    if let Ok(seconds) = value.parse() {
+        return Some(seconds);
+    }
+
+    let mut total = 0u32;
+    let mut number = String::new();
+    for c in value.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        total = total.checked_add(number.parse::<u32>().ok()?.checked_mul(unit)?)?;
+        number.clear();
+    }
+    number.is_empty().then_some(total)
+}
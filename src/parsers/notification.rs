@@ -0,0 +1,66 @@
+// src/parsers/notification.rs
+
+use super::datetime::parse_id_datetime;
+use crate::error::Result;
+use crate::models::{Notification, NotificationKind};
+use scraper::{CaseSensitivity, Html, Selector};
+
+/// Maps the heading SPOT shows above a notification to its category.
+fn kind_from_label(label: &str) -> NotificationKind {
+    let label = label.to_lowercase();
+    if label.contains("nilai") {
+        NotificationKind::Grade
+    } else if label.contains("materi") || label.contains("topik") {
+        NotificationKind::Material
+    } else if label.contains("forum") || label.contains("diskusi") {
+        NotificationKind::Forum
+    } else if label.contains("pengumuman") {
+        NotificationKind::Announcement
+    } else {
+        NotificationKind::Other
+    }
+}
+
+/// Parses the notification list (`/mhs/notifikasi`).
+///
+/// Each `.message-center a[data-id]` entry is one notification; entries with the
+/// `unread` class have not been opened yet. Entries without a numeric `data-id` are
+/// skipped since they cannot be marked as read. The result is sorted newest first,
+/// with undated notifications last.
+pub fn parse_notifications_from_html(html: &str) -> Result<Vec<Notification>> {
+    let document = Html::parse_document(html);
+    let item_selector = Selector::parse(".message-center a[data-id]").unwrap();
+    let label_selector = Selector::parse("h5").unwrap();
+    let message_selector = Selector::parse(".mail-desc").unwrap();
+    let time_selector = Selector::parse(".time").unwrap();
+
+    let text_of = |el: scraper::ElementRef, selector: &Selector| {
+        el.select(selector)
+            .next()
+            .map(|e| e.text().collect::<String>().trim().to_string())
+    };
+
+    let mut notifications: Vec<Notification> = document
+        .select(&item_selector)
+        .filter_map(|item| {
+            let id = item.value().attr("data-id")?.trim().parse().ok()?;
+            Some(Notification {
+                id,
+                kind: kind_from_label(&text_of(item, &label_selector).unwrap_or_default()),
+                message: text_of(item, &message_selector).unwrap_or_default(),
+                link: item
+                    .value()
+                    .attr("href")
+                    .and_then(|href| super::normalize_href_on("/mhs/notifikasi", href)),
+                created_at: text_of(item, &time_selector).and_then(|t| parse_id_datetime(&t)),
+                is_read: !item
+                    .value()
+                    .has_class("unread", CaseSensitivity::AsciiCaseInsensitive),
+            })
+        })
+        .collect();
+
+    // `None` sorts before `Some`, so reversing puts undated entries last.
+    notifications.sort_by_key(|n| std::cmp::Reverse(n.created_at));
+    Ok(notifications)
+}
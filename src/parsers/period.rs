@@ -0,0 +1,56 @@
+// src/parsers/period.rs
+
+use crate::error::Result;
+use crate::models::Period;
+use scraper::{Html, Selector};
+
+/// Selector for the entries of SPOT's period switcher: the `/adm/semester/{YYYYN}` links
+/// of the navbar dropdown, or the options of a period `<select>` (one named after the
+/// semester or period, or inside a form posting to the semester switch). Other
+/// `<select>`s on the page are not part of it.
+const PERIOD_OPTION_SELECTOR: &str = "a[href*='/adm/semester/'], \
+    select[name*='semester' i] option, select[name*='periode' i] option, \
+    form[action*='/semester'] select option";
+
+/// Parses the periods offered by SPOT's period switcher, in page order.
+///
+/// Each entry's label (e.g. `2025/2026 - Ganjil`) is read with
+/// [`Period::from_academic_year_string`]; an entry whose label does not parse falls back
+/// to the `YYYYN` code in its link or option value. Entries that match neither, such as
+/// a "Pilih Semester" placeholder, are skipped, as are duplicates. Returns
+/// `ScraperError::SelectorMissing` if the page has no period switcher at all.
+pub fn parse_available_periods_from_html(html: &str) -> Result<Vec<Period>> {
+    let document = Html::parse_document(html);
+    let option_selector = Selector::parse(PERIOD_OPTION_SELECTOR).unwrap();
+
+    let mut found_switcher = false;
+    let mut periods: Vec<Period> = Vec::new();
+    for option in document.select(&option_selector) {
+        found_switcher = true;
+        let label = option.text().collect::<Vec<_>>().join(" ");
+        let label = label.split_whitespace().collect::<Vec<_>>().join(" ");
+        let code = option
+            .value()
+            .attr("href")
+            .and_then(|href| href.split("/adm/semester/").nth(1))
+            .or_else(|| option.value().attr("value"));
+
+        let period = Period::from_academic_year_string(&label)
+            .ok()
+            .or_else(|| code.and_then(|code| Period::from_code(code).ok()));
+        if let Some(period) = period
+            && !periods.contains(&period)
+        {
+            periods.push(period);
+        }
+    }
+
+    if !found_switcher {
+        return Err(super::selector_missing(
+            &document,
+            PERIOD_OPTION_SELECTOR,
+            "period switcher",
+        ));
+    }
+    Ok(periods)
+}
@@ -0,0 +1,110 @@
+// src/parsers/quiz.rs
+
+use super::datetime::parse_id_deadline;
+use crate::error::Result;
+use crate::models::Quiz;
+use scraper::{ElementRef, Html, Selector};
+
+/// Returns the trimmed text of the `index`-th `<td>` of a row, without the leading ": ".
+fn get_td_content(row: ElementRef, index: usize) -> Option<String> {
+    row.select(&Selector::parse("td").unwrap())
+        .nth(index)
+        .map(|cell| {
+            cell.text()
+                .collect::<String>()
+                .trim()
+                .trim_start_matches(':')
+                .trim()
+                .to_string()
+        })
+}
+
+/// Extracts every number from a string such as `"1 / 3"` or `"1 dari 3"`.
+fn numbers_in(s: &str) -> Vec<u32> {
+    s.split(|c: char| !c.is_ascii_digit())
+        .filter_map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Parses the quizzes (kuis) listed in the `#kuis` section of a topic page.
+pub fn parse_quizzes_from_html(html: &str) -> Result<Vec<Quiz>> {
+    Ok(extract_quizzes(&Html::parse_document(html), "/"))
+}
+
+/// Parses quizzes from an already-parsed topic page.
+///
+/// Each `#kuis .table-striped` table describes one quiz. The link to the quiz is taken
+/// from the table or, failing that, from the element right after it. Relative links are
+/// resolved against `page`.
+pub(crate) fn extract_quizzes(document: &Html, page: &str) -> Vec<Quiz> {
+    let table_selector = Selector::parse("#kuis .table-striped").unwrap();
+    let row_selector = Selector::parse("tbody > tr").unwrap();
+    let link_selector = Selector::parse("a[href*='/kuis/']").unwrap();
+
+    document
+        .select(&table_selector)
+        .map(|table| {
+            let mut quiz = Quiz {
+                id: None,
+                title: String::new(),
+                due_date: None,
+                attempts_allowed: None,
+                attempts_used: 0,
+                best_score: None,
+                href: None,
+            };
+
+            for row in table.select(&row_selector) {
+                let (Some(header), Some(value)) = (get_td_content(row, 0), get_td_content(row, 1))
+                else {
+                    continue;
+                };
+                match header.as_str() {
+                    "Judul" => quiz.title = value,
+                    "Batas Waktu" | "Waktu Selesai" => quiz.due_date = parse_id_deadline(&value),
+                    "Percobaan" => {
+                        // "1 / 3" -> 1 attempt used out of 3 allowed
+                        let numbers = numbers_in(&value);
+                        quiz.attempts_used = numbers.first().copied().unwrap_or(0);
+                        quiz.attempts_allowed = numbers.get(1).copied();
+                    }
+                    "Jumlah Percobaan" | "Batas Percobaan" => {
+                        quiz.attempts_allowed = numbers_in(&value).first().copied();
+                    }
+                    "Nilai Tertinggi" | "Nilai" => {
+                        quiz.best_score = value.replace(',', ".").parse().ok();
+                    }
+                    _ => {}
+                }
+            }
+
+            let link = table.select(&link_selector).next().or_else(|| {
+                table
+                    .next_siblings()
+                    .find_map(ElementRef::wrap)
+                    .and_then(|sibling| {
+                        if link_selector.matches(&sibling) {
+                            Some(sibling)
+                        } else {
+                            sibling.select(&link_selector).next()
+                        }
+                    })
+            });
+            quiz.href = link
+                .and_then(|a| a.value().attr("href"))
+                .and_then(|href| super::normalize_href_on(page, href));
+            quiz.id = quiz
+                .href
+                .as_deref()
+                .and_then(|h| h.trim_end_matches('/').split('/').next_back())
+                .and_then(|v| v.parse::<u64>().ok());
+
+            // Without any recorded attempt there is no meaningful score yet
+            if quiz.attempts_used == 0 {
+                quiz.best_score = None;
+            }
+
+            quiz
+        })
+        .collect()
+}
@@ -0,0 +1,115 @@
+// src/parsers/schedule.rs
+
+use crate::error::Result;
+use crate::models::ScheduleEntry;
+use chrono::{NaiveTime, Weekday};
+use scraper::{Html, Selector};
+
+/// Parses an Indonesian day name ("Senin", "Jum'at", ...) into a `Weekday`.
+pub fn parse_weekday(s: &str) -> Option<Weekday> {
+    let day = s.trim().to_lowercase().replace(['\'', '’'], "");
+    Some(match day.as_str() {
+        "senin" => Weekday::Mon,
+        "selasa" => Weekday::Tue,
+        "rabu" => Weekday::Wed,
+        "kamis" => Weekday::Thu,
+        "jumat" => Weekday::Fri,
+        "sabtu" => Weekday::Sat,
+        "minggu" | "ahad" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Parses a time range such as `"07:00 - 08:40"`.
+///
+/// A class spanning several slots may list each of them (`"07:00 - 08:40, 08:40 - 10:20"`);
+/// the earliest start and latest end are used.
+fn parse_time_range(s: &str) -> Option<(NaiveTime, NaiveTime)> {
+    let times: Vec<NaiveTime> = s
+        .split(|c: char| !(c.is_ascii_digit() || c == ':' || c == '.'))
+        .filter(|part| !part.is_empty())
+        .filter_map(|part| NaiveTime::parse_from_str(&part.replace('.', ":"), "%H:%M").ok())
+        .collect();
+    Some((*times.iter().min()?, *times.iter().max()?))
+}
+
+/// Parses the timetable page (`/mhs/jadwal`).
+///
+/// Columns are located by their header (`Hari`, `Jam`/`Waktu`, `Kode`, `Mata Kuliah`,
+/// `Ruang`, `Dosen`). SPOT merges the day cell over all classes of that day with
+/// `rowspan`, so rows missing that cell inherit the previous row's day. Rows without a
+/// course code, or whose code is a placeholder like `-`, are skipped.
+pub fn parse_schedule_from_html(html: &str) -> Result<Vec<ScheduleEntry>> {
+    let document = Html::parse_document(html);
+    let table_selector = Selector::parse("table").unwrap();
+    let header_selector = Selector::parse("thead th").unwrap();
+    let row_selector = Selector::parse("tbody > tr").unwrap();
+    let cell_selector = Selector::parse("td").unwrap();
+
+    for table in document.select(&table_selector) {
+        let headers: Vec<String> = table
+            .select(&header_selector)
+            .map(|th| th.text().collect::<String>().trim().to_lowercase())
+            .collect();
+        let column = |names: &[&str]| {
+            headers
+                .iter()
+                .position(|header| names.iter().any(|name| header == name))
+        };
+
+        let (Some(day_col), Some(time_col), Some(code_col), Some(name_col)) = (
+            column(&["hari"]),
+            column(&["jam", "waktu"]),
+            column(&["kode", "kode mk"]),
+            column(&["mata kuliah", "nama mata kuliah"]),
+        ) else {
+            continue;
+        };
+        let room_col = column(&["ruang", "ruangan"]);
+        let lecturer_col = column(&["dosen", "dosen pengampu"]);
+
+        let mut entries = Vec::new();
+        let mut current_day = None;
+
+        for row in table.select(&row_selector) {
+            let mut cells: Vec<String> = row
+                .select(&cell_selector)
+                .map(|td| td.text().collect::<String>().trim().to_string())
+                .collect();
+
+            // The day cell is merged over several rows; re-insert it where it is missing.
+            if cells.len() + 1 == headers.len() {
+                cells.insert(day_col, String::new());
+            } else if let Some(day) =
+                parse_weekday(&cells.get(day_col).cloned().unwrap_or_default())
+            {
+                current_day = Some(day);
+            }
+            let cell = |index: usize| cells.get(index).map(String::as_str).unwrap_or_default();
+
+            let code = cell(code_col);
+            if code.is_empty() || code.chars().all(|c| c == '-') {
+                continue;
+            }
+            let (Some(day_of_week), Some((start_time, end_time))) =
+                (current_day, parse_time_range(cell(time_col)))
+            else {
+                continue;
+            };
+
+            entries.push(ScheduleEntry {
+                course_code: code.to_string(),
+                course_name: cell(name_col).to_string(),
+                day_of_week,
+                start_time,
+                end_time,
+                room: room_col.map(cell).unwrap_or_default().to_string(),
+                lecturer: lecturer_col.map(cell).unwrap_or_default().to_string(),
+            });
+        }
+
+        return Ok(entries);
+    }
+
+    Err(super::selector_missing(&document, "table", "schedule page"))
+}
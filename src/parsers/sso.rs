@@ -0,0 +1,59 @@
+use crate::error::{Result, ScraperError};
+use scraper::{Html, Selector};
+
+/// The CAS login form: where to post the credentials and what to post back with them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CasLoginForm {
+    /// The form's `action`, if set (relative to the login page URL).
+    pub action: Option<String>,
+    /// The single-use `execution` token tying the POST to this rendering of the form.
+    pub execution: String,
+    /// Every hidden input of the form, including `execution` and `_eventId`.
+    pub hidden_fields: Vec<(String, String)>,
+}
+
+/// Extracts the login form from a CAS login page.
+///
+/// Returns `ScraperError::TokenNotFound` if the page has no non-empty `execution`
+/// input, e.g. because SSO served an error page instead.
+pub fn parse_cas_login_form(html: &str) -> Result<CasLoginForm> {
+    let document = Html::parse_document(html);
+    let token_selector = Selector::parse("input[name='execution']").unwrap();
+    let token_input = document
+        .select(&token_selector)
+        .find(|input| input.value().attr("value").is_some_and(|v| !v.is_empty()))
+        .ok_or(ScraperError::TokenNotFound)?;
+
+    let form = token_input
+        .ancestors()
+        .filter_map(scraper::ElementRef::wrap)
+        .find(|el| el.value().name() == "form");
+    let hidden_selector = Selector::parse("input[type='hidden'][name]").unwrap();
+    let hidden_fields = form
+        .map(|form| {
+            form.select(&hidden_selector)
+                .filter_map(|input| {
+                    let name = input.value().attr("name")?;
+                    let value = input.value().attr("value").unwrap_or_default();
+                    Some((name.to_string(), value.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(CasLoginForm {
+        action: form
+            .and_then(|form| form.value().attr("action"))
+            .filter(|action| !action.is_empty())
+            .map(String::from),
+        execution: token_input.value().attr("value").unwrap().to_string(),
+        hidden_fields,
+    })
+}
+
+/// Extracts the CAS `execution` token from a login page.
+///
+/// Returns `ScraperError::TokenNotFound` if it is missing or empty.
+pub fn parse_execution_token(html: &str) -> Result<String> {
+    parse_cas_login_form(html).map(|form| form.execution)
+}
@@ -1,21 +1,12 @@
 // src/parsers/topic_detail.rs
 
+use super::datetime::{parse_id_datetime, parse_id_deadline};
 use crate::error::Result;
-use crate::models::{Answer, Content, Task, TaskStatus, TopicDetail};
-use chrono::NaiveDateTime;
+use crate::models::{Answer, Content, ContentKind, RubricItem, Task, TaskStatus, TopicDetail};
 use scraper::{CaseSensitivity, ElementRef, Html, Selector};
-
-fn parse_dt(s: &str) -> Option<NaiveDateTime> {
-    let t = s.trim();
-    [
-        "%d-%m-%Y %H:%M",
-        "%d-%m-%Y %H:%M:%S",
-        "%d/%m/%Y %H:%M",
-        "%d/%m/%Y %H:%M:%S",
-    ]
-    .iter()
-    .find_map(|fmt| NaiveDateTime::parse_from_str(t, fmt).ok())
-}
+use std::collections::HashSet;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 /// Helper untuk mendapatkan konten teks yang sudah di-trim dari elemen `<td>` berdasarkan indeksnya.
 fn get_td_content(row: ElementRef, index: usize) -> Option<String> {
@@ -24,15 +15,310 @@ fn get_td_content(row: ElementRef, index: usize) -> Option<String> {
         .map(|cell| cell.text().collect::<String>().trim().replace(": ", ""))
 }
 
-/// Helper untuk mendapatkan atribut `href` dari tag `<a>` di dalam `<td>`.
-fn get_td_file_href(row: ElementRef, index: usize) -> Option<String> {
+/// Helper untuk mendapatkan atribut `href` dari tag `<a>` di dalam `<td>`, dinormalisasi
+/// terhadap halaman `page`.
+fn get_td_file_href(row: ElementRef, index: usize, page: &str) -> Option<String> {
     row.select(&Selector::parse("td").unwrap())
         .nth(index)?
         .select(&Selector::parse("a").unwrap())
         .next()?
         .value()
         .attr("href")
-        .map(String::from)
+        .and_then(|href| super::normalize_href_on(page, href))
+}
+
+/// Menghapus blok konten ganda (SPOT terkadang merender blok yang sama dua kali).
+///
+/// Kunci duplikat adalah pasangan `(youtube_id, hash HTML yang whitespace-nya dinormalisasi)`.
+/// Kemunculan pertama beserta `id`-nya (posisi aslinya di halaman) tetap dipertahankan.
+fn dedup_contents(contents: Vec<Content>) -> Vec<Content> {
+    let mut seen = HashSet::new();
+    contents
+        .into_iter()
+        .filter(|content| {
+            let mut hasher = DefaultHasher::new();
+            content
+                .raw_html
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" ")
+                .hash(&mut hasher);
+            seen.insert((content.youtube_id.clone(), hasher.finish()))
+        })
+        .collect()
+}
+
+/// Meng-escape karakter khusus HTML pada teks biasa.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Mengambil isi jawaban dari `.panel-body` sebagai HTML, dengan format aslinya.
+///
+/// Tautan aksi (unduh file, hapus jawaban) dan tabel info pengumpulan tidak ikut diambil.
+fn submission_html(body: ElementRef) -> String {
+    let action_selector =
+        Selector::parse("a[href*='/tugas/mhs'], a[href*='tugas_del'], table").unwrap();
+
+    body.children()
+        .filter_map(|node| {
+            if let Some(text) = node.value().as_text() {
+                return Some(escape_html(text));
+            }
+            let element = ElementRef::wrap(node)?;
+            let is_action = action_selector.matches(&element)
+                || element.select(&action_selector).next().is_some();
+            (!is_action).then(|| element.html())
+        })
+        .collect::<String>()
+        .trim()
+        .to_string()
+}
+
+/// Ekstensi file yang dianggap sebagai lampiran materi.
+const FILE_EXTENSIONS: &[&str] = &[
+    "pdf", "doc", "docx", "ppt", "pptx", "xls", "xlsx", "zip", "rar", "7z", "txt", "csv", "png",
+    "jpg", "jpeg", "mp4", "mp3",
+];
+
+/// Menentukan apakah sebuah href mengarah ke file yang dapat diunduh.
+fn is_file_href(href: &str) -> bool {
+    let path = href.split(['?', '#']).next().unwrap_or_default();
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|segment| segment.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    extension.is_some_and(|ext| FILE_EXTENSIONS.contains(&ext.as_str()))
+        || path.contains("/download")
+        || path.contains("/materi/mhs")
+}
+
+/// Mengklasifikasikan blok konten non-video berdasarkan HTML-nya; lihat [`Content::kind`].
+///
+/// Href relatif di-resolve terhadap halaman `page`.
+pub(crate) fn classify_content(raw_html: &str, page: &str) -> ContentKind {
+    let fragment = Html::parse_fragment(raw_html);
+    let link_selector = Selector::parse("a[href]").unwrap();
+    let links: Vec<ElementRef> = fragment.select(&link_selector).collect();
+
+    let text_outside_links = fragment
+        .root_element()
+        .descendants()
+        .filter_map(|node| node.value().as_text().map(|text| (node, text)))
+        .filter(|(node, _)| {
+            !node
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(|el| el.value().name() == "a")
+        })
+        .any(|(_, text)| !text.trim().is_empty());
+
+    if text_outside_links || links.len() > 1 {
+        return ContentKind::Article {
+            html: raw_html.trim().to_string(),
+        };
+    }
+
+    let Some(link) = links.first() else {
+        let has_markup = fragment
+            .select(&Selector::parse("img, table, ul, ol").unwrap())
+            .next()
+            .is_some();
+        return if has_markup {
+            ContentKind::Article {
+                html: raw_html.trim().to_string(),
+            }
+        } else {
+            ContentKind::Unknown
+        };
+    };
+
+    let raw_href = link.value().attr("href").unwrap_or_default();
+    if is_file_href(raw_href) {
+        let href = super::normalize_href_on(page, raw_href).unwrap_or_else(|| raw_href.to_string());
+        let text = link.text().collect::<String>().trim().to_string();
+        let name = if text.is_empty() {
+            href.split(['?', '#'])
+                .next()
+                .and_then(|path| path.rsplit('/').next())
+                .unwrap_or_default()
+                .to_string()
+        } else {
+            text
+        };
+        ContentKind::File { href, name }
+    } else {
+        ContentKind::Link {
+            url: super::normalize_href_on(page, raw_href).unwrap_or_else(|| raw_href.to_string()),
+        }
+    }
+}
+
+/// Mengambil angka pertama dari teks nilai, misalnya `"87,5"` atau `"20 / 25"`.
+fn parse_score(text: &str) -> Option<f32> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let number: String = text[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',' || *c == '.')
+        .collect();
+    number.replace(',', ".").parse().ok()
+}
+
+/// Mencari tabel rubrik penilaian di dalam panel jawaban.
+///
+/// Tabel rubrik dikenali dari header `<th>`-nya: kolom kriteria dan kolom nilai wajib ada,
+/// sedangkan kolom nilai maksimal opsional (sel nilai seperti `"20/25"` juga didukung).
+/// Baris total tidak ikut dimasukkan.
+fn parse_rubric(panel: ElementRef) -> Option<(ElementRef, Vec<RubricItem>)> {
+    let th_selector = Selector::parse("th").unwrap();
+    let td_selector = Selector::parse("td").unwrap();
+
+    panel
+        .select(&Selector::parse("table").unwrap())
+        .find_map(|table| {
+            let headers: Vec<String> = table
+                .select(&th_selector)
+                .map(|th| th.text().collect::<String>().trim().to_lowercase())
+                .collect();
+            let column = |names: &[&str]| {
+                headers
+                    .iter()
+                    .position(|h| names.iter().any(|name| h.contains(name)))
+            };
+            let max_col = column(&["maks", "bobot"]);
+            let name_col = column(&["kriteria", "rubrik", "aspek"])?;
+            let points_col = headers.iter().enumerate().position(|(i, h)| {
+                Some(i) != max_col && ["nilai", "skor", "poin"].iter().any(|n| h.contains(n))
+            })?;
+
+            let items = table
+                .select(&Selector::parse("tr").unwrap())
+                .filter_map(|row| {
+                    let cells: Vec<String> = row
+                        .select(&td_selector)
+                        .map(|td| td.text().collect::<String>().trim().to_string())
+                        .collect();
+                    let name = cells.get(name_col)?.clone();
+                    if name.is_empty()
+                        || ["total", "jumlah"].contains(&name.to_lowercase().as_str())
+                    {
+                        return None;
+                    }
+                    let points_cell = cells.get(points_col)?;
+                    let (points, max_points) = match max_col {
+                        Some(col) => (parse_score(points_cell)?, parse_score(cells.get(col)?)?),
+                        None => {
+                            let (points, max) = points_cell.split_once('/')?;
+                            (parse_score(points)?, parse_score(max)?)
+                        }
+                    };
+                    Some(RubricItem {
+                        name,
+                        points,
+                        max_points,
+                    })
+                })
+                .collect();
+            Some((table, items))
+        })
+}
+
+/// Wadah error formulir pengumpulan: modal tugas atau formulir `tugas_store`.
+const SUBMISSION_ERROR_SELECTOR: &str =
+    "#tugas .modal .alert-danger, form[action*='tugas_store'] .alert-danger";
+
+/// Mengambil pesan error dari halaman yang dikembalikan setelah mengirim tugas.
+///
+/// SPOT menampilkan penolakan (misalnya tipe atau ukuran file tidak valid) sebagai
+/// `.alert-danger` di dalam formulir pengumpulan tugas. Peringatan lain di halaman
+/// (misalnya pada konten topik) diabaikan. Mengembalikan `None` jika tidak ada pesan error.
+pub fn parse_submission_error(html: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    let alert = document
+        .select(&Selector::parse(SUBMISSION_ERROR_SELECTOR).unwrap())
+        .next()?;
+    let message = alert
+        .text()
+        .flat_map(str::split_whitespace)
+        .filter(|word| *word != "×")
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!message.is_empty()).then_some(message)
+}
+
+/// Memeriksa apakah halaman masih menampilkan jawaban `answer_id`.
+///
+/// Setiap jawaban yang tampil memiliki tautan hapus `tugas_del/.../{answer_id}`,
+/// sehingga setelah penghapusan berhasil tautan tersebut tidak lagi ada.
+pub fn has_answer(html: &str, answer_id: u64) -> bool {
+    let document = Html::parse_document(html);
+    document
+        .select(&Selector::parse("a[href*='tugas_del']").unwrap())
+        .filter_map(|a| a.value().attr("href"))
+        .any(|href| href.trim_end_matches('/').rsplit('/').next() == Some(&answer_id.to_string()))
+}
+
+/// Mencari jawaban yang baru saja dikirim untuk tugas `task_id` pada respons pengumpulan.
+///
+/// Biasanya SPOT mengarahkan kembali ke halaman topik, sehingga jawaban diambil dari
+/// tugas yang bersangkutan (dicocokkan lewat `id_tg`; jika tidak ada tugas yang ber-ID,
+/// tugas satu-satunya yang sudah dijawab). Halaman konfirmasi tanpa tabel tugas cukup
+/// memuat tautan hapus `tugas_del/{course_id}/{topic_id}/{id}`; dalam hal ini hanya `id`
+/// yang terisi. Tautan hapus untuk mata kuliah atau topik lain diabaikan.
+/// Mengembalikan `None` jika ID jawaban tidak ditemukan.
+pub fn parse_submitted_answer(
+    html: &str,
+    course_id: u64,
+    topic_id: u64,
+    task_id: u64,
+) -> Option<Answer> {
+    if let Ok(topic) = parse_topic_detail_from_html(html, topic_id, course_id)
+        && !topic.tasks.is_empty()
+    {
+        let answered = |task: &&Task| task.answer.as_ref().is_some_and(|a| a.id.is_some());
+        let task = match topic.tasks.iter().find(|task| task.id == Some(task_id)) {
+            Some(task) => Some(task),
+            None if topic.tasks.iter().all(|task| task.id.is_none()) => {
+                let mut answered_tasks = topic.tasks.iter().filter(answered);
+                answered_tasks
+                    .next()
+                    .filter(|_| answered_tasks.next().is_none())
+            }
+            None => None,
+        };
+        return task.filter(answered).and_then(|task| task.answer.clone());
+    }
+
+    let document = Html::parse_document(html);
+    let id = document
+        .select(&Selector::parse("a[href*='tugas_del']").unwrap())
+        .filter_map(|a| a.value().attr("href"))
+        .find_map(|href| delete_link_answer_id(href, course_id, topic_id))?;
+    Some(Answer {
+        id: Some(id),
+        content: String::new(),
+        file_href: None,
+        is_graded: false,
+        lecturer_notes: String::new(),
+        score: 0.0,
+        date_submitted: None,
+        rubric: Vec::new(),
+    })
+}
+
+/// Mengambil ID jawaban dari tautan hapus `tugas_del/{course_id}/{topic_id}/{id}`.
+///
+/// Tautan milik mata kuliah atau topik lain menghasilkan `None`.
+fn delete_link_answer_id(href: &str, course_id: u64, topic_id: u64) -> Option<u64> {
+    let path = href.split(['?', '#']).next()?;
+    let mut segments = path.trim_end_matches('/').rsplit('/');
+    let id = segments.next()?.parse().ok()?;
+    let topic = segments.next()?.parse::<u64>().ok()?;
+    let course = segments.next()?.parse::<u64>().ok()?;
+    (segments.next() == Some("tugas_del") && course == course_id && topic == topic_id).then_some(id)
 }
 
 /// Fungsi utama untuk mem-parsing seluruh halaman detail topik.
@@ -42,6 +328,7 @@ pub fn parse_topic_detail_from_html(
     course_id: u64,
 ) -> Result<TopicDetail> {
     let document = Html::parse_document(html);
+    let href = format!("/mhs/topik/{}/{}", course_id, topic_id);
 
     let description = document
         .select(&Selector::parse("#dashboard div").unwrap())
@@ -50,32 +337,28 @@ pub fn parse_topic_detail_from_html(
     let access_time = document
         .select(&Selector::parse(".panel-heading p").unwrap())
         .next()
-        .and_then(|p| parse_dt(&p.text().collect::<String>().replace("Waktu Akses:", "")));
+        .and_then(|p| parse_id_datetime(&p.text().collect::<String>().replace("Waktu Akses:", "")));
 
     let content_selector = Selector::parse("#materi .row .col-lg-12").unwrap();
     let contents: Vec<Content> = document
         .select(&content_selector)
         .enumerate()
         .map(|(index, content_el)| {
-            let youtube_id = content_el
-                .select(&Selector::parse("iframe[src*='youtube.com']").unwrap())
-                .next()
-                .and_then(|iframe| iframe.value().attr("src"))
-                .and_then(|src| {
-                    src.split("embed/")
-                        .last()?
-                        .split('?')
-                        .next()
-                        .map(String::from)
-                });
+            let video = content_el
+                .select(&Selector::parse("iframe[src*='youtu']").unwrap())
+                .filter_map(|iframe| iframe.value().attr("src"))
+                .find_map(super::parse_youtube_url);
             let raw_html = content_el.inner_html();
             Content {
                 id: index as u32,
-                youtube_id,
+                youtube_start_secs: video.as_ref().and_then(|(_, start)| *start),
+                youtube_id: video.map(|(id, _)| id),
                 raw_html,
+                page_href: href.clone(),
             }
         })
         .collect();
+    let contents = dedup_contents(contents);
 
     let task_instruction_selector = Selector::parse("#tugas .table-striped").unwrap();
     let task_modal_selector = Selector::parse("#tugas .modal").unwrap();
@@ -96,6 +379,8 @@ pub fn parse_topic_detail_from_html(
             due_date: None,
             status: TaskStatus::NotSubmitted,
             answer: None,
+            min_chars: None,
+            max_chars: None,
         };
 
         for row in task_table.select(&Selector::parse("tbody > tr").unwrap()) {
@@ -103,15 +388,15 @@ pub fn parse_topic_detail_from_html(
                 match header.as_str() {
                     "Judul" => task.title = get_td_content(row, 1).unwrap_or_default(),
                     "Deskripsi" => task.description = get_td_content(row, 1).unwrap_or_default(),
-                    "File" => task.file = get_td_file_href(row, 1),
+                    "File" => task.file = get_td_file_href(row, 1, &href),
                     "Waktu Pengumpulan" => {
                         let dates: Vec<String> = row
                             .select(&Selector::parse("b").unwrap())
                             .map(|b| b.text().collect())
                             .collect();
                         if dates.len() >= 2 {
-                            task.start_date = parse_dt(&dates[0]);
-                            task.due_date = parse_dt(&dates[1]);
+                            task.start_date = parse_id_datetime(&dates[0]);
+                            task.due_date = parse_id_deadline(&dates[1]);
                         }
                     }
                     _ => {}
@@ -120,73 +405,77 @@ pub fn parse_topic_detail_from_html(
         }
 
         let next_element = task_table.next_siblings().find_map(ElementRef::wrap);
-        if let Some(sibling) = next_element {
-            if sibling
+        if let Some(sibling) = next_element
+            && sibling
                 .value()
                 .has_class("panel-info", CaseSensitivity::AsciiCaseInsensitive)
-            {
-                task.status = TaskStatus::Submitted;
-                let mut answer = Answer {
-                    id: None,
-                    content: String::new(),
-                    file_href: None,
-                    is_graded: false,
-                    lecturer_notes: String::new(),
-                    score: 0.0,
-                    date_submitted: None,
-                };
-
-                for row in sibling.select(&Selector::parse("tr").unwrap()) {
-                    if let Some(header) = get_td_content(row, 0) {
-                        match header.as_str() {
-                            "Waktu Pengumpulan" => {
-                                answer.date_submitted =
-                                    get_td_content(row, 1).and_then(|s| parse_dt(&s));
-                            }
-                            "Nilai" => {
-                                answer.score = get_td_content(row, 1)
-                                    .unwrap_or_default()
-                                    .parse()
-                                    .unwrap_or(0.0);
-                                answer.is_graded = true;
-                                task.status = TaskStatus::Graded;
-                            }
-                            "Catatan" => {
-                                answer.lecturer_notes = get_td_content(row, 1).unwrap_or_default()
-                            }
-                            _ => {}
+        {
+            task.status = TaskStatus::Submitted;
+            let mut answer = Answer {
+                id: None,
+                content: String::new(),
+                file_href: None,
+                is_graded: false,
+                lecturer_notes: String::new(),
+                score: 0.0,
+                date_submitted: None,
+                rubric: Vec::new(),
+            };
+
+            let rubric_table = parse_rubric(sibling).map(|(table, items)| {
+                answer.rubric = items;
+                table.id()
+            });
+
+            for row in sibling.select(&Selector::parse("tr").unwrap()) {
+                let in_rubric = row.ancestors().any(|node| Some(node.id()) == rubric_table);
+                if in_rubric {
+                    continue;
+                }
+                if let Some(header) = get_td_content(row, 0) {
+                    match header.as_str() {
+                        "Waktu Pengumpulan" => {
+                            answer.date_submitted =
+                                get_td_content(row, 1).and_then(|s| parse_id_datetime(&s));
+                        }
+                        "Nilai" => {
+                            answer.score = get_td_content(row, 1)
+                                .and_then(|s| parse_score(&s))
+                                .unwrap_or(0.0);
+                            answer.is_graded = true;
+                            task.status = TaskStatus::Graded;
+                        }
+                        "Catatan" => {
+                            answer.lecturer_notes = get_td_content(row, 1).unwrap_or_default()
                         }
+                        _ => {}
                     }
                 }
+            }
 
-                let panel_body_selector = Selector::parse(".panel-body").unwrap();
-                if let Some(body) = sibling.select(&panel_body_selector).next() {
-                    answer.content = body
-                        .children()
-                        .filter_map(|node| node.value().as_text())
-                        .map(|text| text.trim())
-                        .collect::<Vec<_>>()
-                        .join(" ");
-
-                    answer.file_href = body
-                        .select(&Selector::parse("a[href*='/tugas/mhs']").unwrap())
-                        .next()
-                        .and_then(|a| a.value().attr("href"))
-                        .map(|href| href.replace("/tugas/mhs", "/tugas"));
-
-                    if let Some(delete_link) = body
-                        .select(&Selector::parse("a[href*='tugas_del']").unwrap())
-                        .next()
-                    {
-                        answer.id = delete_link
-                            .value()
-                            .attr("href")
-                            .and_then(|h| h.split('/').last())
-                            .and_then(|v| v.parse::<u64>().ok());
-                    }
+            let panel_body_selector = Selector::parse(".panel-body").unwrap();
+            if let Some(body) = sibling.select(&panel_body_selector).next() {
+                answer.content = submission_html(body);
+
+                answer.file_href = body
+                    .select(&Selector::parse("a[href*='/tugas/mhs']").unwrap())
+                    .next()
+                    .and_then(|a| a.value().attr("href"))
+                    .map(|file_href| file_href.replace("/tugas/mhs", "/tugas"))
+                    .and_then(|file_href| super::normalize_href_on(&href, &file_href));
+
+                if let Some(delete_link) = body
+                    .select(&Selector::parse("a[href*='tugas_del']").unwrap())
+                    .next()
+                {
+                    answer.id = delete_link
+                        .value()
+                        .attr("href")
+                        .and_then(|h| h.split('/').next_back())
+                        .and_then(|v| v.parse::<u64>().ok());
                 }
-                task.answer = Some(answer);
             }
+            task.answer = Some(answer);
         }
 
         if let Some(modal) = modals.get(i) {
@@ -201,6 +490,21 @@ pub fn parse_topic_detail_from_html(
                 .and_then(|inp| inp.value().attr("value"))
                 .unwrap_or_default()
                 .to_string();
+
+            // Batas panjang jawaban esai (jika ada) diambil dari atribut textarea
+            if let Some(textarea) = modal
+                .select(&Selector::parse("textarea[name='isi']").unwrap())
+                .next()
+            {
+                task.min_chars = textarea
+                    .value()
+                    .attr("minlength")
+                    .and_then(|v| v.trim().parse().ok());
+                task.max_chars = textarea
+                    .value()
+                    .attr("maxlength")
+                    .and_then(|v| v.trim().parse().ok());
+            }
         }
         tasks.push(task);
     }
@@ -209,7 +513,8 @@ pub fn parse_topic_detail_from_html(
         id: topic_id,
         access_time,
         is_accessible: true,
-        href: format!("/mhs/topik/{}/{}", course_id, topic_id),
+        quizzes: super::quiz::extract_quizzes(&document, &href),
+        href,
         description,
         contents,
         tasks,
@@ -5,15 +5,16 @@ use scraper::{Html, Selector};
 /// Parses the HTML of the main student dashboard page to extract user info.
 pub fn parse_user_from_html(html: &str) -> Result<User> {
     let document = Html::parse_document(html);
-    let selector = Selector::parse(".user-profile .profile-text").unwrap();
+    const PROFILE_SELECTOR: &str = ".user-profile .profile-text";
+    let selector = Selector::parse(PROFILE_SELECTOR).unwrap();
 
     let profile_element = document
         .select(&selector)
         .next()
-        .ok_or_else(|| ScraperError::ElementNotFound("User profile text element".to_string()))?;
+        .ok_or_else(|| super::selector_missing(&document, PROFILE_SELECTOR, "dashboard"))?;
 
     let profile_text = profile_element.text().collect::<String>();
-    let parts: Vec<&str> = profile_text.trim().split_whitespace().collect();
+    let parts: Vec<&str> = profile_text.split_whitespace().collect();
 
     let nim = match parts.last() {
         Some(n) => n.to_string(),
@@ -0,0 +1,24 @@
+//! The types most programs need, for a single glob import.
+//!
+#![cfg_attr(feature = "client", doc = "```")]
+#![cfg_attr(not(feature = "client"), doc = "```ignore")]
+//! use spotifier_core::prelude::*;
+//!
+//! let client = SpotifierCoreClient::builder()
+//!     .delay(DelayConfig::default())
+//!     .build()?;
+//! let period = Period {
+//!     year: 2025,
+//!     semester: Semester::Odd,
+//! };
+//! # let _ = (client, period);
+//! # Ok::<(), ScraperError>(())
+//! ```
+//!
+//! The prelude covers the client, its error type and the core models. Everything else
+//! stays available from the crate root.
+
+#[cfg(feature = "client")]
+pub use crate::client::SpotifierCoreClient;
+pub use crate::error::{Result, ScraperError};
+pub use crate::models::{Course, DelayConfig, DetailCourse, Period, Semester, Task, TopicDetail};
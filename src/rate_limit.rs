@@ -0,0 +1,79 @@
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::{Instant, sleep_until};
+
+/// A global request throttle that can be shared between clients and tasks.
+///
+/// Unlike `DelayConfig`, which only spaces out requests made one after another by a
+/// single caller, a `RateLimiter` guarantees that request starts across *every* holder
+/// of the same `Arc<RateLimiter>` stay under its ceiling.
+///
+/// It is a token bucket: `burst` requests may start back to back, after which one more
+/// is allowed every `min_interval`. The default burst of 1 spaces every request start
+/// at least `min_interval` apart.
+#[derive(Debug)]
+pub struct RateLimiter {
+    min_interval: Duration,
+    burst: u32,
+    /// The time at which the bucket is full again (GCRA's "theoretical arrival time").
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing at most one request every `min_interval`.
+    pub fn new(min_interval: Duration) -> Self {
+        Self {
+            min_interval,
+            burst: 1,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Creates a limiter allowing at most `requests_per_second` requests per second.
+    ///
+    /// # Panics
+    /// Panics if `requests_per_second` is not a positive, finite number.
+    pub fn per_second(requests_per_second: f64) -> Self {
+        assert!(
+            requests_per_second.is_finite() && requests_per_second > 0.0,
+            "requests_per_second must be positive, got {}",
+            requests_per_second
+        );
+        Self::new(Duration::from_secs_f64(1.0 / requests_per_second))
+    }
+
+    /// Lets up to `burst` requests start at once when the limiter has been idle.
+    ///
+    /// The long-run rate is unchanged. A `burst` of 0 is treated as 1.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// Returns the minimum spacing enforced between requests once the burst is spent.
+    pub fn min_interval(&self) -> Duration {
+        self.min_interval
+    }
+
+    /// Returns how many requests may start back to back.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// Waits until the caller is allowed to start its next request.
+    pub async fn acquire(&self) {
+        let slot = {
+            let mut next_slot = self.next_slot.lock().await;
+            let now = Instant::now();
+            let full_at = next_slot.map_or(now, |next| next.max(now));
+            let tolerance = self.min_interval * (self.burst - 1);
+            let slot = full_at
+                .checked_sub(tolerance)
+                .map_or(now, |slot| slot.max(now));
+            *next_slot = Some(full_at + self.min_interval);
+            slot
+        };
+
+        sleep_until(slot).await;
+    }
+}
@@ -0,0 +1,143 @@
+use crate::error::{Result, ScraperError};
+use crate::hash::fnv1a_64;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Form fields whose values are never written to disk.
+const REDACTED_FIELDS: &[&str] = &["password"];
+
+/// A single HTTP exchange captured while recording mode is enabled.
+///
+/// Recordings are stored as `<key>.json` in the recording directory, where the key is
+/// derived from the method, URL and (redacted) request body via [`Recording::key`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recording {
+    /// The HTTP method, e.g. `GET`.
+    pub method: String,
+    /// The requested URL, before redirects.
+    pub url: String,
+    /// The request body, if any: URL-encoded forms as a JSON object with passwords
+    /// redacted, multipart uploads as a JSON list of `[name, value]` pairs in which
+    /// files appear as `<file NAME, N bytes>`.
+    pub request_body: Option<String>,
+    /// The status code of the final response.
+    pub status: u16,
+    /// The URL after following redirects.
+    pub final_url: String,
+    /// The final response body, decoded lossily as UTF-8.
+    pub response_body: String,
+}
+
+impl Recording {
+    /// Returns the file stem under which a request is recorded.
+    ///
+    /// This is a 64-bit FNV-1a hash, so keys stay stable across Rust versions and
+    /// recordings can be committed as fixtures.
+    pub fn key(method: &str, url: &str, request_body: Option<&str>) -> String {
+        let input = format!("{} {}\n{}", method, url, request_body.unwrap_or_default());
+        format!("{:016x}", fnv1a_64(input.as_bytes()))
+    }
+
+    /// Returns the path of the recording for a request inside `dir`.
+    pub fn path(dir: &Path, method: &str, url: &str, request_body: Option<&str>) -> PathBuf {
+        dir.join(format!("{}.json", Self::key(method, url, request_body)))
+    }
+
+    /// Loads the recording for a request from `dir`, if one exists.
+    pub async fn load(
+        dir: &Path,
+        method: &str,
+        url: &str,
+        request_body: Option<&str>,
+    ) -> Result<Option<Recording>> {
+        let path = Self::path(dir, method, url, request_body);
+        let json = match tokio::fs::read_to_string(&path).await {
+            Ok(json) => json,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(ScraperError::ParsingError(format!(
+                    "Failed to read recording: {}",
+                    e
+                )));
+            }
+        };
+
+        serde_json::from_str(&json).map(Some).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to deserialize recording: {}", e))
+        })
+    }
+
+    /// Writes the recording into `dir`, creating the directory if needed.
+    pub async fn save(&self, dir: &Path) -> Result<()> {
+        tokio::fs::create_dir_all(dir).await.map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to create recording directory: {}", e))
+        })?;
+
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            ScraperError::ParsingError(format!("Failed to serialize recording: {}", e))
+        })?;
+
+        let path = Self::path(dir, &self.method, &self.url, self.request_body.as_deref());
+        tokio::fs::write(path, json)
+            .await
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to write recording: {}", e)))
+    }
+}
+
+/// Serializes a form for recording, masking credentials such as the SSO password.
+pub(crate) fn redacted_form<T: Serialize + ?Sized>(form: &T) -> Option<String> {
+    let mut value = serde_json::to_value(form).ok()?;
+    if let Some(fields) = value.as_object_mut() {
+        for name in REDACTED_FIELDS {
+            if let Some(field) = fields.get_mut(*name) {
+                *field = serde_json::Value::String("<redacted>".to_string());
+            }
+        }
+    }
+    serde_json::to_string(&value).ok()
+}
+
+/// A request that dry-run mode logged instead of sending; see
+/// [`SpotifierCoreClient::set_dry_run`](crate::SpotifierCoreClient::set_dry_run).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DryRunRequest {
+    pub method: String,
+    pub url: String,
+    /// The URL-encoded form fields, with passwords redacted. Empty for requests without
+    /// a form body and for multipart uploads.
+    pub form: Vec<(String, String)>,
+}
+
+/// Decodes a URL-encoded form body, masking the values of [`REDACTED_FIELDS`].
+pub(crate) fn redacted_form_pairs(body: &[u8]) -> Vec<(String, String)> {
+    url::form_urlencoded::parse(body)
+        .map(|(name, value)| {
+            let value = if REDACTED_FIELDS.contains(&name.as_ref()) {
+                "<redacted>".to_string()
+            } else {
+                value.into_owned()
+            };
+            (name.into_owned(), value)
+        })
+        .collect()
+}
+
+/// Masks every query value of a URL, since some (e.g. the CAS `ticket`) are credentials.
+pub(crate) fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = reqwest::Url::parse(url) else {
+        return url.split('?').next().unwrap_or_default().to_string();
+    };
+    if parsed.query().is_none() {
+        return parsed.into();
+    }
+
+    let keys: Vec<String> = parsed
+        .query_pairs()
+        .map(|(key, _)| key.into_owned())
+        .collect();
+    parsed
+        .query_pairs_mut()
+        .clear()
+        .extend_pairs(keys.iter().map(|key| (key, "redacted")));
+    parsed.into()
+}
@@ -0,0 +1,82 @@
+use crate::error::{Result, ScraperError};
+use crate::models::RetryConfig;
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::time::Duration;
+use tokio::time::sleep;
+
+/// Parses a `Retry-After` header value, given in delay-seconds or as an HTTP-date.
+///
+/// A date is converted to the time remaining after `now`; dates in the past yield
+/// `Duration::ZERO`. Returns `None` if the value is in neither form.
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(secs) = value.parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - now)
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
+
+/// How long to wait before retrying after `err`: the server's `Retry-After` for
+/// `ScraperError::RateLimited`, otherwise `backoff`.
+///
+/// Returns `None` if `Retry-After` asks for longer than `config.max_backoff_ms`, in
+/// which case the caller gives up and returns `err` rather than sleeping that long.
+pub(crate) fn delay_before_retry(
+    err: &ScraperError,
+    backoff: Duration,
+    config: &RetryConfig,
+) -> Option<Duration> {
+    match err {
+        ScraperError::RateLimited {
+            retry_after: Some(retry_after),
+        } => (*retry_after <= Duration::from_millis(config.max_backoff_ms)).then_some(*retry_after),
+        _ => Some(backoff),
+    }
+}
+
+/// Runs an async operation, retrying it with exponential backoff on transient errors.
+///
+/// The operation is attempted once and then retried up to `config.max_retries` times
+/// as long as the error is [`is_retryable`](crate::ScraperError::is_retryable).
+/// Non-retryable errors are returned immediately. After `ScraperError::RateLimited`,
+/// the server's `Retry-After` delay is waited instead of the backoff, when known; if it
+/// exceeds `config.max_backoff_ms`, the `RateLimited` error is returned right away.
+///
+/// This works with any fallible operation, including composite ones:
+///
+/// ```no_run
+/// # use spotifier_core::{with_retry, RetryConfig, SpotifierCoreClient};
+/// # async fn run(client: &SpotifierCoreClient) -> spotifier_core::Result<()> {
+/// let detail = with_retry(&RetryConfig::default(), || async {
+///     let courses = client.get_courses().await?;
+///     client.get_course_detail(&courses[0]).await
+/// })
+/// .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn with_retry<T, F, Fut>(config: &RetryConfig, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut retry = 0;
+    loop {
+        match operation().await {
+            Err(err) if err.is_retryable() && retry < config.max_retries => {
+                match delay_before_retry(&err, config.backoff(retry), config) {
+                    Some(delay) => sleep(delay).await,
+                    None => return Err(err),
+                }
+                retry += 1;
+            }
+            result => return result,
+        }
+    }
+}
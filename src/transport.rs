@@ -0,0 +1,136 @@
+use crate::error::{Result, ScraperError};
+use crate::recording::Recording;
+use async_trait::async_trait;
+use reqwest::ResponseBuilderExt;
+use std::collections::{HashMap, VecDeque};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Instant;
+
+/// The layer that actually sends the client's HTTP requests.
+///
+/// `SpotifierCoreClient` builds every request itself (User-Agent, Referer, form or
+/// multipart body) and hands it to the transport, which returns the response after
+/// following redirects. The default is [`ReqwestTransport`]; tests can swap in
+/// [`ReplayTransport`] or their own implementation with
+/// `SpotifierCoreClient::with_transport`.
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    /// Sends `request` and returns the final response.
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response>;
+}
+
+/// The default transport, backed by a `reqwest::Client` and its cookie store.
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    /// Wraps a `reqwest::Client`.
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let url = request.url().to_string();
+        let started = Instant::now();
+        self.client
+            .execute(request)
+            .await
+            .map_err(|e| ScraperError::from_request(e, &url, started))
+    }
+}
+
+/// A transport that answers requests from [`Recording`]s instead of the network.
+///
+/// Requests are matched on method and URL only, since recorded form bodies are redacted.
+/// If the same request was recorded more than once, the recordings are served in order
+/// (by file name, or insertion order) and the last one keeps being served after that.
+/// Requests without a recording fail with `ScraperError::ParsingError`.
+#[derive(Debug, Default)]
+pub struct ReplayTransport {
+    recordings: Mutex<HashMap<(String, String), VecDeque<Recording>>>,
+}
+
+impl ReplayTransport {
+    /// Loads every `*.json` recording in `dir`, e.g. one written by recording mode.
+    pub fn from_dir(dir: &Path) -> Result<Self> {
+        let read_err = |e: std::io::Error| {
+            ScraperError::ParsingError(format!("Failed to read recordings: {}", e))
+        };
+
+        let mut paths: Vec<_> = std::fs::read_dir(dir)
+            .map_err(read_err)?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut transport = Self::default();
+        for path in paths {
+            let json = std::fs::read_to_string(&path).map_err(read_err)?;
+            let recording: Recording = serde_json::from_str(&json).map_err(|e| {
+                ScraperError::ParsingError(format!(
+                    "Failed to deserialize recording {}: {}",
+                    path.display(),
+                    e
+                ))
+            })?;
+            transport.insert(recording);
+        }
+        Ok(transport)
+    }
+
+    /// Adds a recording, served after any earlier one for the same method and URL.
+    pub fn insert(&mut self, recording: Recording) {
+        let key = (
+            recording.method.to_uppercase(),
+            normalize_url(&recording.url),
+        );
+        self.recordings
+            .get_mut()
+            .unwrap()
+            .entry(key)
+            .or_default()
+            .push_back(recording);
+    }
+}
+
+/// Normalizes a URL the way `reqwest::Url` serializes it, so recorded and live URLs match.
+fn normalize_url(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .map(String::from)
+        .unwrap_or_else(|_| url.to_string())
+}
+
+#[async_trait]
+impl HttpTransport for ReplayTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let key = (request.method().to_string(), request.url().to_string());
+        let recording = {
+            let mut recordings = self.recordings.lock().unwrap();
+            match recordings.get_mut(&key) {
+                Some(queue) if queue.len() > 1 => queue.pop_front(),
+                Some(queue) => queue.front().cloned(),
+                None => None,
+            }
+        }
+        .ok_or_else(|| {
+            ScraperError::ParsingError(format!("No recording for {} {}", key.0, key.1))
+        })?;
+
+        let final_url = reqwest::Url::parse(&recording.final_url).map_err(|e| {
+            ScraperError::ParsingError(format!("Invalid recorded final URL: {}", e))
+        })?;
+        http::Response::builder()
+            .status(recording.status)
+            .url(final_url)
+            .body(recording.response_body.clone())
+            .map(reqwest::Response::from)
+            .map_err(|e| ScraperError::ParsingError(format!("Failed to rebuild response: {}", e)))
+    }
+}
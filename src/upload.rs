@@ -0,0 +1,224 @@
+use crate::error::{Result, ScraperError};
+
+/// File types SPOT assignments commonly accept, for use with
+/// `SpotifierCoreClient::set_allowed_upload_types`.
+pub const DEFAULT_UPLOAD_TYPES: &[&str] = &["pdf", "docx", "zip", "png", "jpg"];
+
+/// Makes a file name safe to use as an upload name or a path component.
+///
+/// Directory components are dropped, line breaks and tabs become spaces and other
+/// control characters become `_`. Everything else, non-ASCII letters and dashes
+/// included, is kept: [`MultipartForm`] encodes the name for the header.
+///
+/// Example: `"C:\\Users\\budi\\Tugas Besar – Budi.pdf"` becomes `"Tugas Besar – Budi.pdf"`.
+pub(crate) fn sanitize_file_name(name: &str) -> String {
+    let base_name = name.rsplit(['/', '\\']).next().unwrap_or(name);
+
+    let sanitized: String = base_name
+        .chars()
+        .map(|c| match c {
+            c if c.is_whitespace() => ' ',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let sanitized = sanitized.trim();
+    if sanitized.is_empty() || sanitized.chars().all(|c| c == '.') {
+        "file".to_string()
+    } else {
+        sanitized.to_string()
+    }
+}
+
+/// A `multipart/form-data` body, encoded by hand so file parts can carry an RFC 5987
+/// `filename*` parameter next to `filename` (reqwest only writes the latter).
+pub(crate) struct MultipartForm {
+    boundary: String,
+    body: Vec<u8>,
+    /// `(name, value)` per part, with files as `<file NAME, N bytes>`, for recordings.
+    parts: Vec<(String, String)>,
+}
+
+impl MultipartForm {
+    pub(crate) fn new() -> Self {
+        Self {
+            boundary: format!("spotifier-{:016x}", rand::random::<u64>()),
+            body: Vec::new(),
+            parts: Vec::new(),
+        }
+    }
+
+    /// Adds a text field.
+    pub(crate) fn text(mut self, name: &str, value: &str) -> Self {
+        self.start_part(&format!("form-data; name=\"{}\"", name));
+        self.body.extend_from_slice(b"\r\n");
+        self.body.extend_from_slice(value.as_bytes());
+        self.body.extend_from_slice(b"\r\n");
+        self.parts.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a file part; `file_name` should already be sanitized.
+    ///
+    /// The plain `filename` carries the name as UTF-8 the way browsers send it, with `"`
+    /// escaped as `%22`. Names with non-ASCII characters also get `filename*=UTF-8''...`.
+    pub(crate) fn file(
+        mut self,
+        name: &str,
+        file_name: &str,
+        data: &[u8],
+        mime: Option<&str>,
+    ) -> Self {
+        let mut disposition = format!(
+            "form-data; name=\"{}\"; filename=\"{}\"",
+            name,
+            file_name.replace('"', "%22")
+        );
+        if !file_name.is_ascii() {
+            disposition.push_str("; filename*=UTF-8''");
+            disposition.push_str(&rfc5987_encode(file_name));
+        }
+        self.start_part(&disposition);
+        self.body.extend_from_slice(
+            format!(
+                "Content-Type: {}\r\n\r\n",
+                mime.unwrap_or("application/octet-stream")
+            )
+            .as_bytes(),
+        );
+        self.body.extend_from_slice(data);
+        self.body.extend_from_slice(b"\r\n");
+        self.parts.push((
+            name.to_string(),
+            format!("<file {}, {} bytes>", file_name, data.len()),
+        ));
+        self
+    }
+
+    fn start_part(&mut self, disposition: &str) {
+        self.body.extend_from_slice(
+            format!(
+                "--{}\r\nContent-Disposition: {}\r\n",
+                self.boundary, disposition
+            )
+            .as_bytes(),
+        );
+    }
+
+    /// The `Content-Type` header value, including the boundary.
+    pub(crate) fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// Describes the parts as a JSON list of `[name, value]` pairs, files replaced by
+    /// their name and size, so recordings stay readable and free of file contents.
+    pub(crate) fn summary(&self) -> Option<String> {
+        serde_json::to_string(&self.parts).ok()
+    }
+
+    /// Returns the encoded body, closing boundary included.
+    pub(crate) fn into_body(mut self) -> Vec<u8> {
+        self.body
+            .extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        self.body
+    }
+}
+
+/// Percent-encodes everything but RFC 5987 `attr-char`s.
+fn rfc5987_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => (b as char).to_string(),
+            b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+/// Extracts the file name from a `Content-Disposition` header value.
+///
+/// Prefers the RFC 5987 `filename*=UTF-8''...` form over plain `filename="..."`.
+/// The result is not sanitized; `download_file_to` strips directory components before
+/// using it as a path.
+pub(crate) fn content_disposition_file_name(header: &str) -> Option<String> {
+    let mut plain = None;
+    for param in header.split(';').map(str::trim) {
+        let Some((name, value)) = param.split_once('=') else {
+            continue;
+        };
+        match name.trim().to_ascii_lowercase().as_str() {
+            "filename*" => {
+                let encoded = value.trim().splitn(3, '\'').nth(2)?;
+                if let Some(decoded) = percent_decode(encoded) {
+                    return Some(decoded);
+                }
+            }
+            "filename" => {
+                let value = value.trim().trim_matches('"');
+                if !value.is_empty() {
+                    plain = Some(value.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    plain
+}
+
+/// Decodes `%XX` escapes as UTF-8, returning `None` on malformed input.
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(byte) = iter.next() {
+        if byte == b'%' {
+            let hex = [iter.next()?, iter.next()?];
+            bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Detects the MIME type of a file from its leading bytes ("magic numbers").
+///
+/// The file name is deliberately ignored, so a PNG named `tugas.pdf` is reported as
+/// `image/png`. Returns `None` for formats without a signature, such as plain text.
+pub fn detect_mime_type(data: &[u8]) -> Option<&'static str> {
+    infer::get(data).map(|kind| kind.mime_type())
+}
+
+/// Sniffs the type of an upload and checks it against `allowed` (lowercase extensions).
+///
+/// Files without a recognizable signature are judged by their name's extension instead.
+/// Returns the detected MIME type, or `ScraperError::UnsupportedFileType` if the type
+/// is not allowed.
+pub(crate) fn check_upload_type(
+    file_name: &str,
+    data: &[u8],
+    allowed: Option<&[String]>,
+) -> Result<Option<&'static str>> {
+    let kind = infer::get(data);
+    let Some(allowed) = allowed else {
+        return Ok(kind.map(|kind| kind.mime_type()));
+    };
+
+    let extension = match kind {
+        Some(kind) => Some(kind.extension().to_string()),
+        None => file_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase()),
+    };
+    if extension.is_some_and(|ext| allowed.contains(&ext)) {
+        return Ok(kind.map(|kind| kind.mime_type()));
+    }
+
+    Err(ScraperError::UnsupportedFileType {
+        file_name: file_name.to_string(),
+        detected: kind.map(|kind| kind.mime_type().to_string()),
+    })
+}
@@ -1,8 +1,7 @@
 // tests/all_topics_test.rs
 
 use dotenvy::from_path;
-use spotifier_core::{Result, SpotifierCoreClient};
-use std::env;
+use spotifier_core::{Credentials, Result, SpotifierCoreClient};
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -30,9 +29,7 @@ async fn test_scrape_all_topics() -> Result<()> {
         File::create("output/all_topics_output.log").expect("Tidak bisa membuat file log.");
 
     // --- SETUP: Ambil kredensial ---
-    let nim = env::var("SPOT_NIM").expect("ERROR: Environment variable SPOT_NIM tidak di-set.");
-    let password =
-        env::var("SPOT_PASSWORD").expect("ERROR: Environment variable SPOT_PASSWORD tidak di-set.");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     writeln!(log_file, "--- Memulai Full Topic Scraping Test ---").unwrap();
     println!("--- Memulai Full Topic Scraping Test ---");
@@ -42,7 +39,7 @@ async fn test_scrape_all_topics() -> Result<()> {
     // --- Langkah 1: Login ---
     writeln!(log_file, "\n[1/4] Mencoba login...").unwrap();
     println!("[1/4] Mencoba login...");
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
     writeln!(log_file, "[1/4] Login berhasil!").unwrap();
 
     // --- Langkah 2: Ambil Daftar Mata Kuliah ---
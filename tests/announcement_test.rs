@@ -0,0 +1,104 @@
+// tests/announcement_test.rs
+
+use chrono::{NaiveDate, NaiveDateTime};
+use spotifier_core::parsers::announcement::parse_announcements_from_html;
+use spotifier_core::{Result, ScraperError};
+
+const ANNOUNCEMENTS_HTML: &str = r#"
+<html><body>
+  <div id="pengumuman">
+    <div class="panel panel-default" data-id="301">
+      <div class="panel-heading"><h4 class="panel-title">Perubahan jadwal UTS</h4></div>
+      <div class="panel-body"><p>UTS dipindah ke <b>Kamis</b>.</p></div>
+      <div class="panel-footer">
+        <span class="author">Dr. Budi Santoso</span>
+        <span class="time">01-10-2025 09:15</span>
+      </div>
+    </div>
+    <div class="panel panel-default" data-id="305">
+      <div class="panel-heading"><h4 class="panel-title">Tugas 3 sudah dibuka</h4></div>
+      <div class="panel-body"><p>Silakan cek topik 5.</p></div>
+      <div class="panel-footer">
+        <span class="author">Dr. Budi Santoso</span>
+        <span class="time">2 hari yang lalu</span>
+      </div>
+    </div>
+    <div class="panel panel-default" data-id="302">
+      <div class="panel-heading"><h4 class="panel-title">Kontrak kuliah</h4></div>
+      <div class="panel-body"><p>Lihat lampiran.</p></div>
+      <div class="panel-footer">
+        <span class="author">Admin Prodi</span>
+        <span class="time">sebentar lagi</span>
+      </div>
+    </div>
+    <div class="panel panel-default" data-id="306">
+      <div class="panel-heading"><h4 class="panel-title">Kelas daring</h4></div>
+      <div class="panel-body"><p>Pertemuan hari ini daring.</p></div>
+      <div class="panel-footer">
+        <span class="author">Dr. Budi Santoso</span>
+        <span class="time">3 jam yang lalu</span>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 10, day)
+        .and_then(|d| d.and_hms_opt(hour, minute, 0))
+        .unwrap()
+}
+
+#[test]
+fn test_parse_announcements_newest_first() -> Result<()> {
+    let now = at(10, 12, 0);
+    let announcements = parse_announcements_from_html(ANNOUNCEMENTS_HTML, now)?;
+
+    let ids: Vec<u64> = announcements.iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![306, 305, 301, 302]);
+
+    let relative_hours = &announcements[0];
+    assert_eq!(relative_hours.posted_at, Some(at(10, 9, 0)));
+
+    let relative_days = &announcements[1];
+    assert_eq!(relative_days.title, "Tugas 3 sudah dibuka");
+    assert_eq!(relative_days.posted_at, Some(at(8, 12, 0)));
+
+    let absolute = &announcements[2];
+    assert_eq!(absolute.title, "Perubahan jadwal UTS");
+    assert_eq!(absolute.author, "Dr. Budi Santoso");
+    assert_eq!(absolute.body_html, "<p>UTS dipindah ke <b>Kamis</b>.</p>");
+    assert_eq!(absolute.posted_at, Some(at(1, 9, 15)));
+
+    let unparseable = &announcements[3];
+    assert_eq!(unparseable.author, "Admin Prodi");
+    assert_eq!(unparseable.posted_at, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_announcements_empty_board() -> Result<()> {
+    let html =
+        r#"<html><body><div id="pengumuman"><p>Belum ada pengumuman</p></div></body></html>"#;
+    assert!(parse_announcements_from_html(html, at(10, 12, 0))?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_parse_announcements_without_board_is_an_error() {
+    let html = r#"<html><body><div class="login-box">Sesi habis</div></body></html>"#;
+    match parse_announcements_from_html(html, at(10, 12, 0)) {
+        Err(ScraperError::SelectorMissing { selector, .. }) => assert_eq!(selector, "#pengumuman"),
+        other => panic!("expected SelectorMissing, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_parse_announcements_huge_relative_time_is_unparsed() -> Result<()> {
+    let html = ANNOUNCEMENTS_HTML.replace("2 hari yang lalu", "300000000 tahun yang lalu");
+    let announcements = parse_announcements_from_html(&html, at(10, 12, 0))?;
+    let overflowing = announcements.iter().find(|a| a.id == 305).unwrap();
+    assert_eq!(overflowing.posted_at, None);
+    Ok(())
+}
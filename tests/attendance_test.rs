@@ -0,0 +1,109 @@
+// tests/attendance_test.rs
+
+use chrono::NaiveDate;
+use spotifier_core::parsers::attendance::parse_attendance_from_html;
+use spotifier_core::{AttendanceStatus, AttendanceSummary, Result, ScraperError};
+
+const ATTENDANCE_HTML: &str = r#"
+<html><body>
+  <div class="panel panel-default">
+    <div class="panel-heading">Presensi Pemrograman Web</div>
+    <table class="table table-bordered">
+      <thead>
+        <tr><th>Pertemuan</th><th>Tanggal</th><th>Materi</th><th>Status</th><th>Keterangan</th></tr>
+      </thead>
+      <tbody>
+        <tr><td>1</td><td>01-09-2025</td><td>Kontrak kuliah</td><td>Hadir</td><td></td></tr>
+        <tr><td>2</td><td>Senin, 8 September 2025</td><td>HTML</td><td>Terlambat</td><td>15 menit</td></tr>
+        <tr><td>3</td><td>15 Sep 2025</td><td>CSS</td><td>Izin</td><td>Lomba</td></tr>
+        <tr><td>4</td><td>22/09/2025</td><td>JavaScript</td><td>SAKIT</td><td>Surat dokter</td></tr>
+        <tr><td>5</td><td>29-09-2025</td><td>DOM</td><td>Alpa</td><td></td></tr>
+        <tr><td>6</td><td>06-10-2025</td><td>Fetch API</td><td>Hadir</td><td></td></tr>
+        <tr><td>7</td><td>13-10-2025</td><td>UTS</td><td>-</td><td></td></tr>
+      </tbody>
+    </table>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_parse_mixed_attendance() -> Result<()> {
+    let records = parse_attendance_from_html(ATTENDANCE_HTML)?;
+
+    let statuses: Vec<AttendanceStatus> = records.iter().map(|r| r.status).collect();
+    assert_eq!(
+        statuses,
+        [
+            AttendanceStatus::Present,
+            AttendanceStatus::Late,
+            AttendanceStatus::Excused,
+            AttendanceStatus::Sick,
+            AttendanceStatus::Absent,
+            AttendanceStatus::Present,
+        ],
+        "The meeting that has not taken place yet is skipped"
+    );
+
+    let date = |d, m| NaiveDate::from_ymd_opt(2025, m, d);
+    let dates: Vec<Option<NaiveDate>> = records.iter().map(|r| r.date).collect();
+    assert_eq!(
+        dates,
+        [
+            date(1, 9),
+            date(8, 9),
+            date(15, 9),
+            date(22, 9),
+            date(29, 9),
+            date(6, 10)
+        ]
+    );
+    assert_eq!(records[4].meeting_number, 5);
+
+    Ok(())
+}
+
+#[test]
+fn test_attendance_summary() -> Result<()> {
+    let records = parse_attendance_from_html(ATTENDANCE_HTML)?;
+    let summary = AttendanceSummary::from_records(&records);
+
+    assert_eq!(summary.total, 6);
+    assert_eq!(
+        (
+            summary.present,
+            summary.late,
+            summary.excused,
+            summary.sick,
+            summary.absent
+        ),
+        (2, 1, 1, 1, 1)
+    );
+    assert_eq!(summary.present_percentage(), Some(50.0));
+    assert_eq!(
+        AttendanceSummary::from_records(&[]).present_percentage(),
+        None
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_attendance_labels() {
+    assert_eq!(
+        AttendanceStatus::from_label(" hadir "),
+        Some(AttendanceStatus::Present)
+    );
+    assert_eq!(
+        AttendanceStatus::from_label("A"),
+        Some(AttendanceStatus::Absent)
+    );
+    assert_eq!(AttendanceStatus::from_label("Libur"), None);
+    assert!(AttendanceStatus::Late.is_attended());
+    assert!(!AttendanceStatus::Excused.is_attended());
+}
+
+#[test]
+fn test_missing_attendance_table() {
+    let result = parse_attendance_from_html("<html><body><p>Belum ada presensi</p></body></html>");
+    assert!(matches!(result, Err(ScraperError::SelectorMissing { .. })));
+}
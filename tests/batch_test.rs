@@ -0,0 +1,224 @@
+// tests/batch_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use common::{MockResponse, MockTransport};
+use futures::StreamExt;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{
+    Accessibility, CacheBackend, HttpTransport, MemoryCache, Result, TopicDetail, TopicInfo,
+};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn topic_info(topic_id: u64, href: bool) -> TopicInfo {
+    TopicInfo {
+        id: Some(topic_id),
+        course_id: Some(2510009532),
+        access_time: None,
+        updated_at: None,
+        is_accessible: href,
+        accessibility: if href {
+            Accessibility::Open
+        } else {
+            Accessibility::Closed
+        },
+        href: href.then(|| format!("/mhs/topik/2510009532/{}", topic_id)),
+    }
+}
+
+#[tokio::test]
+async fn test_batch_keeps_input_order_and_isolates_failures() -> Result<()> {
+    // Cached topics are served without touching the network.
+    let cache = Arc::new(MemoryCache::new());
+    for topic_id in [1, 3, 4] {
+        let detail = TopicDetail {
+            id: topic_id,
+            access_time: None,
+            is_accessible: true,
+            href: format!("/mhs/topik/2510009532/{}", topic_id),
+            description: Some(format!("Topik {}", topic_id)),
+            contents: Vec::new(),
+            tasks: Vec::new(),
+            quizzes: Vec::new(),
+        };
+        cache
+            .set(
+                &format!("topic_detail:2510009532:{}", topic_id),
+                &serde_json::to_string(&detail).unwrap(),
+                3600,
+            )
+            .await
+            .unwrap();
+    }
+
+    let mut client = common::client();
+    client.set_cache(cache);
+
+    // Topic 2 is locked (no href), so only its own entry fails.
+    let topics = [
+        topic_info(4, true),
+        topic_info(2, false),
+        topic_info(1, true),
+        topic_info(3, true),
+    ];
+    let results = client.get_topic_details_batch(&topics, 2).await;
+
+    assert_eq!(results.len(), topics.len());
+    assert_eq!(results[0].as_ref().unwrap().id, 4);
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().id, 1);
+    assert_eq!(results[3].as_ref().unwrap().id, 3);
+
+    assert!(client.get_topic_details_batch(&[], 0).await.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_caps_requests_in_flight() {
+    let transport = MockTransport::new(|_| {
+        MockResponse::html(
+            r#"<html><body><div id="dashboard"><div>Topik</div></div></body></html>"#,
+        )
+        .delayed(Duration::from_millis(50))
+    });
+    let client = common::client_with(transport.clone());
+    let topics: Vec<TopicInfo> = (1..=9).map(|id| topic_info(id, true)).collect();
+
+    let results = client.get_topic_details_batch(&topics, 3).await;
+
+    assert!(results.iter().all(|result| result.is_ok()));
+    assert_eq!(transport.requests().len(), 9);
+    assert_eq!(transport.max_in_flight(), 3);
+}
+
+/// Serves an empty topic page for every request and counts them.
+#[derive(Default)]
+struct CountingTransport {
+    requests: AtomicUsize,
+}
+
+#[async_trait]
+impl HttpTransport for CountingTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(200)
+            .body(r#"<html><body><div id="dashboard"><div>Topik</div></div></body></html>"#)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+#[tokio::test]
+async fn test_stream_stops_requesting_when_dropped() -> Result<()> {
+    let transport = Arc::new(CountingTransport::default());
+    let client = common::builder().transport(transport.clone()).build()?;
+    let topics: Vec<TopicInfo> = (1..=5).map(|id| topic_info(id, true)).collect();
+
+    let mut stream = Box::pin(client.stream_topic_details(topics));
+    assert_eq!(
+        transport.requests.load(Ordering::SeqCst),
+        0,
+        "Nothing is fetched before the stream is polled"
+    );
+
+    let first = stream.next().await.expect("first item")?;
+    assert_eq!(first.id, 1);
+    drop(stream);
+    tokio::task::yield_now().await;
+
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stream_yields_every_topic_in_order() {
+    let client = common::builder()
+        .transport(Arc::new(CountingTransport::default()))
+        .build()
+        .unwrap();
+    let topics = vec![
+        topic_info(3, true),
+        topic_info(2, false),
+        topic_info(1, true),
+    ];
+
+    let results: Vec<Result<TopicDetail>> = client.stream_topic_details(topics).collect().await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().id, 3);
+    assert!(
+        results[1].is_err(),
+        "A locked topic fails only its own item"
+    );
+    assert_eq!(results[2].as_ref().unwrap().id, 1);
+}
+
+/// Serves an empty topic page and records when each request went out.
+#[derive(Default)]
+struct TimestampTransport {
+    sent_at: Mutex<Vec<Instant>>,
+}
+
+#[async_trait]
+impl HttpTransport for TimestampTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.sent_at.lock().unwrap().push(Instant::now());
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(200)
+            .body(r#"<html><body><div id="dashboard"><div>Topik</div></div></body></html>"#)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_concurrent_course_batches_share_rate_ceiling() -> Result<()> {
+    const REQUESTS_PER_SECOND: usize = 5;
+
+    let transport = Arc::new(TimestampTransport::default());
+    let client = common::builder()
+        .transport(transport.clone())
+        .requests_per_second(REQUESTS_PER_SECOND as f64)
+        .build()?;
+
+    // Three courses scraped at once, each batch with several requests in flight.
+    let courses: Vec<Vec<TopicInfo>> = (1..=3u64)
+        .map(|course_id| {
+            (1..=4)
+                .map(|topic_id| TopicInfo {
+                    course_id: Some(course_id),
+                    href: Some(format!("/mhs/topik/{}/{}", course_id, topic_id)),
+                    ..topic_info(topic_id, true)
+                })
+                .collect()
+        })
+        .collect();
+    let results = futures::future::join_all(
+        courses
+            .iter()
+            .map(|topics| client.get_topic_details_batch(topics, 4)),
+    )
+    .await;
+    assert!(results.iter().flatten().all(|result| result.is_ok()));
+
+    let mut sent_at = transport.sent_at.lock().unwrap().clone();
+    sent_at.sort();
+    assert_eq!(sent_at.len(), 12);
+    // No one-second window may hold more than the ceiling.
+    for window in sent_at.windows(REQUESTS_PER_SECOND + 1) {
+        assert!(
+            window[REQUESTS_PER_SECOND] - window[0] >= Duration::from_secs(1),
+            "More than {} requests within one second",
+            REQUESTS_PER_SECOND
+        );
+    }
+    Ok(())
+}
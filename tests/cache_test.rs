@@ -1,18 +1,25 @@
 // tests/cache_test.rs
 
+mod common;
+
+use async_trait::async_trait;
+use common::{MockResponse, MockTransport};
 use dotenvy::from_path;
-use spotifier_core::{DelayConfig, FileCache, Result, SpotifierCoreClient};
-use std::env;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{
+    CacheBackend, CacheTtls, Credentials, FileCache, HttpTransport, MemoryCache, Period, Result,
+    ScraperError, Semester, SpotifierCoreClient,
+};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
 
 #[tokio::test]
 async fn test_cookie_persistence() -> Result<()> {
     let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
     from_path(&env_path).ok();
 
-    let nim = env::var("SPOT_NIM").expect("SPOT_NIM env var not set");
-    let password = env::var("SPOT_PASSWORD").expect("SPOT_PASSWORD env var not set");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     let cookie_path = Path::new("test_cookies.json");
     if cookie_path.exists() {
@@ -22,7 +29,7 @@ async fn test_cookie_persistence() -> Result<()> {
     {
         println!("Stage 1: Login and save cookies...");
         let client = SpotifierCoreClient::new();
-        client.login(&nim, &password).await?;
+        client.login_with(&credentials).await?;
         client.save_cookies(cookie_path).await?;
         println!("✅ Cookies saved to {:?}", cookie_path);
     }
@@ -43,48 +50,520 @@ async fn test_cookie_persistence() -> Result<()> {
 }
 
 #[tokio::test]
-async fn test_course_list_caching() -> Result<()> {
-    let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
-    from_path(&env_path).ok();
+async fn test_session_round_trip_without_period() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("spotifier-session-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let cookies_in = dir.join("cookies_in.json");
+    let session = dir.join("session.json");
+    let cookies_out = dir.join("cookies_out.json");
+    std::fs::write(
+        &cookies_in,
+        r#"{"spot": "laravel_session=abc123", "sso": "TGC=xyz"}"#,
+    )
+    .unwrap();
 
-    let nim = env::var("SPOT_NIM").expect("SPOT_NIM env var not set");
-    let password = env::var("SPOT_PASSWORD").expect("SPOT_PASSWORD env var not set");
+    let client = SpotifierCoreClient::new();
+    client.load_cookies(&cookies_in).await?;
+    client.save_session(&session).await?;
 
-    let cache_dir = Path::new("test_cache");
-    if cache_dir.exists() {
-        std::fs::remove_dir_all(cache_dir).ok();
-    }
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&session).unwrap()).unwrap();
+    assert_eq!(saved["version"], 1);
+    assert!(saved["period"].is_null());
 
-    let mut client = SpotifierCoreClient::with_config(DelayConfig {
-        enabled: false, // Disable delays for faster test
-        ..Default::default()
-    });
+    // No period was ever selected, so restoring must not touch the network.
+    let restored = SpotifierCoreClient::new();
+    restored.load_session(&session).await?;
+    assert_eq!(restored.current_period(), None);
+    restored.save_cookies(&cookies_out).await?;
+
+    let cookies: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&cookies_out).unwrap()).unwrap();
+    assert_eq!(cookies["spot"], "laravel_session=abc123");
+    assert_eq!(cookies["sso"], "TGC=xyz");
+
+    std::fs::remove_dir_all(&dir).ok();
+    Ok(())
+}
+
+/// Answers the period switch with a redirect to `/adm` and the dashboard with a course
+/// of the 2024/2025 even semester.
+fn period_transport() -> Arc<MockTransport> {
+    MockTransport::new(|request| {
+        if request.path().starts_with("/adm/semester/") {
+            return MockResponse::html("<html></html>").at("https://spot.upi.edu/adm");
+        }
+        MockResponse::html(&format!(
+            "<html><body><table><tbody>{}</tbody></table></body></html>",
+            COURSE_ROW.replace("2025/2026 - Ganjil", "2024/2025 - Genap")
+        ))
+    })
+}
+
+#[tokio::test]
+async fn test_session_round_trip_reselects_period() -> Result<()> {
+    let path = std::env::temp_dir().join(format!(
+        "spotifier-session-period-{}.json",
+        std::process::id()
+    ));
+
+    let client = common::client_with(period_transport());
+    client.change_period(2024, Semester::Even).await?;
+    client.save_session(&path).await?;
 
-    let nim_copy = nim.clone();
-    let cache = Arc::new(FileCache::new(cache_dir));
-    client.set_cache(cache);
-    client.set_cache_prefix(&nim_copy); // Use NIM as namespace
+    let transport = period_transport();
+    let restored = common::builder()
+        .prefer_json(false)
+        .transport(transport.clone())
+        .build()?;
+    restored.load_session(&path).await?;
+    std::fs::remove_file(&path).ok();
 
-    client.login(&nim, &password).await?;
+    assert_eq!(
+        restored.current_period(),
+        Some(Period::new(2024, Semester::Even))
+    );
+    let paths: Vec<String> = transport.requests().iter().map(|r| r.path()).collect();
+    assert_eq!(paths, ["/adm/semester/20242", "/mhs"]);
+
+    // Loading it again while the period is already active sends nothing.
+    let path_again = std::env::temp_dir().join(format!(
+        "spotifier-session-period2-{}.json",
+        std::process::id()
+    ));
+    restored.save_session(&path_again).await?;
+    restored.load_session(&path_again).await?;
+    std::fs::remove_file(&path_again).ok();
+    assert_eq!(transport.requests().len(), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_session_rejects_unknown_version() {
+    let path =
+        std::env::temp_dir().join(format!("spotifier-session-v99-{}.json", std::process::id()));
+    std::fs::write(&path, r#"{"version": 99, "cookies": {}, "period": null}"#).unwrap();
+
+    let client = SpotifierCoreClient::new();
+    assert!(matches!(
+        client.load_session(&path).await,
+        Err(ScraperError::ParsingError(_))
+    ));
+
+    std::fs::remove_file(&path).ok();
+}
+
+#[tokio::test]
+async fn test_course_list_caching() -> Result<()> {
+    let cache_dir = std::env::temp_dir().join(format!("spotifier-cache-{}", std::process::id()));
+    std::fs::remove_dir_all(&cache_dir).ok();
+
+    let transport = MockTransport::new(|_| {
+        MockResponse::html(&format!(
+            "<html><body><table><tbody>{}</tbody></table></body></html>",
+            COURSE_ROW
+        ))
+    });
+    let mut client = common::client_with(transport.clone());
+    client.set_prefer_json(false);
+    client.set_cache(Arc::new(FileCache::new(&cache_dir)));
+    client.set_cache_prefix("2306012");
 
-    println!("Fetching courses with prefix {}...", nim_copy);
     let courses1 = client.get_courses().await?;
-    assert!(!courses1.is_empty());
+    assert_eq!(courses1.len(), 1);
 
-    // The file name should now contain the prefix: "{nim}:courses.json"
-    let cache_file = cache_dir.join(format!("{}:courses.json", nim_copy));
+    // No period was selected yet, so the key is only namespaced by the prefix.
+    let cache_file = cache_dir.join("2306012:courses.json");
     assert!(
         cache_file.exists(),
         "Namespaced cache file {:?} should be created",
         cache_file
     );
 
-    println!("Fetching courses for the second time (from cache)...");
-    // We can simulate offline or just check if it works
     let courses2 = client.get_courses().await?;
-    assert_eq!(courses1.len(), courses2.len());
-    println!("✅ Cache works! Fetched {} courses.", courses2.len());
+    assert_eq!(courses1, courses2);
+    assert_eq!(transport.requests().len(), 1);
+
+    std::fs::remove_dir_all(&cache_dir).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_entries_are_kept_per_period() -> Result<()> {
+    // SPOT serves the course list of whichever period was selected last.
+    let selected = Arc::new(Mutex::new("20251".to_string()));
+    let handler_selected = Arc::clone(&selected);
+    let transport = MockTransport::new(move |request| {
+        let path = request.path();
+        if let Some(period) = path.strip_prefix("/adm/semester/") {
+            *handler_selected.lock().unwrap() = period.to_string();
+            return MockResponse::html("<html></html>").at("https://spot.upi.edu/adm");
+        }
+        let row = match handler_selected.lock().unwrap().as_str() {
+            "20251" => COURSE_ROW.to_string(),
+            _ => COURSE_ROW
+                .replace("Pemrograman Web", "Algoritma")
+                .replace("2025/2026 - Ganjil", "2024/2025 - Genap"),
+        };
+        MockResponse::html(&format!(
+            "<html><body><table><tbody>{}</tbody></table></body></html>",
+            row
+        ))
+    });
+    let client = common::builder()
+        .cache(Arc::new(MemoryCache::new()))
+        .transport(transport)
+        .build()?;
+
+    client.change_period(2025, Semester::Odd).await?;
+    assert_eq!(client.get_courses().await?[0].name, "Pemrograman Web");
+
+    client.change_period(2024, Semester::Even).await?;
+    assert_eq!(client.get_courses().await?[0].name, "Algoritma");
+
+    client.change_period(2025, Semester::Odd).await?;
+    assert_eq!(client.get_courses().await?[0].name, "Pemrograman Web");
+    assert_eq!(selected.lock().unwrap().as_str(), "20251");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_logout_clears_the_cache() -> Result<()> {
+    let logged_out = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let handler_logged_out = Arc::clone(&logged_out);
+    let transport = MockTransport::new(move |request| {
+        if request.path() == "/cas/logout" {
+            handler_logged_out.store(true, Ordering::SeqCst);
+            return MockResponse::html("<html><body>Logout successful</body></html>");
+        }
+        if handler_logged_out.load(Ordering::SeqCst) {
+            return MockResponse::html("<html></html>").at("https://sso.upi.edu/cas/login");
+        }
+        MockResponse::html(&format!(
+            "<html><body><table><tbody>{}</tbody></table></body></html>",
+            COURSE_ROW
+        ))
+    });
+    let cache = Arc::new(MemoryCache::new());
+    let client = common::builder()
+        .cache(cache.clone())
+        .cache_prefix("2306012")
+        .transport(transport.clone())
+        .build()?;
+    cache.set("2301234:courses", "[]", 3600).await.unwrap();
+
+    let cookies = std::env::temp_dir().join(format!(
+        "spotifier-logout-cache-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&cookies, r#"{"spot": "laravel_session=abc123"}"#).unwrap();
+    client.load_cookies(&cookies).await?;
+    std::fs::remove_file(&cookies).ok();
+
+    client.get_courses().await?;
+    assert!(cache.get("2306012:courses").await.is_some());
+
+    client.logout().await?;
+
+    assert!(cache.get("2306012:courses").await.is_none());
+    assert!(
+        cache.get("2301234:courses").await.is_some(),
+        "Entries of other users are kept"
+    );
+    let sent = transport.requests().len();
+    assert!(matches!(
+        client.get_courses().await,
+        Err(ScraperError::SessionExpired)
+    ));
+    assert_eq!(transport.requests().len(), sent + 1);
+    Ok(())
+}
+
+/// A custom backend that only implements the required methods.
+#[derive(Default)]
+struct MapCache {
+    entries: Mutex<std::collections::HashMap<String, String>>,
+}
+
+#[async_trait]
+impl CacheBackend for MapCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.entries.lock().unwrap().get(key).cloned()
+    }
+
+    async fn set(&self, key: &str, value: &str, _ttl_secs: u64) -> std::result::Result<(), String> {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> std::result::Result<(), String> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_logout_without_prefix_keeps_a_shared_cache() -> Result<()> {
+    let transport = MockTransport::new(|_| MockResponse::html("<html></html>"));
+    let cache = Arc::new(MapCache::default());
+    cache.set("other-app:key", "value", 3600).await.unwrap();
+    let client = common::builder()
+        .cache(cache.clone())
+        .transport(transport)
+        .build()?;
+
+    let cookies = std::env::temp_dir().join(format!(
+        "spotifier-logout-shared-{}.json",
+        std::process::id()
+    ));
+    std::fs::write(&cookies, r#"{"spot": "laravel_session=abc123"}"#).unwrap();
+    client.load_cookies(&cookies).await?;
+    std::fs::remove_file(&cookies).ok();
+
+    client.logout().await?;
+    assert_eq!(cache.get("other-app:key").await.as_deref(), Some("value"));
+    Ok(())
+}
+
+/// Sets the modification time of a `FileCache` entry to `secs` after the epoch, so the
+/// LRU order does not depend on how fast the test runs.
+fn set_last_used(cache_dir: &std::path::Path, key: &str, secs: u64) {
+    let file = std::fs::File::options()
+        .write(true)
+        .open(cache_dir.join(format!("{}.json", key)))
+        .unwrap();
+    file.set_modified(std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs))
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_file_cache_lru_eviction() {
+    let cache_dir = std::env::temp_dir().join("spotifier_lru_test");
+    if cache_dir.exists() {
+        std::fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    // Each entry is a bit over 1000 bytes, so only two fit under the limit.
+    let value = "x".repeat(1000);
+    let cache = FileCache::with_limit(&cache_dir, 2500);
+
+    cache.set("a", &value, 3600).await.unwrap();
+    cache.set("b", &value, 3600).await.unwrap();
+    set_last_used(&cache_dir, "a", 1_000);
+    set_last_used(&cache_dir, "b", 2_000);
+
+    // Reading "a" makes it the most recently used entry.
+    assert!(cache.get("a").await.is_some());
+
+    cache.set("c", &value, 3600).await.unwrap();
+    assert!(
+        cache.get("b").await.is_none(),
+        "LRU entry should be evicted"
+    );
+    assert!(cache.get("a").await.is_some());
+    assert!(cache.get("c").await.is_some());
+
+    set_last_used(&cache_dir, "a", 3_000);
+    set_last_used(&cache_dir, "c", 4_000);
+    cache.set("d", &value, 3600).await.unwrap();
+    assert!(
+        cache.get("a").await.is_none(),
+        "Oldest entry should be evicted"
+    );
+    assert!(cache.get("c").await.is_some());
+    assert!(cache.get("d").await.is_some());
+
+    std::fs::remove_dir_all(&cache_dir).ok();
+}
+
+#[tokio::test]
+async fn test_clones_share_the_cache() -> Result<()> {
+    let cache_dir = std::env::temp_dir().join("spotifier_shared_cache_test");
+    if cache_dir.exists() {
+        std::fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    let cache: Arc<dyn CacheBackend> = Arc::new(FileCache::new(&cache_dir));
+    let mut original = SpotifierCoreClient::shared(Arc::clone(&cache));
+    original.set_cache_prefix("2306012");
+    let clone = original.clone();
+
+    // Populate the cache as `get_courses` on the original would
+    let courses = r#"[{"id":2510009532,"code":"IK410","name":"Pemrograman Web","credits":3,"lecturer":"Dosen","academic_year":"2025/2026 - Ganjil","href":"/mhs/topik/2510009532"}]"#;
+    cache.set("2306012:courses", courses, 3600).await.unwrap();
+
+    // The clone is not logged in, so this only succeeds if it reads the shared cache
+    let cached = clone.get_courses().await?;
+    assert_eq!(cached.len(), 1);
+    assert_eq!(cached[0].code, "IK410");
+
+    std::fs::remove_dir_all(&cache_dir).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_cache_ttls_per_resource() -> Result<()> {
+    let cache_dir = std::env::temp_dir().join("spotifier_cache_ttls_test");
+    if cache_dir.exists() {
+        std::fs::remove_dir_all(&cache_dir).ok();
+    }
+
+    let ttls = CacheTtls {
+        courses_secs: 3600,
+        profile_secs: 1,
+        ..Default::default()
+    };
+    let cache: Arc<dyn CacheBackend> = Arc::new(FileCache::new(&cache_dir));
+    let mut client = common::client();
+    client.set_cache(Arc::clone(&cache));
+    client.set_cache_ttls(ttls.clone());
+
+    // Entries as the client would have stored them with these TTLs.
+    let course = serde_json::json!([{
+        "id": 2510009532u64,
+        "code": "IK410",
+        "name": "Pemrograman Web",
+        "credits": 3,
+        "lecturer": "Dosen",
+        "academic_year": "2025/2026 - Ganjil",
+        "href": "/mhs/topik/2510009532"
+    }]);
+    let profile = serde_json::json!({ "name": "Budi", "nim": "2101234" });
+    cache
+        .set("courses", &course.to_string(), ttls.courses_secs)
+        .await
+        .unwrap();
+    cache
+        .set("profile", &profile.to_string(), ttls.profile_secs)
+        .await
+        .unwrap();
+
+    assert_eq!(client.get_user_profile().await?.nim, "2101234");
+    tokio::time::sleep(std::time::Duration::from_millis(2100)).await;
+
+    // The long-lived course list is still served from the cache...
+    assert_eq!(client.get_courses().await?[0].code, "IK410");
+    // ...while the expired profile has to be fetched again, which fails without a session.
+    assert!(client.get_user_profile().await.is_err());
+
+    std::fs::remove_dir_all(&cache_dir).ok();
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_memory_cache_expiry() {
+    let cache = MemoryCache::new();
+
+    cache.set("short", "value", 1).await.unwrap();
+    cache.set("long", "value", 3600).await.unwrap();
+    assert_eq!(cache.get("short").await.as_deref(), Some("value"));
+
+    // TTLs have second granularity, so wait until the entry is past its last valid second.
+    tokio::time::sleep(std::time::Duration::from_millis(2100)).await;
+    assert_eq!(cache.get("short").await, None);
+    assert_eq!(cache.get("long").await.as_deref(), Some("value"));
+
+    cache.delete("long").await.unwrap();
+    assert_eq!(cache.get("long").await, None);
+}
+
+const COURSE_ROW: &str = r#"<tr>
+  <td>IK410</td><td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+  <td>3</td><td>Dosen Pengampu</td><td>2025/2026 - Ganjil</td>
+</tr>"#;
+
+/// Serves `/mhs` pages in order, each with a fresh CSRF token like the real dashboard.
+///
+/// Only the HTML dashboard is served, so clients using it turn `prefer_json` off.
+struct DashboardSequence {
+    pages: Mutex<Vec<String>>,
+    served: AtomicUsize,
+}
+
+#[async_trait]
+impl HttpTransport for DashboardSequence {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        assert_eq!(request.url().path(), "/mhs");
+        let n = self.served.fetch_add(1, Ordering::SeqCst);
+        let rows = self.pages.lock().unwrap().remove(0);
+        let body = format!(
+            r#"<html><head><meta name="csrf-token" content="token-{}"></head><body>
+            <table><tbody>{}</tbody></table></body></html>"#,
+            n, rows
+        );
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(200)
+            .body(body)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+#[tokio::test]
+async fn test_course_list_change_detection() -> Result<()> {
+    let renamed = COURSE_ROW.replace("Pemrograman Web", "Pemrograman Web Lanjut");
+    let transport = Arc::new(DashboardSequence {
+        pages: Mutex::new(vec![
+            COURSE_ROW.to_string(),
+            COURSE_ROW.to_string(),
+            renamed,
+        ]),
+        served: AtomicUsize::new(0),
+    });
+    let client = common::builder()
+        .cache(Arc::new(MemoryCache::new()))
+        .prefer_json(false)
+        .transport(transport.clone())
+        .build()?;
+
+    let first = client.get_courses_checked().await?;
+    assert!(!first.from_cache && first.changed);
+    assert_eq!(first.courses.len(), 1);
+
+    let cached = client.get_courses_checked().await?;
+    assert!(cached.from_cache && !cached.changed);
+    assert_eq!(transport.served.load(Ordering::SeqCst), 1);
+
+    // Same courses, different page (new token): not a change.
+    let same = client.refresh_courses().await?;
+    assert!(!same.from_cache && !same.changed);
+
+    let changed = client.refresh_courses().await?;
+    assert!(changed.changed);
+    assert_eq!(changed.courses[0].name, "Pemrograman Web Lanjut");
+    assert_eq!(
+        client.get_courses().await?[0].name,
+        "Pemrograman Web Lanjut"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_course_list_hash_is_shared_through_the_cache() -> Result<()> {
+    let cache = Arc::new(MemoryCache::new());
+    let client_for = |page: &str| {
+        common::builder()
+            .cache(cache.clone())
+            .prefer_json(false)
+            .transport(Arc::new(DashboardSequence {
+                pages: Mutex::new(vec![page.to_string()]),
+                served: AtomicUsize::new(0),
+            }))
+            .build()
+    };
+
+    assert!(client_for(COURSE_ROW)?.refresh_courses().await?.changed);
+    assert!(cache.get("courses_hash").await.is_some());
+
+    // A second worker (or a restarted process) on the same cache sees the same list.
+    let same = client_for(COURSE_ROW)?.refresh_courses().await?;
+    assert!(!same.changed);
 
-    std::fs::remove_dir_all(cache_dir).ok();
+    let renamed = COURSE_ROW.replace("Pemrograman Web", "Pemrograman Web Lanjut");
+    assert!(client_for(&renamed)?.refresh_courses().await?.changed);
     Ok(())
 }
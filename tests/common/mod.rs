@@ -0,0 +1,346 @@
+// tests/common/mod.rs
+//
+// Helpers shared by the offline client tests. Each test binary only uses some of them.
+#![allow(dead_code)]
+
+use async_trait::async_trait;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{
+    DelayConfig, HttpTransport, Recording, ReplayTransport, Result, ScraperError,
+    SpotifierCoreClient, SpotifierCoreClientBuilder,
+};
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// A client builder with human-like delays turned off.
+pub fn builder() -> SpotifierCoreClientBuilder {
+    SpotifierCoreClient::builder().delay(DelayConfig {
+        enabled: false,
+        ..DelayConfig::default()
+    })
+}
+
+/// A client with human-like delays turned off.
+pub fn client() -> SpotifierCoreClient {
+    builder().build().unwrap()
+}
+
+/// A client without delays that sends every request through `transport`.
+pub fn client_with(transport: Arc<dyn HttpTransport>) -> SpotifierCoreClient {
+    builder().transport(transport).build().unwrap()
+}
+
+/// A successful recording of `method url` that ended at `final_url`.
+pub fn recording(method: &str, url: &str, final_url: &str, body: &str) -> Recording {
+    Recording {
+        method: method.to_string(),
+        url: url.to_string(),
+        request_body: None,
+        status: 200,
+        final_url: final_url.to_string(),
+        response_body: body.to_string(),
+    }
+}
+
+/// A client without delays that answers from `recordings` only.
+pub fn replay_client(recordings: Vec<Recording>) -> SpotifierCoreClient {
+    let mut transport = ReplayTransport::default();
+    for recording in recordings {
+        transport.insert(recording);
+    }
+    client_with(Arc::new(transport))
+}
+
+/// A client without delays that replays `tests/fixtures/recordings/{fixture}`.
+pub fn replay_fixture(fixture: &str) -> Result<SpotifierCoreClient> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/recordings")
+        .join(fixture);
+    Ok(client_with(Arc::new(ReplayTransport::from_dir(&dir)?)))
+}
+
+/// A request as it reached [`MockTransport`].
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    pub method: String,
+    pub url: String,
+    pub headers: http::HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl CapturedRequest {
+    /// The value of header `name`, if present and valid UTF-8.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(name).and_then(|v| v.to_str().ok())
+    }
+
+    /// The URL path, e.g. `/mhs/topik/1`.
+    pub fn path(&self) -> String {
+        reqwest::Url::parse(&self.url).unwrap().path().to_string()
+    }
+
+    /// The body, lossily decoded, including every part of a multipart upload.
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+}
+
+/// What [`MockTransport`] answers with.
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    /// The URL the response ended at; the request URL when `None`.
+    pub final_url: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+    /// How long the transport waits before answering.
+    pub delay: Duration,
+}
+
+impl MockResponse {
+    /// A `200 OK` HTML page.
+    pub fn html(body: &str) -> Self {
+        Self::bytes(body.as_bytes().to_vec()).header("content-type", "text/html")
+    }
+
+    /// A `200 OK` response with a raw body.
+    pub fn bytes(body: Vec<u8>) -> Self {
+        Self {
+            status: 200,
+            final_url: None,
+            headers: Vec::new(),
+            body,
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// An empty response with the given status.
+    pub fn status(status: u16) -> Self {
+        Self {
+            status,
+            ..Self::bytes(Vec::new())
+        }
+    }
+
+    pub fn with_status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Pretends the request was redirected to `url`.
+    pub fn at(mut self, url: &str) -> Self {
+        self.final_url = Some(url.to_string());
+        self
+    }
+
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn delayed(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+}
+
+type Handler = dyn Fn(&CapturedRequest) -> MockResponse + Send + Sync;
+
+/// A transport that records every request and answers through a handler.
+///
+/// It also tracks how many requests were in flight at once, for concurrency limits.
+pub struct MockTransport {
+    handler: Box<Handler>,
+    requests: Mutex<Vec<CapturedRequest>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+impl MockTransport {
+    pub fn new(
+        handler: impl Fn(&CapturedRequest) -> MockResponse + Send + Sync + 'static,
+    ) -> Arc<Self> {
+        Arc::new(Self {
+            handler: Box::new(handler),
+            requests: Mutex::new(Vec::new()),
+            in_flight: AtomicUsize::new(0),
+            max_in_flight: AtomicUsize::new(0),
+        })
+    }
+
+    /// Every request received so far, in order.
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// The last request received.
+    pub fn last_request(&self) -> CapturedRequest {
+        self.requests().pop().expect("no request was sent")
+    }
+
+    /// The highest number of requests that were in flight at the same time.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+}
+
+/// Collects a request body, streaming ones (multipart uploads) included.
+async fn collect_body(body: Option<reqwest::Body>) -> Vec<u8> {
+    use http_body::Body;
+
+    let Some(mut body) = body else {
+        return Vec::new();
+    };
+    if let Some(bytes) = body.as_bytes() {
+        return bytes.to_vec();
+    }
+    let mut collected = Vec::new();
+    while let Some(frame) = futures::future::poll_fn(|cx| Pin::new(&mut body).poll_frame(cx)).await
+    {
+        if let Ok(data) = frame.expect("request body").into_data() {
+            collected.extend_from_slice(&data);
+        }
+    }
+    collected
+}
+
+#[async_trait]
+impl HttpTransport for MockTransport {
+    async fn execute(&self, mut request: reqwest::Request) -> Result<reqwest::Response> {
+        let captured = CapturedRequest {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            headers: request.headers().clone(),
+            body: collect_body(request.body_mut().take()).await,
+        };
+        self.requests.lock().unwrap().push(captured.clone());
+
+        let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+        let reply = (self.handler)(&captured);
+        if !reply.delay.is_zero() {
+            tokio::time::sleep(reply.delay).await;
+        }
+        self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+        let url = reqwest::Url::parse(reply.final_url.as_deref().unwrap_or(&captured.url))
+            .map_err(|e| ScraperError::ParsingError(e.to_string()))?;
+        let mut response = http::Response::builder().status(reply.status).url(url);
+        for (name, value) in &reply.headers {
+            response = response.header(name, value);
+        }
+        Ok(response.body(reply.body).unwrap().into())
+    }
+}
+
+/// A raw HTTP/1.1 response that closes the connection.
+pub fn http_response(status: &str, headers: &str, body: &str) -> String {
+    format!(
+        "HTTP/1.1 {}\r\n{}content-length: {}\r\nconnection: close\r\n\r\n{}",
+        status,
+        headers,
+        body.len(),
+        body
+    )
+}
+
+/// What [`TestServer`] writes back: raw response chunks, each after a delay.
+pub struct Reply {
+    parts: Vec<(Duration, String)>,
+}
+
+impl Reply {
+    /// Writes `response` right away.
+    pub fn now(response: String) -> Self {
+        Self {
+            parts: vec![(Duration::ZERO, response)],
+        }
+    }
+
+    /// Writes `more` once `delay` has passed since the previous chunk.
+    pub fn then_after(mut self, delay: Duration, more: &str) -> Self {
+        self.parts.push((delay, more.to_string()));
+        self
+    }
+
+    /// Accepts the connection but never answers.
+    pub fn hang() -> Self {
+        Self { parts: Vec::new() }
+    }
+}
+
+/// A local HTTP server, for tests that need real sockets: cookies, proxies, timeouts.
+pub struct TestServer {
+    listener: TcpListener,
+}
+
+impl TestServer {
+    pub async fn bind() -> Self {
+        Self {
+            listener: TcpListener::bind("127.0.0.1:0").await.unwrap(),
+        }
+    }
+
+    pub fn port(&self) -> u16 {
+        self.listener.local_addr().unwrap().port()
+    }
+
+    /// `http://127.0.0.1:{port}`.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{}", self.port())
+    }
+
+    /// Answers every connection with what `handler` returns for its raw request.
+    pub fn serve(self, handler: impl Fn(&str) -> Reply + Send + Sync + 'static) {
+        let handler = Arc::new(handler);
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = self.listener.accept().await {
+                let handler = Arc::clone(&handler);
+                tokio::spawn(async move {
+                    let request = read_request(&mut socket).await;
+                    let reply = handler(&request);
+                    if reply.parts.is_empty() {
+                        std::future::pending::<()>().await;
+                    }
+                    for (delay, chunk) in reply.parts {
+                        tokio::time::sleep(delay).await;
+                        if socket.write_all(chunk.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+    }
+}
+
+/// Reads one request: the head plus as much body as `content-length` announces.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> String {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 8192];
+    loop {
+        let n = socket.read(&mut buf).await.unwrap_or(0);
+        request.extend_from_slice(&buf[..n]);
+        let text = String::from_utf8_lossy(&request);
+        let complete = text.split_once("\r\n\r\n").is_some_and(|(head, body)| {
+            let length = head
+                .lines()
+                .find_map(|l| {
+                    l.to_ascii_lowercase()
+                        .strip_prefix("content-length:")
+                        .map(|v| v.trim().to_string())
+                })
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(0);
+            body.len() >= length
+        });
+        if n == 0 || complete {
+            return text.into_owned();
+        }
+    }
+}
@@ -0,0 +1,415 @@
+// tests/course_detail_test.rs
+
+use chrono::{NaiveDate, NaiveDateTime};
+use spotifier_core::parsers::course::parse_course_detail;
+use spotifier_core::parsers::course_detail::{
+    parse_course_detail_from_html, parse_course_topics_from_html,
+};
+use spotifier_core::parsers::{normalize_href, normalize_href_on};
+use spotifier_core::{Accessibility, Course, Result, Rps, ScraperError};
+
+fn sample_course() -> Course {
+    Course {
+        id: 2510009532,
+        code: "IK410".to_string(),
+        name: "Pemrograman Web".to_string(),
+        credits: 3,
+        lecturer: "Dosen".to_string(),
+        lecturers: vec!["Dosen".to_string()],
+        academic_year: "2025/2026 - Ganjil".to_string(),
+        href: "/mhs/topik/2510009532".to_string(),
+    }
+}
+
+/// The time in WIB the pages below are parsed at.
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 9, 10)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap()
+}
+
+const TOPICS_HTML: &str = r#"
+<html><body>
+  <div class="container-fluid">
+    <div class="block4">
+      <div class="panel-body">
+        <a class="btn btn-info" href="https://spot.upi.edu/mhs/topik/2510009532/1358801">Masuk</a>
+        <div><div>
+          <button class="btn disabled">Waktu Akses: 01-09-2025 08:00</button>
+          <button class="btn disabled">Diperbarui: 10-09-2025 13:30</button>
+        </div></div>
+      </div>
+    </div>
+    <div class="block4">
+      <div class="panel-body">
+        <a class="btn btn-info" href="/mhs/topik/2510009532/1358802">Masuk</a>
+        <div><div>
+          <button class="btn disabled">Waktu Akses: 08-09-2025 08:00</button>
+        </div></div>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_topic_updated_at_is_parsed() -> Result<()> {
+    let detail = parse_course_detail_from_html(TOPICS_HTML, sample_course(), now())?;
+    assert_eq!(detail.topics.len(), 2);
+
+    let first = &detail.topics[0];
+    assert_eq!(first.id, Some(1358801));
+    assert_eq!(first.course_id, Some(2510009532));
+    assert_eq!(
+        first.access_time,
+        NaiveDate::from_ymd_opt(2025, 9, 1).and_then(|d| d.and_hms_opt(8, 0, 0))
+    );
+    assert_eq!(
+        first.updated_at,
+        NaiveDate::from_ymd_opt(2025, 9, 10).and_then(|d| d.and_hms_opt(13, 30, 0))
+    );
+
+    let second = &detail.topics[1];
+    assert!(second.access_time.is_some());
+    assert_eq!(second.updated_at, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_topics_only_parse_matches_full_detail() -> Result<()> {
+    let detail = parse_course_detail_from_html(TOPICS_HTML, sample_course(), now())?;
+    let topics = parse_course_topics_from_html(TOPICS_HTML, 2510009532, now())?;
+
+    let ids = |topics: &[spotifier_core::TopicInfo]| {
+        topics
+            .iter()
+            .map(|t| {
+                (
+                    t.course_id,
+                    t.id,
+                    t.href.clone(),
+                    t.access_time,
+                    t.updated_at,
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(ids(&topics), ids(&detail.topics));
+    assert_eq!(detail.topics_accessible().count(), 2);
+
+    Ok(())
+}
+
+const DESCRIPTION_HTML: &str = r#"
+<html><body>
+  <div class="white-box">
+    <p>Mata kuliah ini membahas <b>dasar-dasar</b> web.
+       Lihat <a href="https://example.com/silabus">silabus</a>.</p>
+    <p><a class="btn btn-danger" href="/mhs/rps/98765">RPS</a></p>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_description_keeps_html_and_strips_to_text() -> Result<()> {
+    let detail = parse_course_detail_from_html(DESCRIPTION_HTML, sample_course(), now())?;
+
+    assert_eq!(detail.rps.id, Some(98765));
+    assert!(detail.description.contains("<b>dasar-dasar</b>"));
+    assert!(
+        detail
+            .description
+            .contains(r#"<a href="https://example.com/silabus">silabus</a>"#)
+    );
+    assert_eq!(
+        detail.description_text(),
+        "Mata kuliah ini membahas dasar-dasar web. Lihat silabus."
+    );
+
+    Ok(())
+}
+
+const LECTURER_CONTACT_HTML: &str = r#"
+<html><body>
+  <div class="white-box">
+    <h4>Dosen: <a href="../../dosen/profil/1987">Dr. Budi Santoso, M.Kom.</a></h4>
+    <a href="mailto:Budi.Santoso@upi.edu?subject=IK410">Kirim email</a>
+    <p>Mata kuliah ini membahas dasar-dasar web.</p>
+    <p><a class="btn btn-danger" href="/mhs/rps/98765">RPS</a></p>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_lecturer_contact_is_parsed() -> Result<()> {
+    let detail = parse_course_detail_from_html(LECTURER_CONTACT_HTML, sample_course(), now())?;
+    assert_eq!(
+        detail.lecturer_email.as_deref(),
+        Some("budi.santoso@upi.edu")
+    );
+    assert_eq!(
+        detail.lecturer_profile_href.as_deref(),
+        Some("/dosen/profil/1987")
+    );
+
+    // Plain-text addresses count too, skipping malformed ones; external profiles stay absolute.
+    let html = LECTURER_CONTACT_HTML
+        .replace(
+            r#"<a href="mailto:Budi.Santoso@upi.edu?subject=IK410">Kirim email</a>"#,
+            "Email: (budi@upi) atau budi@cs.upi.edu.",
+        )
+        .replace(
+            "../../dosen/profil/1987",
+            "https://sinta.kemdikbud.go.id/authors/profile/42",
+        );
+    let detail = parse_course_detail_from_html(&html, sample_course(), now())?;
+    assert_eq!(detail.lecturer_email.as_deref(), Some("budi@cs.upi.edu"));
+    assert_eq!(
+        detail.lecturer_profile_href.as_deref(),
+        Some("https://sinta.kemdikbud.go.id/authors/profile/42")
+    );
+
+    let detail = parse_course_detail_from_html(DESCRIPTION_HTML, sample_course(), now())?;
+    assert_eq!(detail.lecturer_email, None);
+    assert_eq!(detail.lecturer_profile_href, None);
+
+    Ok(())
+}
+
+const LECTURER_CONTACT_WITH_CHROME_HTML: &str = r#"
+<html><body>
+  <nav class="navbar">
+    <a href="/mhs/profil">Profil Saya</a>
+  </nav>
+  <div class="white-box">
+    <h4>Dosen: Dr. Budi Santoso, M.Kom.</h4>
+    <p>Mata kuliah ini membahas dasar-dasar web.</p>
+    <p><a class="btn btn-danger" href="/mhs/rps/98765">RPS</a></p>
+  </div>
+  <footer>
+    <a href="mailto:helpdesk@upi.edu">Helpdesk</a>
+  </footer>
+</body></html>
+"#;
+
+#[test]
+fn test_lecturer_contact_ignores_navbar_and_footer() -> Result<()> {
+    let detail =
+        parse_course_detail_from_html(LECTURER_CONTACT_WITH_CHROME_HTML, sample_course(), now())?;
+    assert_eq!(detail.lecturer_email, None);
+    assert_eq!(detail.lecturer_profile_href, None);
+
+    Ok(())
+}
+
+const LOCKED_AND_UNLOCKED_HTML: &str = r#"
+<html><body>
+  <div class="container-fluid">
+    <div class="block4">
+      <div class="panel-body">
+        <a class="btn btn-info" href="/mhs/topik/2510009532/1358801">Masuk</a>
+      </div>
+    </div>
+    <div class="block4">
+      <div class="panel-body">
+        <button class="btn btn-default disabled"><i class="fa fa-lock"></i> Terkunci</button>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_topic_accessibility_flag() -> Result<()> {
+    let detail = parse_course_detail(LOCKED_AND_UNLOCKED_HTML, &sample_course(), now())?;
+    assert_eq!(detail.topics.len(), 2);
+
+    let unlocked = &detail.topics[0];
+    assert!(unlocked.is_accessible);
+    assert_eq!(unlocked.ids(), Some((2510009532, 1358801)));
+
+    let locked = &detail.topics[1];
+    assert!(!locked.is_accessible);
+    assert_eq!(locked.accessibility, Accessibility::Closed);
+    assert_eq!(locked.href, None);
+    assert_eq!(locked.ids(), None);
+
+    Ok(())
+}
+
+const LOCK_REASONS_HTML: &str = r#"
+<html><body>
+  <div class="container-fluid">
+    <div class="block4">
+      <div class="panel-body">
+        <div><div>
+          <button class="btn btn-default disabled">Waktu Akses: 15-09-2025 08:00</button>
+          <button class="btn btn-default disabled">Diperbarui: 01-09-2025 10:00</button>
+        </div></div>
+        <span><i class="fa fa-lock"></i> Terkunci</span>
+      </div>
+    </div>
+    <div class="block4">
+      <div class="panel-body">
+        <span><i class="fa fa-lock"></i> Selesaikan topik sebelumnya untuk membuka topik ini</span>
+      </div>
+    </div>
+    <div class="block4">
+      <div class="panel-heading">Selesaikan Studi Kasus</div>
+      <div class="panel-body">
+        <div><div>
+          <button class="btn btn-default disabled">Waktu Akses: 01-09-2025 08:00</button>
+        </div></div>
+        <span><i class="fa fa-lock"></i> Terkunci</span>
+      </div>
+    </div>
+    <div class="block4">
+      <div class="panel-body">
+        <a class="btn btn-info" href="/mhs/topik/2510009532/1358803">Masuk</a>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_topic_lock_reasons() -> Result<()> {
+    let topics = parse_course_topics_from_html(LOCK_REASONS_HTML, 2510009532, now())?;
+    assert_eq!(topics.len(), 4);
+
+    let opens_at = NaiveDate::from_ymd_opt(2025, 9, 15)
+        .unwrap()
+        .and_hms_opt(8, 0, 0)
+        .unwrap();
+    assert_eq!(
+        topics[0].accessibility,
+        Accessibility::LockedUntil(opens_at)
+    );
+    assert!(!topics[0].is_accessible);
+    assert_eq!(topics[1].accessibility, Accessibility::LockedPrerequisite);
+    assert!(!topics[1].is_accessible);
+    // Its access time has passed and the title is not a lock label: closed by the lecturer.
+    assert_eq!(topics[2].accessibility, Accessibility::Closed);
+    assert_eq!(topics[3].accessibility, Accessibility::Open);
+    assert!(topics[3].is_accessible);
+
+    // Once the opening date has passed, the first topic counts as closed as well.
+    let later = NaiveDate::from_ymd_opt(2025, 9, 20)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let topics = parse_course_topics_from_html(LOCK_REASONS_HTML, 2510009532, later)?;
+    assert_eq!(topics[0].accessibility, Accessibility::Closed);
+
+    Ok(())
+}
+
+const NO_RPS_HTML: &str = r#"
+<html><body>
+  <div class="white-box">
+    <p>Belum ada RPS untuk mata kuliah ini.</p>
+  </div>
+  <div class="container-fluid">
+    <div class="block4">
+      <div class="panel-body">
+        <a class="btn btn-info" href="/mhs/topik/2510009532/1358801">Masuk</a>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_course_without_rps() -> Result<()> {
+    let detail = parse_course_detail_from_html(NO_RPS_HTML, sample_course(), now())?;
+
+    assert_eq!(detail.rps, Rps::default());
+    assert!(!detail.rps.is_available());
+    assert_eq!(detail.topics.len(), 1);
+    assert!(matches!(
+        detail.available_rps(),
+        Err(ScraperError::RpsNotAvailable(2510009532))
+    ));
+
+    let with_rps = parse_course_detail_from_html(DESCRIPTION_HTML, sample_course(), now())?;
+    assert_eq!(with_rps.available_rps()?.id, Some(98765));
+
+    Ok(())
+}
+
+#[test]
+fn test_normalize_href() {
+    for href in [
+        "/mhs/topik/1/2",
+        "mhs/topik/1/2",
+        "https://spot.upi.edu/mhs/topik/1/2",
+        "//spot.upi.edu/mhs/topik/1/2#tugas",
+        " ../mhs/./topik/1/2 ",
+    ] {
+        assert_eq!(
+            normalize_href(href).as_deref(),
+            Some("/mhs/topik/1/2"),
+            "{}",
+            href
+        );
+    }
+    assert_eq!(
+        normalize_href("https://spot.upi.edu/tugas/1/2/a.pdf?v=3").as_deref(),
+        Some("/tugas/1/2/a.pdf?v=3")
+    );
+    assert_eq!(
+        normalize_href("http://example.com/silabus").as_deref(),
+        Some("http://example.com/silabus")
+    );
+    for href in [
+        "",
+        "#",
+        "#top",
+        "mailto:dosen@upi.edu",
+        "javascript:void(0)",
+    ] {
+        assert_eq!(normalize_href(href), None, "{}", href);
+    }
+}
+
+#[test]
+fn test_normalize_href_on_resolves_against_the_page() {
+    assert_eq!(
+        normalize_href_on("/mhs/topik/1/2", "tugas/5").as_deref(),
+        Some("/mhs/topik/1/tugas/5")
+    );
+    assert_eq!(
+        normalize_href_on("/mhs/topik/1", "../rps/9").as_deref(),
+        Some("/mhs/rps/9")
+    );
+    assert_eq!(
+        normalize_href_on("/mhs/topik/1/2", "/materi/a.pdf").as_deref(),
+        Some("/materi/a.pdf")
+    );
+    assert_eq!(
+        normalize_href_on("/mhs/topik/1/2", "https://example.com/a").as_deref(),
+        Some("https://example.com/a")
+    );
+}
+
+#[test]
+fn test_course_detail_hrefs_are_normalized() -> Result<()> {
+    let html = DESCRIPTION_HTML.replace("/mhs/rps/98765", "https://spot.upi.edu/mhs/rps/98765");
+    let detail = parse_course_detail_from_html(&html, sample_course(), now())?;
+    assert_eq!(detail.rps.href.as_deref(), Some("/mhs/rps/98765"));
+    assert_eq!(detail.rps.id, Some(98765));
+
+    let detail = parse_course_detail_from_html(TOPICS_HTML, sample_course(), now())?;
+    let hrefs: Vec<_> = detail.topics.iter().map(|t| t.href.as_deref()).collect();
+    assert_eq!(
+        hrefs,
+        [
+            Some("/mhs/topik/2510009532/1358801"),
+            Some("/mhs/topik/2510009532/1358802")
+        ]
+    );
+
+    Ok(())
+}
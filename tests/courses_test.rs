@@ -0,0 +1,290 @@
+// tests/courses_test.rs
+
+mod common;
+
+use common::{MockResponse, MockTransport};
+use spotifier_core::parsers::courses::{
+    parse_courses_from_html, parse_courses_from_json, parse_next_page_href,
+};
+use spotifier_core::{ReplayTransport, Result, SpotifierCoreClient};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const COURSES_HTML: &str = r#"
+<html><body>
+  <table>
+    <tbody>
+      <tr>
+        <td>IK410</td>
+        <td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+        <td>3</td>
+        <td>Dosen Pengampu</td>
+        <td>2025/2026 - Ganjil</td>
+      </tr>
+      <tr>
+        <td>IK420</td>
+        <td><a href="/mhs/topik/2510009533">Basis Data</a></td>
+        <td>2</td>
+        <td>Dosen Lain</td>
+        <td>2025/2026 - Ganjil</td>
+      </tr>
+    </tbody>
+  </table>
+</body></html>
+"#;
+
+const COURSES_JSON: &str = r#"{
+  "data": [
+    {"id": 2510009532, "code": "IK410", "name": "Pemrograman Web", "credits": 3,
+     "lecturer": "Dosen Pengampu", "academic_year": "2025/2026 - Ganjil", "href": "/mhs/topik/2510009532"},
+    {"id": 2510009533, "code": "IK420", "name": "Basis Data", "credits": 2,
+     "lecturer": "Dosen Lain", "academic_year": "2025/2026 - Ganjil", "href": "/mhs/topik/2510009533"}
+  ]
+}"#;
+
+#[test]
+fn test_json_course_list_matches_html() -> Result<()> {
+    let from_html = parse_courses_from_html(COURSES_HTML)?;
+    let from_json = parse_courses_from_json(COURSES_JSON)?;
+
+    assert_eq!(
+        serde_json::to_value(&from_html).unwrap(),
+        serde_json::to_value(&from_json).unwrap()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_json_course_list_accepts_bare_array_and_rejects_other_shapes() {
+    let bare = r#"[{"id": 1, "code": "IK410", "name": "Pemrograman Web", "credits": 3,
+        "lecturer": "Dosen", "academic_year": "2025/2026 - Ganjil", "href": "/mhs/topik/1"}]"#;
+    assert_eq!(parse_courses_from_json(bare).unwrap().len(), 1);
+
+    assert!(parse_courses_from_json(r#"{"status": "error"}"#).is_err());
+    assert!(parse_courses_from_json("<html></html>").is_err());
+    assert!(parse_courses_from_json("[]").is_err());
+}
+
+#[test]
+fn test_json_lecturer_is_the_first_of_lecturers() -> Result<()> {
+    let json = r#"[
+      {"id": 1, "code": "IK410", "name": "Pemrograman Web", "credits": 3,
+       "lecturer": "Dr. Budi Santoso, M.Kom.; Siti Aminah", "academic_year": "2025/2026 - Ganjil",
+       "href": "/mhs/topik/1"},
+      {"id": 2, "code": "IK420", "name": "Basis Data", "credits": 2, "lecturer": "",
+       "lecturers": ["Rina Wati", "Andi Pratama"], "academic_year": "2025/2026 - Ganjil",
+       "href": "/mhs/topik/2"}
+    ]"#;
+
+    let courses = parse_courses_from_json(json)?;
+
+    assert_eq!(
+        courses[0].lecturers,
+        ["Dr. Budi Santoso, M.Kom.", "Siti Aminah"]
+    );
+    assert_eq!(courses[0].lecturer, "Dr. Budi Santoso, M.Kom.");
+    assert_eq!(courses[1].lecturer, "Rina Wati");
+    Ok(())
+}
+
+const COURSES_PAGE_1_HTML: &str = r#"
+<html><body>
+  <table>
+    <tbody>
+      <tr>
+        <td>IK410</td>
+        <td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+        <td>3</td>
+        <td>Dosen Pengampu</td>
+        <td>2025/2026 - Ganjil</td>
+      </tr>
+    </tbody>
+  </table>
+  <ul class="pagination">
+    <li class="page-item disabled"><span class="page-link">&lsaquo;</span></li>
+    <li class="page-item active"><span class="page-link">1</span></li>
+    <li class="page-item"><a class="page-link" href="https://spot.upi.edu/mhs?page=2">2</a></li>
+    <li class="page-item"><a class="page-link" href="https://spot.upi.edu/mhs?page=2" rel="next">&rsaquo;</a></li>
+  </ul>
+</body></html>
+"#;
+
+const COURSES_PAGE_2_HTML: &str = r#"
+<html><body>
+  <table>
+    <tbody>
+      <tr>
+        <td>IK300</td>
+        <td><a href="/mhs/topik/2420001">Algoritma</a></td>
+        <td>3</td>
+        <td>Dosen Lain</td>
+        <td>2024/2025 - Genap</td>
+      </tr>
+    </tbody>
+  </table>
+  <ul class="pagination">
+    <li class="page-item"><a class="page-link" href="https://spot.upi.edu/mhs?page=1" rel="prev">&lsaquo;</a></li>
+    <li class="page-item"><a class="page-link" href="https://spot.upi.edu/mhs?page=1">1</a></li>
+    <li class="page-item active"><span class="page-link">2</span></li>
+    <li class="page-item disabled"><span class="page-link">&rsaquo;</span></li>
+  </ul>
+</body></html>
+"#;
+
+const DEFENSIVE_COURSES_HTML: &str = r#"
+<html><body>
+  <table>
+    <tbody>
+      <tr>
+        <td>IK410</td>
+        <td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+        <td> 3 SKS </td>
+        <td>Dr. Budi Santoso, M.Kom., Siti Aminah, S.Pd., M.T.</td>
+        <td>2025/2026 - Ganjil</td>
+      </tr>
+      <tr>
+        <td>IK420</td>
+        <td><a href="/mhs/topik/2510009533">Basis Data</a></td>
+        <td></td>
+        <td>Dosen Pertama<br>Dosen Kedua; Dosen Ketiga</td>
+        <td>2025/2026 - Ganjil</td>
+      </tr>
+    </tbody>
+  </table>
+</body></html>
+"#;
+
+#[test]
+fn test_credits_and_lecturers_are_parsed_defensively() -> Result<()> {
+    let courses = parse_courses_from_html(DEFENSIVE_COURSES_HTML)?;
+    assert_eq!(courses.len(), 2);
+
+    assert_eq!(courses[0].credits, 3);
+    assert_eq!(
+        courses[0].lecturers,
+        vec!["Dr. Budi Santoso, M.Kom.", "Siti Aminah, S.Pd., M.T."]
+    );
+    assert_eq!(courses[0].lecturer, "Dr. Budi Santoso, M.Kom.");
+
+    // An empty credits cell falls back to 0 instead of dropping the course.
+    assert_eq!(courses[1].credits, 0);
+    assert_eq!(
+        courses[1].lecturers,
+        vec!["Dosen Pertama", "Dosen Kedua", "Dosen Ketiga"]
+    );
+    assert_eq!(courses[1].lecturer, "Dosen Pertama");
+    Ok(())
+}
+
+#[test]
+fn test_next_page_detection() {
+    assert_eq!(
+        parse_next_page_href(COURSES_PAGE_1_HTML).as_deref(),
+        Some("https://spot.upi.edu/mhs?page=2")
+    );
+    assert_eq!(parse_next_page_href(COURSES_PAGE_2_HTML), None);
+    assert_eq!(parse_next_page_href(COURSES_HTML), None);
+
+    let numbered = r#"<ul class="pagination">
+        <li class="active"><span>1</span></li><li><a href="?page=2">2</a></li></ul>"#;
+    assert_eq!(parse_next_page_href(numbered).as_deref(), Some("?page=2"));
+
+    let indicator = "<p>Halaman 2 dari 3</p>";
+    assert_eq!(parse_next_page_href(indicator).as_deref(), Some("?page=3"));
+    assert_eq!(parse_next_page_href("<p>Halaman 3 dari 3</p>"), None);
+}
+
+fn paginated_client(max_pages: usize) -> SpotifierCoreClient {
+    let mut transport = ReplayTransport::default();
+    for (url, body) in [
+        ("https://spot.upi.edu/mhs", COURSES_PAGE_1_HTML),
+        ("https://spot.upi.edu/mhs?page=2", COURSES_PAGE_2_HTML),
+    ] {
+        transport.insert(common::recording("GET", url, url, body));
+    }
+
+    common::builder()
+        .max_course_pages(max_pages)
+        .transport(Arc::new(transport))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_courses_follows_pagination() -> Result<()> {
+    let courses = paginated_client(10).get_courses().await?;
+    let codes: Vec<&str> = courses.iter().map(|c| c.code.as_str()).collect();
+    assert_eq!(codes, ["IK410", "IK300"]);
+
+    let capped = paginated_client(1).get_courses().await?;
+    assert_eq!(capped.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_course_list_is_tried_by_default() -> Result<()> {
+    // An HTML body where JSON is expected falls back to the dashboard.
+    let transport = MockTransport::new(|_| MockResponse::html(COURSES_HTML));
+    let courses = common::client_with(transport.clone()).get_courses().await?;
+
+    assert_eq!(courses.len(), 2);
+    let paths: Vec<String> = transport.requests().iter().map(|r| r.path()).collect();
+    assert_eq!(paths, ["/api/mhs/matakuliah", "/mhs"]);
+
+    let transport = MockTransport::new(|_| MockResponse::html(COURSES_HTML));
+    let client = common::builder()
+        .prefer_json(false)
+        .transport(transport.clone())
+        .build()?;
+    assert_eq!(client.get_courses().await?.len(), 2);
+    let paths: Vec<String> = transport.requests().iter().map(|r| r.path()).collect();
+    assert_eq!(paths, ["/mhs"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_course_list_is_given_up_only_when_missing() -> Result<()> {
+    // The JSON endpoint fails with a 503, then a 404, then would serve the list.
+    let json_calls = Arc::new(AtomicUsize::new(0));
+    let calls = Arc::clone(&json_calls);
+    let transport = MockTransport::new(move |request| {
+        if request.path() != "/api/mhs/matakuliah" {
+            return MockResponse::html(COURSES_HTML);
+        }
+        match calls.fetch_add(1, Ordering::SeqCst) {
+            0 => MockResponse::status(503),
+            1 => MockResponse::status(404),
+            _ => MockResponse::bytes(COURSES_JSON.as_bytes().to_vec()),
+        }
+    });
+    let client = common::builder().transport(transport.clone()).build()?;
+
+    for _ in 0..3 {
+        assert_eq!(client.get_courses().await?.len(), 2);
+    }
+    // The 503 is retried on the next call; the 404 disables the endpoint.
+    assert_eq!(json_calls.load(Ordering::SeqCst), 2);
+    let html_calls = transport
+        .requests()
+        .iter()
+        .filter(|r| r.path() == "/mhs")
+        .count();
+    assert_eq!(html_calls, 3);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_course_list_is_used_when_served() -> Result<()> {
+    let transport = MockTransport::new(|request| match request.path().as_str() {
+        "/api/mhs/matakuliah" => MockResponse::bytes(COURSES_JSON.as_bytes().to_vec())
+            .header("content-type", "application/json"),
+        _ => MockResponse::status(500),
+    });
+    let client = common::builder().transport(transport.clone()).build()?;
+
+    let courses = client.get_courses().await?;
+    assert_eq!(courses[0].code, "IK410");
+    assert_eq!(transport.requests().len(), 1);
+    Ok(())
+}
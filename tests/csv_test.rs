@@ -0,0 +1,77 @@
+// tests/csv_test.rs
+#![cfg(feature = "csv")]
+
+use chrono::NaiveDate;
+use spotifier_core::{
+    AttendanceRecord, AttendanceStatus, Grade, Period, Semester, Transcript, attendance_to_csv,
+    grades_to_csv,
+};
+
+fn grade(code: &str, name: &str, letter: &str, point: f32) -> Grade {
+    Grade {
+        course_code: code.to_string(),
+        course_name: name.to_string(),
+        credits: 3,
+        letter_grade: letter.to_string(),
+        grade_point: point,
+        period: Period::new(2025, Semester::Odd),
+    }
+}
+
+#[test]
+fn test_grades_to_csv_escapes_fields() {
+    let transcript = Transcript {
+        grades: vec![
+            grade("IK410", "Pemrograman Web", "A", 4.0),
+            grade("IK411", "Basis Data, Lanjut", "B+", 3.3),
+            grade("IK412", "Seminar \"Riset\"", "", 0.0),
+        ],
+    };
+
+    let csv = grades_to_csv(&transcript);
+    let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+    assert_eq!(
+        rows[0],
+        "course_code,course_name,credits,letter_grade,grade_point,period"
+    );
+    assert_eq!(rows.len(), transcript.grades.len() + 1);
+    assert_eq!(rows[1], "IK410,Pemrograman Web,3,A,4,2025/2026 - Ganjil");
+    assert_eq!(
+        rows[2],
+        "IK411,\"Basis Data, Lanjut\",3,B+,3.3,2025/2026 - Ganjil"
+    );
+    assert_eq!(
+        rows[3],
+        "IK412,\"Seminar \"\"Riset\"\"\",3,,0,2025/2026 - Ganjil"
+    );
+}
+
+#[test]
+fn test_attendance_to_csv_uses_indonesian_labels() {
+    let records = vec![
+        AttendanceRecord {
+            meeting_number: 1,
+            date: NaiveDate::from_ymd_opt(2025, 9, 1),
+            status: AttendanceStatus::Present,
+        },
+        AttendanceRecord {
+            meeting_number: 2,
+            date: None,
+            status: AttendanceStatus::Sick,
+        },
+    ];
+
+    let csv = attendance_to_csv(&records);
+    let rows: Vec<&str> = csv.split_terminator("\r\n").collect();
+
+    assert_eq!(
+        rows,
+        [
+            "meeting_number,date,status",
+            "1,2025-09-01,Hadir",
+            "2,,Sakit"
+        ]
+    );
+    assert_eq!(attendance_to_csv(&[]), "meeting_number,date,status\r\n");
+}
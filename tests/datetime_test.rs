@@ -0,0 +1,87 @@
+// tests/datetime_test.rs
+
+use chrono::{NaiveDate, NaiveDateTime};
+use spotifier_core::parsers::datetime::{parse_id_date, parse_id_datetime, parse_id_deadline};
+
+fn dt(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, m, d)
+        .unwrap()
+        .and_hms_opt(h, min, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_parse_id_datetime_formats() {
+    let expected = dt(2025, 2, 12, 14, 30);
+    for input in [
+        "12-02-2025 14:30",
+        "12/02/2025 14:30:00",
+        "2025-02-12 14:30",
+        "Senin, 12 Februari 2025 14:30",
+        "senin 12 februari 2025 14.30",
+        "12 Feb 2025, 14:30",
+        "Rabu, 12 Feb. 2025 pukul 14.30 WIB",
+        "  12 FEBRUARI 2025   14:30  ",
+        "Senin, 12-02-2025 14:30",
+    ] {
+        assert_eq!(parse_id_datetime(input), Some(expected), "{:?}", input);
+    }
+
+    // Without a time of day, midnight is assumed.
+    assert_eq!(
+        parse_id_datetime("Jum'at, 1 Agustus 2025"),
+        Some(dt(2025, 8, 1, 0, 0))
+    );
+    assert_eq!(
+        parse_id_datetime("3 Pebruari 2025 07:05:00"),
+        Some(dt(2025, 2, 3, 7, 5))
+    );
+}
+
+#[test]
+fn test_deadline_without_time_is_end_of_day() {
+    let end_of_day = NaiveDate::from_ymd_opt(2025, 8, 1)
+        .unwrap()
+        .and_hms_opt(23, 59, 59);
+    assert_eq!(parse_id_deadline("Jum'at, 1 Agustus 2025"), end_of_day);
+    assert_eq!(parse_id_deadline("01-08-2025"), end_of_day);
+
+    // An explicit time is kept as is.
+    assert_eq!(
+        parse_id_deadline("1 Agustus 2025 07:05"),
+        Some(dt(2025, 8, 1, 7, 5))
+    );
+    assert_eq!(parse_id_deadline("bukan tanggal"), None);
+}
+
+#[test]
+fn test_parse_id_date_ignores_time() {
+    let expected = NaiveDate::from_ymd_opt(2025, 9, 8);
+    for input in [
+        "08-09-2025",
+        "08/09/2025",
+        "2025-09-08",
+        "Senin, 8 September 2025",
+        "8 Sept 2025 10:00",
+    ] {
+        assert_eq!(parse_id_date(input), expected, "{:?}", input);
+    }
+}
+
+#[test]
+fn test_unrecognized_dates_are_none() {
+    for input in [
+        "",
+        "-",
+        "Belum ada",
+        "31 Februari 2025",
+        "12 Foo 2025",
+        "12 Februari",
+        "12 Februari 2025 25:00",
+        "12 Februari 2025 14:30 lalu",
+        "Senin",
+    ] {
+        assert_eq!(parse_id_datetime(input), None, "{:?}", input);
+        assert_eq!(parse_id_date(input), None, "{:?}", input);
+    }
+}
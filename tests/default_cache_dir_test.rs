@@ -0,0 +1,45 @@
+// tests/default_cache_dir_test.rs
+#![cfg(all(feature = "default-cache-dir", unix))]
+
+use spotifier_core::{FileCache, Result, SpotifierCoreClient};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Set in the child process that runs the actual assertions.
+const HOME_VAR: &str = "SPOTIFIER_TEST_HOME";
+
+/// Re-runs this test in a child process whose home and XDG cache directory are a temp
+/// dir, so no cache directory is created for the user running the tests.
+#[test]
+fn test_default_cache_location() -> Result<()> {
+    let Some(home) = std::env::var_os(HOME_VAR).map(PathBuf::from) else {
+        let home =
+            std::env::temp_dir().join(format!("spotifier-cache-home-{}", std::process::id()));
+        std::fs::create_dir_all(&home).unwrap();
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args(["--exact", "test_default_cache_location", "--test-threads=1"])
+            .env(HOME_VAR, &home)
+            .env("HOME", &home)
+            .env("XDG_CACHE_HOME", home.join(".cache"))
+            .output()
+            .unwrap();
+        std::fs::remove_dir_all(&home).ok();
+        assert!(
+            output.status.success(),
+            "The child test failed:\n{}",
+            String::from_utf8_lossy(&output.stdout)
+        );
+        return Ok(());
+    };
+
+    let cache = FileCache::default_location()?;
+
+    assert!(cache.cache_dir().starts_with(&home));
+    assert!(cache.cache_dir().ends_with("spotifier-core"));
+    assert!(cache.cache_dir().is_dir());
+
+    let mut client = SpotifierCoreClient::new();
+    client.set_default_file_cache()?;
+
+    Ok(())
+}
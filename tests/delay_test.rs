@@ -1,52 +1,326 @@
 // tests/delay_test.rs
 
-use dotenvy::from_path;
-use spotifier_core::{DelayConfig, Result, SpotifierCoreClient};
-use std::env;
-use std::path::PathBuf;
-use std::time::Instant;
+mod common;
 
-#[tokio::test]
-async fn test_human_like_delays() -> Result<()> {
-    let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
-    from_path(&env_path).ok();
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use spotifier_core::{
+    DelayAction, DelayConfig, DelayProfile, DelayRange, JitterDistribution, ReplayTransport,
+    Result, SpotifierCoreClient,
+};
+use std::sync::Arc;
+use std::time::Duration;
+
+const SSO_LOGIN_URL: &str = "https://sso.upi.edu/cas/login?service=https://spot.upi.edu/beranda";
+const CAS_LOGIN_HTML: &str = r#"<html><body><form id="fm1" method="post" action="/cas/login">
+  <input name="username" type="text"><input name="password" type="password">
+  <input type="hidden" name="execution" value="e1s1"></form></body></html>"#;
+const PROFILE_HTML: &str =
+    r#"<div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>"#;
+
+/// A client with `config` that can log in and load the profile.
+fn login_client(config: DelayConfig, seed: u64) -> Result<SpotifierCoreClient> {
+    let mut transport = ReplayTransport::default();
+    transport.insert(common::recording(
+        "GET",
+        SSO_LOGIN_URL,
+        SSO_LOGIN_URL,
+        CAS_LOGIN_HTML,
+    ));
+    transport.insert(common::recording(
+        "POST",
+        "https://sso.upi.edu/cas/login",
+        "https://spot.upi.edu/beranda",
+        PROFILE_HTML,
+    ));
+    transport.insert(common::recording(
+        "GET",
+        "https://spot.upi.edu/mhs",
+        "https://spot.upi.edu/mhs",
+        PROFILE_HTML,
+    ));
+    SpotifierCoreClient::builder()
+        .delay(config)
+        .rng_seed(seed)
+        .transport(Arc::new(transport))
+        .build()
+}
 
-    let nim = env::var("SPOT_NIM").expect("SPOT_NIM env var not set");
-    let password = env::var("SPOT_PASSWORD").expect("SPOT_PASSWORD env var not set");
+/// How long logging in and then loading the profile take, in virtual time.
+async fn timed_login(client: &SpotifierCoreClient) -> Result<(Duration, Duration)> {
+    let start = tokio::time::Instant::now();
+    client.login("2301234", "rahasia").await?;
+    let login = start.elapsed();
 
-    // Use a very short delay for testing to not waste too much time,
-    // but long enough to measure
+    let start = tokio::time::Instant::now();
+    client.get_user_profile().await?;
+    Ok((login, start.elapsed()))
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_human_like_delays() -> Result<()> {
     let config = DelayConfig {
         min_delay_ms: 500,
         max_delay_ms: 1000,
         enabled: true,
+        ..Default::default()
     };
 
-    let client = SpotifierCoreClient::with_config(config);
+    let (login, navigation) = timed_login(&login_client(config.clone(), 7)?).await?;
+    // Two SSO requests (GET and POST) of 0.5-1 s each, then a 2-5 s pause.
+    assert!(
+        (Duration::from_millis(3000)..=Duration::from_millis(7000)).contains(&login),
+        "Login took {:?}",
+        login
+    );
+    assert!((Duration::from_millis(500)..=Duration::from_millis(1000)).contains(&navigation));
 
-    let start = Instant::now();
-    println!("Logging in (expecting 2-5s jitter + base delay)...");
-    client.login(&nim, &password).await?;
-    let login_duration = start.elapsed();
-    println!("Login took {:?}", login_duration);
+    // The same seed sleeps for exactly the same durations.
+    assert_eq!(
+        timed_login(&login_client(config, 7)?).await?,
+        (login, navigation)
+    );
 
-    // Login has 2-5s jitter + 2 requests (SSO GET + SSO POST)
-    // Min time: 2s (jitter) + 2 * 0.5s (requests) = 3s
+    Ok(())
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_login_range_sets_the_pause_after_login() -> Result<()> {
+    let config = DelayConfig {
+        min_delay_ms: 500,
+        max_delay_ms: 500,
+        enabled: true,
+        profile: DelayProfile {
+            login: Some(DelayRange {
+                min_delay_ms: 3000,
+                max_delay_ms: 3000,
+            }),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    let (login, navigation) = timed_login(&login_client(config, 7)?).await?;
+    // Both SSO requests and the pause after them use the login range.
+    assert_eq!(login, Duration::from_millis(9000));
+    assert_eq!(navigation, Duration::from_millis(500));
+
+    Ok(())
+}
+
+fn mean_of_samples(config: &DelayConfig) -> f64 {
+    let mut rng = StdRng::seed_from_u64(42);
+    let samples: Vec<u64> = (0..10_000)
+        .map(|_| config.sample_delay_ms(&mut rng))
+        .collect();
     assert!(
-        login_duration.as_millis() >= 2500,
-        "Login should take at least 2.5s with delays enabled"
+        samples
+            .iter()
+            .all(|ms| (config.min_delay_ms..=config.max_delay_ms).contains(ms))
     );
+    samples.iter().sum::<u64>() as f64 / samples.len() as f64
+}
+
+#[test]
+fn test_jitter_distributions_stay_in_bounds() {
+    let uniform = DelayConfig {
+        min_delay_ms: 1000,
+        max_delay_ms: 3000,
+        ..Default::default()
+    };
+    assert_eq!(uniform.distribution, JitterDistribution::Uniform);
+    assert!((mean_of_samples(&uniform) - 2000.0).abs() < 50.0);
 
-    let start = Instant::now();
-    println!("Fetching courses (expecting 0.5-1s delay)...");
-    client.get_courses().await?;
-    let courses_duration = start.elapsed();
-    println!("Fetching courses took {:?}", courses_duration);
+    let normal = DelayConfig {
+        distribution: JitterDistribution::Normal {
+            mean_ms: 800.0,
+            stddev_ms: 200.0,
+        },
+        ..uniform.clone()
+    };
+    assert!((mean_of_samples(&normal) - 1800.0).abs() < 30.0);
 
-    assert!(
-        courses_duration.as_millis() >= 450,
-        "Request should take at least 450ms with 500ms min delay"
+    // Mean of 1000 + Exp(1/400) is 1400; the cap at 3000 barely moves it.
+    let exponential = DelayConfig {
+        distribution: JitterDistribution::Exponential {
+            lambda: 1.0 / 400.0,
+        },
+        ..uniform
+    };
+    assert!((mean_of_samples(&exponential) - 1400.0).abs() < 30.0);
+}
+
+#[test]
+fn test_delay_profile_applies_per_action_ranges() {
+    let config = DelayConfig {
+        min_delay_ms: 1000,
+        max_delay_ms: 3000,
+        profile: DelayProfile {
+            navigation: Some(DelayRange {
+                min_delay_ms: 100,
+                max_delay_ms: 300,
+            }),
+            submission: Some(DelayRange {
+                min_delay_ms: 5000,
+                max_delay_ms: 8000,
+            }),
+            login: None,
+        },
+        ..Default::default()
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for (action, range) in [
+        (DelayAction::Navigation, 100..=300),
+        (DelayAction::Submission, 5000..=8000),
+        // No login range: falls back to the base range.
+        (DelayAction::Login, 1000..=3000),
+    ] {
+        let samples: Vec<u64> = (0..1_000)
+            .map(|_| config.sample_delay_ms_for(action, &mut rng))
+            .collect();
+        assert!(
+            samples.iter().all(|ms| range.contains(ms)),
+            "{:?} delays must stay in {:?}",
+            action,
+            range
+        );
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        let midpoint = (*range.start() + *range.end()) as f64 / 2.0;
+        assert!((mean - midpoint).abs() < (range.end() - range.start()) as f64 * 0.05);
+    }
+
+    // The default profile delays every action like the base range.
+    let default = DelayConfig::default();
+    for action in [
+        DelayAction::Login,
+        DelayAction::Navigation,
+        DelayAction::Submission,
+    ] {
+        assert_eq!(
+            default.range_for(action),
+            DelayRange {
+                min_delay_ms: 1000,
+                max_delay_ms: 3000
+            }
+        );
+    }
+}
+
+#[test]
+fn test_normal_jitter_is_relative_to_each_range() {
+    let config = DelayConfig {
+        min_delay_ms: 1000,
+        max_delay_ms: 3000,
+        distribution: JitterDistribution::Normal {
+            mean_ms: 100.0,
+            stddev_ms: 30.0,
+        },
+        profile: DelayProfile {
+            navigation: Some(DelayRange {
+                min_delay_ms: 100,
+                max_delay_ms: 300,
+            }),
+            submission: Some(DelayRange {
+                min_delay_ms: 5000,
+                max_delay_ms: 8000,
+            }),
+            login: None,
+        },
+        ..Default::default()
+    };
+    let mut rng = StdRng::seed_from_u64(11);
+
+    for (action, range, expected_mean) in [
+        (DelayAction::Navigation, 100..=300, 200.0),
+        (DelayAction::Submission, 5000..=8000, 5100.0),
+        (DelayAction::Login, 1000..=3000, 1100.0),
+    ] {
+        let samples: Vec<u64> = (0..10_000)
+            .map(|_| config.sample_delay_ms_for(action, &mut rng))
+            .collect();
+        assert!(samples.iter().all(|ms| range.contains(ms)));
+        let mean = samples.iter().sum::<u64>() as f64 / samples.len() as f64;
+        assert!(
+            (mean - expected_mean).abs() < 5.0,
+            "{:?} delays should cluster around {}, got {}",
+            action,
+            expected_mean,
+            mean
+        );
+    }
+}
+
+#[test]
+fn test_compute_delay_is_deterministic_per_seed() {
+    let config = DelayConfig {
+        min_delay_ms: 100,
+        max_delay_ms: 900,
+        ..Default::default()
+    };
+    let delays = |seed| {
+        let mut rng = StdRng::seed_from_u64(seed);
+        (0..20)
+            .map(|_| config.compute_delay(DelayAction::Navigation, &mut rng))
+            .collect::<Vec<Duration>>()
+    };
+
+    let mut sequences = Vec::new();
+    for seed in [0, 1, 42, 2025, u64::MAX] {
+        let sequence = delays(seed);
+        assert_eq!(sequence, delays(seed), "Seed {} must replay exactly", seed);
+        assert!(
+            sequence
+                .iter()
+                .all(|d| (100..=900).contains(&(d.as_millis() as u64)))
+        );
+        sequences.push(sequence);
+    }
+    sequences.dedup();
+    assert_eq!(sequences.len(), 5, "Different seeds give different delays");
+
+    let disabled = DelayConfig {
+        enabled: false,
+        ..config
+    };
+    let mut rng = StdRng::seed_from_u64(42);
+    assert_eq!(
+        disabled.compute_delay(DelayAction::Navigation, &mut rng),
+        Duration::ZERO
     );
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_seeded_client_sleeps_exact_delays() -> Result<()> {
+    let url = "https://spot.upi.edu/mhs";
+    let mut transport = ReplayTransport::default();
+    transport.insert(common::recording(
+        "GET",
+        url,
+        url,
+        r#"<div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>"#,
+    ));
+    let config = DelayConfig {
+        min_delay_ms: 500,
+        max_delay_ms: 1500,
+        ..Default::default()
+    };
+    let client = SpotifierCoreClient::builder()
+        .delay(config.clone())
+        .rng_seed(2025)
+        .transport(Arc::new(transport))
+        .build()?;
+
+    let mut rng = StdRng::seed_from_u64(2025);
+    let expected: Duration = (0..3)
+        .map(|_| config.compute_delay(DelayAction::Navigation, &mut rng))
+        .sum();
+
+    let start = tokio::time::Instant::now();
+    for _ in 0..3 {
+        client.get_user_profile().await?;
+    }
+    assert_eq!(start.elapsed(), expected);
 
     Ok(())
 }
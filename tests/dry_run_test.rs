@@ -0,0 +1,104 @@
+// tests/dry_run_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use spotifier_core::{DryRunRequest, HttpTransport, Result, ScraperError, SpotifierCoreClient};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Fails the test if anything reaches the transport.
+#[derive(Default)]
+struct ForbiddenTransport {
+    requests: AtomicUsize,
+}
+
+#[async_trait]
+impl HttpTransport for ForbiddenTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        self.requests.fetch_add(1, Ordering::SeqCst);
+        panic!("Dry run sent {} {}", request.method(), request.url());
+    }
+}
+
+const CAS_LOGIN_HTML: &str = r#"<html><body>
+  <form id="fm1" method="post" action="/cas/login">
+    <input id="username" name="username" type="text">
+    <input id="password" name="password" type="password">
+    <input type="hidden" name="execution" value="e1s1">
+  </form>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_dry_run_without_fixture_sends_nothing() -> Result<()> {
+    let transport = Arc::new(ForbiddenTransport::default());
+    let client = SpotifierCoreClient::builder()
+        .transport(transport.clone())
+        .dry_run(true)
+        .build()?;
+
+    let started = std::time::Instant::now();
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::DryRun { method, url })
+            if method == "GET" && url == "https://spot.upi.edu/mhs"
+    ));
+    let submission = client
+        .submit_task(
+            1,
+            2,
+            3,
+            "csrf",
+            "Jawaban",
+            Some("laporan.pdf".to_string()),
+            Some(b"%PDF-1.4".to_vec()),
+        )
+        .await;
+    assert!(matches!(submission, Err(ScraperError::DryRun { .. })));
+    assert!(
+        started.elapsed().as_millis() < 500,
+        "Dry runs skip the request delays"
+    );
+
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 0);
+    let logged = client.dry_run_requests();
+    assert_eq!(logged.len(), 2);
+    assert_eq!(logged[1].method, "POST");
+    assert_eq!(logged[1].url, "https://spot.upi.edu/mhs/tugas_store");
+    assert!(
+        logged[1].form.is_empty(),
+        "Multipart bodies are not decoded"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dry_run_login_serves_fixture_and_redacts_password() -> Result<()> {
+    let transport = Arc::new(ForbiddenTransport::default());
+    let client = common::builder()
+        .transport(transport.clone())
+        .dry_run(true)
+        .dry_run_fixture(CAS_LOGIN_HTML)
+        .build()?;
+
+    // The fixture is the login page again, so the login cannot complete.
+    assert!(client.login("2301234", "rahasia").await.is_err());
+
+    assert_eq!(transport.requests.load(Ordering::SeqCst), 0);
+    let logged = client.dry_run_requests();
+    assert_eq!(logged.len(), 2);
+    assert_eq!(logged[0].method, "GET");
+    let post: &DryRunRequest = &logged[1];
+    assert_eq!(post.method, "POST");
+    assert!(post.url.starts_with("https://sso.upi.edu/cas/login"));
+    let field = |name: &str| {
+        post.form
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    assert_eq!(field("username"), Some("2301234"));
+    assert_eq!(field("password"), Some("<redacted>"));
+    assert_eq!(field("execution"), Some("e1s1"));
+    Ok(())
+}
@@ -0,0 +1,174 @@
+// tests/export_test.rs
+#![cfg(feature = "export")]
+
+use chrono::{NaiveTime, Weekday};
+use spotifier_core::{
+    Accessibility, Course, DetailCourse, Period, Rps, ScheduleEntry, Semester, TopicDetail,
+    TopicInfo, to_ics, to_json_pretty, to_ndjson, write_topics_ndjson,
+};
+
+fn sample_course(id: u64) -> Course {
+    Course {
+        id,
+        code: format!("IK{}", id),
+        name: "Mata Kuliah\nDengan Baris Baru".to_string(),
+        credits: 3,
+        lecturer: "Dosen".to_string(),
+        lecturers: vec!["Dosen".to_string()],
+        academic_year: "2025/2026 - Ganjil".to_string(),
+        href: format!("/mhs/topik/{}", id),
+    }
+}
+
+fn sample_topic(id: u64) -> TopicDetail {
+    TopicDetail {
+        id,
+        access_time: None,
+        is_accessible: true,
+        href: format!("/mhs/topik/1/{}", id),
+        description: Some("Topik\ndengan baris baru".to_string()),
+        contents: Vec::new(),
+        tasks: Vec::new(),
+        quizzes: Vec::new(),
+    }
+}
+
+#[test]
+fn test_courses_to_ndjson_one_object_per_line() {
+    let courses: Vec<Course> = (1..=3).map(sample_course).collect();
+    let ndjson = to_ndjson(&courses).unwrap();
+
+    let lines: Vec<&str> = ndjson.lines().collect();
+    assert_eq!(lines.len(), courses.len());
+    for (line, course) in lines.iter().zip(&courses) {
+        let parsed: Course = serde_json::from_str(line).expect("Each line must be valid JSON");
+        assert_eq!(parsed.id, course.id);
+    }
+}
+
+#[test]
+fn test_write_topics_ndjson_streams_each_topic() {
+    let topics: Vec<TopicDetail> = (10..15).map(sample_topic).collect();
+    let mut buffer = Vec::new();
+    write_topics_ndjson(&mut buffer, topics.iter()).unwrap();
+
+    let output = String::from_utf8(buffer).unwrap();
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), topics.len());
+    for line in lines {
+        serde_json::from_str::<serde_json::Value>(line).expect("Each line must be valid JSON");
+    }
+}
+
+#[test]
+fn test_detail_course_round_trips_through_json() {
+    let detail = DetailCourse {
+        course_info: sample_course(7),
+        description: "<p>Deskripsi</p>".to_string(),
+        rps: Rps {
+            id: Some(98765),
+            href: Some("/mhs/rps/98765".to_string()),
+        },
+        topics: vec![TopicInfo {
+            id: Some(1358801),
+            course_id: Some(7),
+            access_time: None,
+            updated_at: None,
+            is_accessible: true,
+            accessibility: Accessibility::Open,
+            href: Some("/mhs/topik/7/1358801".to_string()),
+        }],
+        lecturer_email: Some("dosen@upi.edu".to_string()),
+        lecturer_profile_href: None,
+    };
+
+    let json = to_json_pretty(&detail).unwrap();
+    // The flattened course fields sit next to the detail fields.
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["code"], "IK7");
+    assert!(value.get("course_info").is_none());
+
+    let parsed: DetailCourse = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed.course_info.id, 7);
+    assert_eq!(parsed.course_info.lecturers, ["Dosen"]);
+    assert_eq!(parsed.description, detail.description);
+    assert_eq!(parsed.rps.id, Some(98765));
+    assert_eq!(parsed.topics.len(), 1);
+    assert_eq!(parsed.lecturer_email.as_deref(), Some("dosen@upi.edu"));
+}
+
+#[test]
+fn test_to_ndjson_one_line_per_item() {
+    let topics: Vec<TopicDetail> = (1..=4).map(sample_topic).collect();
+    let ndjson = to_ndjson(&topics).unwrap();
+
+    assert!(ndjson.ends_with('\n'));
+    let lines: Vec<&str> = ndjson.lines().collect();
+    assert_eq!(lines.len(), topics.len());
+    for (line, topic) in lines.iter().zip(&topics) {
+        let parsed: TopicDetail = serde_json::from_str(line).unwrap();
+        assert_eq!(parsed.id, topic.id);
+    }
+    assert_eq!(to_ndjson::<Course>(&[]).unwrap(), "");
+}
+
+fn schedule_entry(code: &str, day: Weekday, start: (u32, u32), end: (u32, u32)) -> ScheduleEntry {
+    ScheduleEntry {
+        course_code: code.to_string(),
+        course_name: "Pemrograman Web, Lanjut".to_string(),
+        day_of_week: day,
+        start_time: NaiveTime::from_hms_opt(start.0, start.1, 0).unwrap(),
+        end_time: NaiveTime::from_hms_opt(end.0, end.1, 0).unwrap(),
+        room: "Lab; Gedung B".to_string(),
+        lecturer: "Dr. Budi Santoso".to_string(),
+    }
+}
+
+#[test]
+fn test_to_ics_is_well_formed() {
+    let entries = vec![
+        schedule_entry("IK410", Weekday::Mon, (8, 0), (10, 30)),
+        schedule_entry("IK411", Weekday::Thu, (13, 0), (15, 0)),
+    ];
+    let period = Period {
+        year: 2025,
+        semester: Semester::Odd,
+    };
+
+    let ics = to_ics(&entries, period);
+
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert!(!ics.replace("\r\n", "").contains('\n'));
+    let lines: Vec<&str> = ics.split_terminator("\r\n").collect();
+    assert!(lines.iter().all(|line| line.len() <= 75));
+    assert_eq!(lines.iter().filter(|l| **l == "BEGIN:VEVENT").count(), 2);
+    assert_eq!(lines.iter().filter(|l| **l == "END:VEVENT").count(), 2);
+
+    // 1 September 2025 is a Monday, so the first Thursday is the 4th.
+    assert!(lines.contains(&"DTSTART;TZID=Asia/Jakarta:20250901T080000"));
+    assert!(lines.contains(&"DTEND;TZID=Asia/Jakarta:20250901T103000"));
+    assert!(lines.contains(&"DTSTART;TZID=Asia/Jakarta:20250904T130000"));
+    assert!(lines.contains(&"RRULE:FREQ=WEEKLY;UNTIL=20260131T165959Z"));
+    assert!(lines.contains(&"SUMMARY:Pemrograman Web\\, Lanjut"));
+    assert!(lines.contains(&"LOCATION:Lab\\; Gedung B"));
+}
+
+#[test]
+fn test_period_date_range() {
+    let range = |semester| {
+        Period {
+            year: 2025,
+            semester,
+        }
+        .date_range()
+    };
+    let date = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+    assert_eq!(range(Semester::Odd), (date(2025, 9, 1), date(2026, 1, 31)));
+    assert_eq!(range(Semester::Even), (date(2026, 2, 1), date(2026, 6, 30)));
+    assert_eq!(
+        range(Semester::Short),
+        (date(2026, 7, 1), date(2026, 8, 31))
+    );
+}
@@ -0,0 +1,74 @@
+// tests/grade_test.rs
+
+use spotifier_core::parsers::grade::parse_grades_from_html;
+use spotifier_core::{Period, Result, ScraperError, Semester};
+
+const KHS_HTML: &str = r#"
+<html><body>
+  <div class="panel panel-default">
+    <div class="panel-heading">Semester: 2024/2025 - Genap</div>
+    <div class="panel-body">
+      <table class="table">
+        <thead>
+          <tr><th>No</th><th>Kode</th><th>Mata Kuliah</th><th>SKS</th><th>Nilai</th><th>Bobot</th></tr>
+        </thead>
+        <tbody>
+          <tr><td>1</td><td>IK310</td><td>Basis Data</td><td>3</td><td>A</td><td>4,00</td></tr>
+          <tr><td>2</td><td>IK320</td><td>Jaringan Komputer</td><td>2</td><td>B+</td><td>3,30</td></tr>
+        </tbody>
+      </table>
+    </div>
+  </div>
+  <div class="panel panel-default">
+    <div class="panel-heading">2025/2026 - Ganjil</div>
+    <div class="panel-body">
+      <table class="table">
+        <thead>
+          <tr><th>No</th><th>Kode</th><th>Mata Kuliah</th><th>SKS</th><th>Huruf</th></tr>
+        </thead>
+        <tbody>
+          <tr><td>1</td><td>IK410</td><td>Pemrograman Web</td><td>3</td><td>B</td></tr>
+          <tr><td>2</td><td>IK420</td><td>Kecerdasan Buatan</td><td>3</td><td></td></tr>
+        </tbody>
+      </table>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_parse_grades_across_periods() -> Result<()> {
+    let transcript = parse_grades_from_html(KHS_HTML)?;
+    assert_eq!(transcript.grades.len(), 4);
+
+    let first = &transcript.grades[0];
+    assert_eq!(first.course_code, "IK310");
+    assert_eq!(first.course_name, "Basis Data");
+    assert_eq!(first.credits, 3);
+    assert_eq!(first.letter_grade, "A");
+    assert_eq!(first.grade_point, 4.0);
+    assert_eq!(first.period, Period::new(2024, Semester::Even));
+
+    // Without a "Bobot" column the point comes from the letter.
+    let web = &transcript.grades[2];
+    assert_eq!(web.grade_point, 3.0);
+    assert_eq!(web.period, Period::new(2025, Semester::Odd));
+
+    let ungraded = &transcript.grades[3];
+    assert!(!ungraded.is_graded());
+    assert_eq!(ungraded.grade_point, 0.0);
+
+    // (3 * 4.0 + 2 * 3.3 + 3 * 3.0) / 8; the ungraded course is ignored.
+    let gpa = transcript.gpa().expect("graded courses");
+    assert!((gpa - 3.45).abs() < 1e-4, "gpa was {}", gpa);
+
+    Ok(())
+}
+
+#[test]
+fn test_grades_without_table() {
+    assert!(matches!(
+        parse_grades_from_html("<html><body><p>Belum ada KHS</p></body></html>"),
+        Err(ScraperError::SelectorMissing { .. })
+    ));
+}
@@ -0,0 +1,105 @@
+// tests/health_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{HealthStatus, HttpTransport, Result, ScraperError};
+use std::sync::Arc;
+use std::time::Duration;
+
+const PROFILE_HTML: &str = r#"<html><body>
+  <div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>
+</body></html>"#;
+
+const PROFILE_WITH_COURSES_HTML: &str = r#"<html><body>
+  <div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>
+  <table><tbody><tr>
+    <td>IK410</td><td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+    <td>3</td><td>Dosen Pengampu</td><td>2025/2026 - Ganjil</td>
+  </tr></tbody></table>
+</body></html>"#;
+
+const CAS_LOGIN_HTML: &str = include_str!("fixtures/cas_login.html");
+
+/// Answers `/mhs` with a fixed status and body, or times out when `status` is `None`.
+struct DashboardTransport {
+    status: Option<u16>,
+    body: &'static str,
+}
+
+#[async_trait]
+impl HttpTransport for DashboardTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        assert_eq!(request.url().path(), "/mhs");
+        let Some(status) = self.status else {
+            return Err(ScraperError::Timeout {
+                url: request.url().to_string(),
+                elapsed: Duration::from_secs(30),
+            });
+        };
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(status)
+            .body(self.body)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+async fn check(status: Option<u16>, body: &'static str) -> Result<HealthStatus> {
+    let client = common::builder()
+        .transport(Arc::new(DashboardTransport { status, body }))
+        .build()?;
+    client.health_check().await
+}
+
+#[tokio::test]
+async fn test_health_check_classifies_dashboard() -> Result<()> {
+    let unreachable = HealthStatus {
+        reachable: false,
+        authenticated: false,
+        active_period: None,
+    };
+    assert_eq!(check(None, "").await?, unreachable);
+    assert_eq!(check(Some(503), "Service Unavailable").await?, unreachable);
+
+    let logged_out = check(Some(200), CAS_LOGIN_HTML).await?;
+    assert!(logged_out.reachable);
+    assert!(!logged_out.authenticated);
+
+    let logged_in = check(Some(200), PROFILE_HTML).await?;
+    assert!(logged_in.reachable);
+    assert!(logged_in.authenticated);
+    assert_eq!(logged_in.active_period, None);
+
+    let with_courses = check(Some(200), PROFILE_WITH_COURSES_HTML).await?;
+    assert!(with_courses.authenticated);
+    assert_eq!(
+        with_courses.active_period.as_deref(),
+        Some("2025/2026 - Ganjil")
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_health_check_counts_a_stalled_page_as_unreachable() -> Result<()> {
+    let server = common::TestServer::bind().await;
+    let base_url = server.url();
+    server.serve(|_| {
+        common::Reply::now(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\ncontent-length: 100\r\n\r\n".to_string(),
+        )
+        .then_after(Duration::from_secs(5), "<html></html>")
+    });
+    let client = common::builder()
+        .base_url(&base_url)
+        .timeout(Duration::from_millis(300))
+        .build()?;
+
+    let status = client.health_check().await?;
+    assert!(!status.reachable);
+    assert!(!status.authenticated);
+    Ok(())
+}
@@ -1,8 +1,7 @@
 // tests/integration_test.rs
 
 use dotenvy::from_path;
-use spotifier_core::{Result, SpotifierCoreClient};
-use std::env;
+use spotifier_core::{Credentials, Result, SpotifierCoreClient};
 use std::fs::File;
 use std::io::Write;
 use std::path::PathBuf;
@@ -26,9 +25,8 @@ async fn test_full_login_and_scrape_flow() -> Result<()> {
     let mut log_file = File::create("output/test_output.log").expect("Could not create log file.");
 
     // --- SETUP: Load credentials from environment variables ---
-    let nim = env::var("SPOT_NIM").expect("ERROR: SPOT_NIM environment variable not set.");
-    let password =
-        env::var("SPOT_PASSWORD").expect("ERROR: SPOT_PASSWORD environment variable not set.");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
+    let nim = credentials.nim.clone();
 
     writeln!(log_file, "--- Starting Full Scraper Integration Test ---").unwrap();
 
@@ -36,7 +34,7 @@ async fn test_full_login_and_scrape_flow() -> Result<()> {
 
     // --- STEP 1: Login ---
     writeln!(log_file, "\n[1/5] Attempting login with NIM: {}...", nim).unwrap();
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
     writeln!(log_file, "[1/5] Login successful!").unwrap();
 
     // --- STEP 2: Fetch User Profile ---
@@ -120,12 +118,10 @@ async fn test_get_course_detail_by_id() -> Result<()> {
     let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
     from_path(&env_path).ok();
 
-    let nim = env::var("SPOT_NIM").expect("ERROR: SPOT_NIM environment variable not set.");
-    let password =
-        env::var("SPOT_PASSWORD").expect("ERROR: SPOT_PASSWORD environment variable not set.");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     let client = SpotifierCoreClient::new();
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
 
     // First get the courses to find a valid ID
     let courses = client.get_courses().await?;
@@ -160,12 +156,10 @@ async fn test_get_topic_detail_by_id() -> Result<()> {
     let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
     from_path(&env_path).ok();
 
-    let nim = env::var("SPOT_NIM").expect("ERROR: SPOT_NIM environment variable not set.");
-    let password =
-        env::var("SPOT_PASSWORD").expect("ERROR: SPOT_PASSWORD environment variable not set.");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     let client = SpotifierCoreClient::new();
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
 
     // Get courses and find one with accessible topics
     let courses = client.get_courses().await?;
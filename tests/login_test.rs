@@ -0,0 +1,628 @@
+// tests/login_test.rs
+
+mod common;
+
+use common::{
+    MockResponse, MockTransport, Reply, TestServer, http_response, recording, replay_client,
+};
+use spotifier_core::parsers::login::{
+    is_password_change_page, is_session_expired_page, parse_cas_error, parse_otp_challenge,
+};
+use spotifier_core::parsers::sso::{parse_cas_login_form, parse_execution_token};
+use spotifier_core::{AutoReloginConfig, Credentials, ScraperError, SpotifierCoreClient};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+const PASSWORD_CHANGE_HTML: &str = r#"
+<html>
+<head><title>SSO UPI</title></head>
+<body>
+  <div class="alert alert-warning">Password Anda telah kedaluwarsa. Silakan ganti password.</div>
+  <form method="post">
+    <input type="password" name="password_lama">
+    <input type="password" name="password_baru">
+    <input type="password" name="confirm_password">
+    <button type="submit">Simpan</button>
+  </form>
+</body>
+</html>
+"#;
+
+const LOGIN_FAILED_HTML: &str = r#"
+<html>
+<head><title>SSO UPI</title></head>
+<body>
+  <div class="alert alert-danger">Username atau password salah.</div>
+  <form method="post">
+    <input type="text" name="username">
+    <input type="password" name="password">
+    <input type="hidden" name="execution" value="e1s1">
+  </form>
+</body>
+</html>
+"#;
+
+#[test]
+fn test_detects_password_change_interstitial() {
+    assert!(is_password_change_page(
+        "https://sso.upi.edu/cas/login",
+        PASSWORD_CHANGE_HTML
+    ));
+    assert!(is_password_change_page(
+        "https://sso.upi.edu/gantipassword",
+        ""
+    ));
+}
+
+#[test]
+fn test_wrong_password_is_not_password_change() {
+    assert!(!is_password_change_page(
+        "https://sso.upi.edu/cas/login",
+        LOGIN_FAILED_HTML
+    ));
+}
+
+#[tokio::test]
+async fn test_login_reports_forced_password_change() {
+    for (landing_url, body) in [
+        (
+            "https://sso.upi.edu/gantipassword",
+            "<html><body>Ganti</body></html>",
+        ),
+        ("https://sso.upi.edu/cas/login", PASSWORD_CHANGE_HTML),
+    ] {
+        let transport = MockTransport::new(move |request| match request.method.as_str() {
+            "GET" => MockResponse::html(CAS_LOGIN_HTML),
+            _ => MockResponse::html(body).at(landing_url),
+        });
+        let client = common::client_with(transport.clone());
+
+        assert!(matches!(
+            client.login("2301234", "rahasia").await,
+            Err(ScraperError::PasswordChangeRequired)
+        ));
+        assert_eq!(transport.requests().len(), 2);
+    }
+}
+
+const OTP_HTML: &str = r#"
+<html>
+<head><title>SSO UPI</title></head>
+<body>
+  <h3>Verifikasi Dua Langkah</h3>
+  <form method="post" action="/cas/login?service=https%3A%2F%2Fspot.upi.edu%2Fberanda">
+    <label for="token">Masukkan kode OTP dari aplikasi authenticator</label>
+    <input type="text" id="token" name="token" autocomplete="one-time-code">
+    <input type="hidden" name="execution" value="e1s2">
+    <input type="hidden" name="_eventId" value="submit">
+    <button type="submit">Verifikasi</button>
+  </form>
+</body>
+</html>
+"#;
+
+#[test]
+fn test_detects_otp_challenge() {
+    let form = parse_otp_challenge("https://sso.upi.edu/cas/login", OTP_HTML)
+        .expect("OTP page should be detected");
+
+    assert_eq!(form.code_field, "token");
+    assert_eq!(
+        form.action.as_deref(),
+        Some("/cas/login?service=https%3A%2F%2Fspot.upi.edu%2Fberanda")
+    );
+    assert_eq!(
+        form.hidden_fields,
+        vec![
+            ("execution".to_string(), "e1s2".to_string()),
+            ("_eventId".to_string(), "submit".to_string()),
+        ]
+    );
+
+    assert!(parse_otp_challenge("https://sso.upi.edu/cas/login", LOGIN_FAILED_HTML).is_none());
+    assert!(parse_otp_challenge("https://sso.upi.edu/cas/login", PASSWORD_CHANGE_HTML).is_none());
+}
+
+#[tokio::test]
+async fn test_submit_otp_without_challenge() {
+    let client = SpotifierCoreClient::new();
+    assert!(matches!(
+        client.submit_otp(" ").await,
+        Err(ScraperError::MissingCredentials("OTP code"))
+    ));
+    assert!(matches!(
+        client.submit_otp("123456").await,
+        Err(ScraperError::ElementNotFound(_))
+    ));
+}
+
+const CAS_LEGACY_ERROR_HTML: &str = r#"
+<html><body>
+  <form id="fm1" method="post">
+    <div id="msg" class="errors">
+      Invalid   credentials.
+    </div>
+    <input type="text" name="username">
+  </form>
+</body></html>
+"#;
+
+/// A synthetic page laid out like the SSO UPI login page; see the note in the fixture.
+const CAS_LOGIN_FIXTURE: &str = include_str!("fixtures/cas_login.html");
+
+#[test]
+fn test_parse_cas_login_form_from_fixture() {
+    let form = parse_cas_login_form(CAS_LOGIN_FIXTURE).unwrap();
+    assert_eq!(
+        form.execution,
+        "b7a1c3e2-5f4d-4e8a-9c61-0d2f3e4a5b6c_ZXlKaGJHY2lPaUpJVXpVeE1pSjkuYTNkM2QyMmQ="
+    );
+    assert_eq!(
+        form.action.as_deref(),
+        Some("login?service=https%3A%2F%2Fspot.upi.edu%2Fberanda")
+    );
+    assert_eq!(
+        form.hidden_fields,
+        vec![
+            ("execution".to_string(), form.execution.clone()),
+            ("_eventId".to_string(), "submit".to_string()),
+            ("geolocation".to_string(), String::new()),
+        ]
+    );
+    assert_eq!(
+        parse_execution_token(CAS_LOGIN_FIXTURE).unwrap(),
+        form.execution
+    );
+}
+
+#[test]
+fn test_mangled_cas_page_has_no_execution_token() {
+    let without_token = CAS_LOGIN_FIXTURE.replace("name=\"execution\"", "name=\"exec\"");
+    assert!(matches!(
+        parse_execution_token(&without_token),
+        Err(ScraperError::TokenNotFound)
+    ));
+
+    let empty_token = r#"<form><input type="hidden" name="execution" value=""></form>"#;
+    assert!(matches!(
+        parse_cas_login_form(empty_token),
+        Err(ScraperError::TokenNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_login_posts_every_field_to_the_form_action() {
+    let transport = MockTransport::new(|request| {
+        if request.method == "POST" {
+            return MockResponse::html(PROFILE_HTML).at("https://spot.upi.edu/beranda");
+        }
+        MockResponse::html(CAS_LOGIN_FIXTURE)
+    });
+    let client = common::client_with(transport.clone());
+
+    client.login("2301234", "rahasia").await.expect("login");
+
+    let post = transport.last_request();
+    assert_eq!(post.method, "POST");
+    assert_eq!(
+        post.url,
+        "https://sso.upi.edu/cas/login?service=https%3A%2F%2Fspot.upi.edu%2Fberanda"
+    );
+    let form: HashMap<String, String> = url::form_urlencoded::parse(&post.body)
+        .into_owned()
+        .collect();
+    let execution = parse_execution_token(CAS_LOGIN_FIXTURE).unwrap();
+    assert_eq!(form.get("execution"), Some(&execution));
+    assert_eq!(form.get("_eventId").map(String::as_str), Some("submit"));
+    assert_eq!(form.get("geolocation").map(String::as_str), Some(""));
+    assert_eq!(form.get("username").map(String::as_str), Some("2301234"));
+    assert_eq!(form.get("password").map(String::as_str), Some("rahasia"));
+}
+
+#[tokio::test]
+async fn test_login_form_posting_elsewhere_is_refused() {
+    let page = CAS_LOGIN_FIXTURE.replace(
+        "action=\"login?service=https%3A%2F%2Fspot.upi.edu%2Fberanda\"",
+        "action=\"https://evil.example/collect\"",
+    );
+    let transport = MockTransport::new(move |_| MockResponse::html(&page));
+    let client = common::client_with(transport.clone());
+
+    assert!(matches!(
+        client.login("2301234", "rahasia").await,
+        Err(ScraperError::ParsingError(_))
+    ));
+    assert!(transport.requests().iter().all(|r| r.method == "GET"));
+}
+
+#[test]
+fn test_parse_cas_error_banner() {
+    assert_eq!(
+        parse_cas_error(LOGIN_FAILED_HTML).as_deref(),
+        Some("Username atau password salah.")
+    );
+    assert_eq!(
+        parse_cas_error(CAS_LEGACY_ERROR_HTML).as_deref(),
+        Some("Invalid credentials.")
+    );
+    assert_eq!(parse_cas_error(PASSWORD_CHANGE_HTML), None);
+    assert_eq!(parse_cas_error(OTP_HTML), None);
+}
+
+#[test]
+fn test_sso_outage_is_retryable() {
+    let err = ScraperError::SsoUnavailable { status: 503 };
+    assert!(err.is_retryable());
+    assert_eq!(err.to_string(), "SSO is unavailable (HTTP 503)");
+    assert!(!ScraperError::InvalidCredentials("salah".to_string()).is_retryable());
+}
+
+const PROFILE_HTML: &str = r#"
+<html><body>
+  <div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>
+</body></html>
+"#;
+
+const CAS_LOGIN_HTML: &str = r#"
+<html><body>
+  <form id="fm1" method="post" action="/cas/login">
+    <input id="username" name="username" type="text">
+    <input id="password" name="password" type="password">
+    <input type="hidden" name="execution" value="e1s1">
+  </form>
+</body></html>
+"#;
+
+/// A client that answers `GET https://spot.upi.edu/mhs` with the given final URL and body.
+fn client_serving_dashboard(final_url: &str, body: &str) -> SpotifierCoreClient {
+    replay_client(vec![recording(
+        "GET",
+        "https://spot.upi.edu/mhs",
+        final_url,
+        body,
+    )])
+}
+
+#[tokio::test]
+async fn test_redirect_within_spot_is_not_expiry() {
+    let client = client_serving_dashboard("https://spot.upi.edu/mhs/beranda/", PROFILE_HTML);
+    let user = client
+        .get_user_profile()
+        .await
+        .expect("profile after redirect");
+    assert_eq!(user.nim, "2301234");
+}
+
+#[tokio::test]
+async fn test_login_page_served_in_place_is_expiry() {
+    let client = client_serving_dashboard("https://spot.upi.edu/mhs", CAS_LOGIN_HTML);
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+
+    let client = client_serving_dashboard(
+        "https://sso.upi.edu/cas/login?service=https://spot.upi.edu/mhs",
+        "<html><body></body></html>",
+    );
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+}
+
+#[test]
+fn test_only_the_cas_form_means_expiry() {
+    assert!(is_session_expired_page(CAS_LOGIN_HTML));
+    assert!(!is_session_expired_page(PROFILE_HTML));
+
+    // Ordinary pages that talk about logging in are not the login form.
+    let banner = r#"<html><body><div class="alert alert-warning">Sesi Anda telah berakhir, silakan login kembali.</div></body></html>"#;
+    assert!(!is_session_expired_page(banner));
+    let heading = r#"<html><head><title>Panduan login SSO</title></head>
+        <body><h2>Session expired? Silakan login kembali</h2></body></html>"#;
+    assert!(!is_session_expired_page(heading));
+    let change_password = r#"<form><input type="password" name="password"></form>"#;
+    assert!(!is_session_expired_page(change_password));
+}
+
+const SSO_LOGIN_URL: &str = "https://sso.upi.edu/cas/login?service=https://spot.upi.edu/beranda";
+/// Where [`CAS_LOGIN_HTML`]'s form posts to.
+const SSO_FORM_ACTION_URL: &str = "https://sso.upi.edu/cas/login";
+
+/// The SSO exchanges of a successful login, followed by the given `/mhs` responses.
+fn client_with_login_and_dashboard(dashboard_pages: &[&str]) -> SpotifierCoreClient {
+    let mut recordings = vec![
+        recording("GET", SSO_LOGIN_URL, SSO_LOGIN_URL, CAS_LOGIN_HTML),
+        recording(
+            "POST",
+            SSO_FORM_ACTION_URL,
+            "https://spot.upi.edu/beranda",
+            PROFILE_HTML,
+        ),
+    ];
+    for page in dashboard_pages {
+        recordings.push(recording(
+            "GET",
+            "https://spot.upi.edu/mhs",
+            "https://spot.upi.edu/mhs",
+            page,
+        ));
+    }
+    replay_client(recordings)
+}
+
+#[tokio::test]
+async fn test_auto_relogin_replays_request() {
+    let mut client = client_with_login_and_dashboard(&[CAS_LOGIN_HTML, PROFILE_HTML]);
+    client.set_auto_relogin(AutoReloginConfig::RememberLogin);
+    client
+        .login("2301234", "rahasia")
+        .await
+        .expect("initial login");
+
+    let user = client
+        .get_user_profile()
+        .await
+        .expect("profile after re-login");
+    assert_eq!(user.name, "Budi Santoso");
+}
+
+#[tokio::test]
+async fn test_remember_login_enabled_after_login() {
+    let mut client = client_with_login_and_dashboard(&[CAS_LOGIN_HTML, PROFILE_HTML]);
+    client
+        .login("2301234", "rahasia")
+        .await
+        .expect("initial login");
+    client.set_auto_relogin(AutoReloginConfig::RememberLogin);
+
+    let user = client
+        .get_user_profile()
+        .await
+        .expect("profile after re-login");
+    assert_eq!(user.name, "Budi Santoso");
+}
+
+#[tokio::test]
+async fn test_auto_relogin_only_retries_once() {
+    let calls = Arc::new(AtomicU32::new(0));
+    let counter = Arc::clone(&calls);
+
+    let mut client = client_with_login_and_dashboard(&[CAS_LOGIN_HTML]);
+    client.set_auto_relogin(AutoReloginConfig::Provider(Arc::new(move || {
+        counter.fetch_add(1, Ordering::SeqCst);
+        Some(("2301234".to_string(), "rahasia".to_string()))
+    })));
+
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+/// SSO and a dashboard that shows the login form until a login POST went through.
+fn expiring_session_transport() -> Arc<MockTransport> {
+    let logged_in = Arc::new(std::sync::atomic::AtomicBool::new(false));
+    MockTransport::new(move |request| {
+        if request.url.starts_with("https://sso.upi.edu") {
+            if request.method == "POST" {
+                logged_in.store(true, Ordering::SeqCst);
+                return MockResponse::html(PROFILE_HTML)
+                    .at("https://spot.upi.edu/beranda")
+                    .delayed(std::time::Duration::from_millis(100));
+            }
+            return MockResponse::html(CAS_LOGIN_HTML);
+        }
+        // Slow enough that every concurrent request sees the expired session.
+        let page = if logged_in.load(Ordering::SeqCst) {
+            PROFILE_HTML
+        } else {
+            CAS_LOGIN_HTML
+        };
+        MockResponse::html(page).delayed(std::time::Duration::from_millis(20))
+    })
+}
+
+#[tokio::test]
+async fn test_concurrent_expiries_log_in_once() {
+    let transport = expiring_session_transport();
+    let client = common::builder()
+        .transport(transport.clone())
+        .auto_relogin(AutoReloginConfig::Provider(Arc::new(|| {
+            Some(("2301234".to_string(), "rahasia".to_string()))
+        })))
+        .build()
+        .unwrap();
+
+    let (a, b, c) = tokio::join!(
+        client.get_user_profile(),
+        client.get_user_profile(),
+        client.get_user_profile()
+    );
+    for result in [a, b, c] {
+        assert_eq!(result.expect("profile after re-login").nim, "2301234");
+    }
+    let logins = transport
+        .requests()
+        .iter()
+        .filter(|r| r.method == "POST")
+        .count();
+    assert_eq!(logins, 1);
+}
+
+#[tokio::test]
+async fn test_remember_login_without_login_gives_up() {
+    let mut client = client_with_login_and_dashboard(&[CAS_LOGIN_HTML]);
+    client.set_auto_relogin(AutoReloginConfig::RememberLogin);
+
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+}
+
+#[tokio::test]
+async fn test_login_with_credentials_from_env() {
+    let only_nim = |key: &str| (key == "SPOT_NIM").then(|| " 2301234 ".to_string());
+    assert!(matches!(
+        Credentials::from_env_with(only_nim),
+        Err(ScraperError::MissingCredentials("SPOT_PASSWORD"))
+    ));
+
+    let credentials = Credentials::from_env_with(|key| match key {
+        "SPOT_NIM" => Some(" 2301234 ".to_string()),
+        "SPOT_PASSWORD" => Some("rahasia".to_string()),
+        _ => None,
+    })
+    .expect("credentials from env");
+    assert_eq!(credentials.nim, "2301234");
+    assert_eq!(credentials.password(), "rahasia");
+    assert!(!format!("{:?}", credentials).contains("rahasia"));
+
+    let client = client_with_login_and_dashboard(&[PROFILE_HTML]);
+    client.login_with(&credentials).await.expect("login");
+    let user = client.get_user_profile().await.expect("profile");
+    assert_eq!(user.nim, "2301234");
+}
+
+#[tokio::test]
+async fn test_logout_clears_session() {
+    let client = replay_client(vec![
+        recording(
+            "GET",
+            "https://sso.upi.edu/cas/logout",
+            "https://sso.upi.edu/cas/logout",
+            "<html><body>Logout successful</body></html>",
+        ),
+        recording(
+            "GET",
+            "https://spot.upi.edu/mhs",
+            SSO_LOGIN_URL,
+            CAS_LOGIN_HTML,
+        ),
+    ]);
+
+    let path = std::env::temp_dir().join(format!("spotifier-logout-{}.json", std::process::id()));
+    std::fs::write(
+        &path,
+        r#"{"spot": "laravel_session=abc123", "sso": "TGC=TGT-1-xyz"}"#,
+    )
+    .unwrap();
+    client.load_cookies(&path).await.unwrap();
+
+    client.logout().await.expect("logout");
+
+    client.save_cookies(&path).await.unwrap();
+    let saved: HashMap<String, String> =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert!(saved.is_empty(), "cookies left after logout: {:?}", saved);
+
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+}
+
+#[tokio::test]
+async fn test_logout_without_session_is_noop() {
+    // No recordings: any request would fail.
+    let client = replay_client(Vec::new());
+    client.logout().await.expect("logout without a session");
+}
+
+/// A local SPOT and SSO in one server: SPOT answers as `127.0.0.1`, SSO as `localhost`.
+///
+/// Logging in sets `TGC` for SSO and `laravel_session` for SPOT; `/mhs` only serves the
+/// profile to requests carrying that session cookie. Returns the SPOT and SSO URLs.
+async fn session_server() -> (String, String) {
+    let server = TestServer::bind().await;
+    let spot_url = server.url();
+    let sso_url = format!("http://localhost:{}", server.port());
+
+    let (spot, sso) = (spot_url.clone(), sso_url.clone());
+    server.serve(move |request| {
+        let has_session = request.lines().any(|line| {
+            line.to_ascii_lowercase().starts_with("cookie:")
+                && line.contains("laravel_session=s3cret")
+        });
+        let html = "content-type: text/html\r\n";
+        let response = match request.split_whitespace().take(2).collect::<Vec<_>>()[..] {
+            ["GET", path] if path.starts_with("/cas/login") => {
+                http_response("200 OK", html, CAS_LOGIN_HTML)
+            }
+            ["POST", path] if path.starts_with("/cas/login") => http_response(
+                "302 Found",
+                &format!(
+                    "location: {}/beranda\r\nset-cookie: TGC=TGT-1; Path=/cas\r\n",
+                    spot
+                ),
+                "",
+            ),
+            ["GET", "/beranda"] => http_response(
+                "200 OK",
+                &format!("{}set-cookie: laravel_session=s3cret; Path=/\r\n", html),
+                PROFILE_HTML,
+            ),
+            ["GET", "/mhs"] if has_session => http_response("200 OK", html, PROFILE_HTML),
+            _ => http_response(
+                "302 Found",
+                &format!("location: {}/cas/login?service={}/mhs\r\n", sso, spot),
+                "",
+            ),
+        };
+        Reply::now(response)
+    });
+
+    (spot_url, sso_url)
+}
+
+fn session_client(spot_url: &str, sso_url: &str) -> SpotifierCoreClient {
+    common::builder()
+        .base_url(spot_url)
+        .sso_url(sso_url)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_exported_cookies_restore_session_in_fresh_client() {
+    let (spot_url, sso_url) = session_server().await;
+
+    let client = session_client(&spot_url, &sso_url);
+    client.login("2301234", "rahasia").await.expect("login");
+    let mut cookies = client.export_cookies();
+    cookies.sort();
+    assert_eq!(
+        cookies,
+        vec![
+            (
+                "127.0.0.1".to_string(),
+                "laravel_session=s3cret".to_string()
+            ),
+            ("localhost".to_string(), "TGC=TGT-1".to_string()),
+        ]
+    );
+
+    // The cookie file also picks up the TGC scoped to `/cas`.
+    let path = std::env::temp_dir().join(format!("spotifier-tgc-{}.json", std::process::id()));
+    client.save_cookies(&path).await.unwrap();
+    let saved: HashMap<String, String> =
+        serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).ok();
+    assert_eq!(saved["sso"], "TGC=TGT-1");
+
+    let fresh = session_client(&spot_url, &sso_url);
+    assert!(matches!(
+        fresh.get_user_profile().await,
+        Err(ScraperError::SessionExpired)
+    ));
+
+    let restored = session_client(&spot_url, &sso_url);
+    restored.import_cookies(&cookies);
+    let user = restored.get_user_profile().await.expect("profile");
+    assert_eq!(user.nim, "2301234");
+}
@@ -0,0 +1,110 @@
+// tests/markdown_test.rs
+#![cfg(feature = "markdown")]
+
+use spotifier_core::Content;
+
+fn content(raw_html: &str, youtube_id: Option<&str>) -> Content {
+    Content {
+        id: 0,
+        youtube_id: youtube_id.map(String::from),
+        youtube_start_secs: None,
+        raw_html: raw_html.to_string(),
+        page_href: String::new(),
+    }
+}
+
+#[test]
+fn test_reading_material_to_markdown() {
+    let html = r#"
+        <h3>Pengantar HTML</h3>
+        <p>Baca materi <strong>berikut</strong> dengan <em>teliti</em>:</p>
+        <ul>
+            <li>Struktur dokumen</li>
+            <li>Elemen dasar</li>
+        </ul>
+        <p>Unduh <a href="https://spot.upi.edu/files/materi1.pdf">materi1.pdf</a></p>
+    "#;
+
+    let markdown = content(html, None).to_markdown();
+
+    assert!(markdown.contains("### Pengantar HTML"));
+    assert!(markdown.contains("**berikut**"));
+    assert!(markdown.contains("*teliti*"));
+    assert!(markdown.contains("* Struktur dokumen"));
+    assert!(markdown.contains("* Elemen dasar"));
+    assert!(markdown.contains("[materi1.pdf](https://spot.upi.edu/files/materi1.pdf)"));
+}
+
+#[test]
+fn test_youtube_content_to_markdown_link() {
+    let html = r#"<iframe title="Belajar Rust" src="https://www.youtube.com/embed/abc123DEF_0?rel=0"></iframe>"#;
+    let markdown = content(html, Some("abc123DEF_0")).to_markdown();
+    assert_eq!(
+        markdown,
+        "[Belajar Rust](https://www.youtube.com/watch?v=abc123DEF_0)"
+    );
+
+    let html = r#"<iframe src="https://www.youtube.com/embed/xyz789-ghij"></iframe>"#;
+    let markdown = content(html, Some("xyz789-ghij")).to_markdown();
+    assert_eq!(
+        markdown,
+        "[YouTube video](https://www.youtube.com/watch?v=xyz789-ghij)"
+    );
+}
+
+const ARTICLE_HTML: &str = r#"
+<div class="panel panel-default" style="margin-top: 10px">
+  <div class="panel-body">
+    <h2 style="color: #333">Minggu 3: CSS Dasar</h2>
+    <p style="text-align: justify">Pelajari <b>selector</b> dan <i>box model</i>.<br>Baca juga catatan berikut.</p>
+    <ol>
+      <li>Selector elemen</li>
+      <li>Selector kelas</li>
+    </ol>
+    <p><img src="/uploads/materi/box-model.png" alt="Diagram box model" width="400" style="display: block"></p>
+    <p>Lampiran: <a href="/files/css-dasar.pdf" style="color: red">css-dasar.pdf</a></p>
+  </div>
+</div>
+"#;
+
+const ANNOUNCEMENT_HTML: &str = r#"
+<div style="font-family: Arial">
+  <p><span style="font-weight: bold">Perhatian:</span> kuis dipindah ke hari Kamis.</p>
+  <ul>
+    <li>Lihat <a href="https://example.com/jadwal">jadwal baru</a></li>
+    <li>Kumpulkan di <a href="../tugas/12">halaman tugas</a></li>
+  </ul>
+</div>
+"#;
+
+#[test]
+fn test_article_markdown_snapshot() {
+    let expected = "\
+Minggu 3: CSS Dasar
+----------
+
+Pelajari **selector** dan *box model*.  
+Baca juga catatan berikut.
+
+1. Selector elemen
+2. Selector kelas
+
+![Diagram box model](https://spot.upi.edu/uploads/materi/box-model.png)
+
+Lampiran: [css-dasar.pdf](https://spot.upi.edu/files/css-dasar.pdf)";
+
+    assert_eq!(content(ARTICLE_HTML, None).to_markdown(), expected);
+}
+
+#[test]
+fn test_announcement_markdown_snapshot_with_custom_base() {
+    let expected = "\
+Perhatian: kuis dipindah ke hari Kamis.
+
+* Lihat [jadwal baru](https://example.com/jadwal)
+* Kumpulkan di [halaman tugas](http://localhost:8080/mhs/topik/tugas/12)";
+
+    let markdown = content(ANNOUNCEMENT_HTML, None)
+        .to_markdown_with_base("http://localhost:8080/mhs/topik/1/2");
+    assert_eq!(markdown, expected);
+}
@@ -0,0 +1,91 @@
+// tests/metrics_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{
+    ClientMetrics, HttpTransport, MemoryCache, Result, RetryConfig, ScraperError,
+};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Duration;
+
+const PROFILE_HTML: &str = r#"<html><body>
+  <div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>
+</body></html>"#;
+
+/// Serves the profile, a schedule page that fails once with 503, and a KHS page that
+/// always times out. Counts the body bytes it hands out.
+#[derive(Default)]
+struct MetricsTransport {
+    schedule_requests: AtomicUsize,
+    served_bytes: AtomicU64,
+}
+
+#[async_trait]
+impl HttpTransport for MetricsTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        let (status, body) = match request.url().path() {
+            "/mhs" => (200, PROFILE_HTML),
+            "/mhs/jadwal" if self.schedule_requests.fetch_add(1, Ordering::SeqCst) == 0 => {
+                (503, "<html><body>Service Unavailable</body></html>")
+            }
+            "/mhs/jadwal" => (200, "<html><body><table></table></body></html>"),
+            _ => {
+                return Err(ScraperError::Timeout {
+                    url: request.url().to_string(),
+                    elapsed: Duration::from_secs(30),
+                });
+            }
+        };
+        self.served_bytes
+            .fetch_add(body.len() as u64, Ordering::SeqCst);
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(status)
+            .body(body)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_metrics_count_requests_failures_retries_and_cache() -> Result<()> {
+    let transport = Arc::new(MetricsTransport::default());
+    let client = common::builder()
+        .cache(Arc::new(MemoryCache::new()))
+        .retry(RetryConfig {
+            max_retries: 1,
+            initial_backoff_ms: 10,
+            ..RetryConfig::default()
+        })
+        .transport(transport.clone())
+        .build()?;
+    assert_eq!(client.metrics(), ClientMetrics::default());
+
+    // A cache miss and one request, then a cache hit.
+    client.get_user_profile().await?;
+    client.get_user_profile().await?;
+    // A 503 that succeeds on retry.
+    let _ = client.get_schedule().await;
+    // Two timeouts: the first attempt and its retry.
+    assert!(client.get_grades().await.is_err());
+
+    let metrics = client.clone().metrics();
+    assert_eq!(metrics.requests, 5);
+    assert_eq!(metrics.retries, 2);
+    assert_eq!(
+        metrics.failures,
+        BTreeMap::from([("http_status", 1), ("timeout", 2)])
+    );
+    assert_eq!(metrics.total_failures(), 3);
+    assert_eq!(metrics.cache_hits, 1);
+    assert_eq!(metrics.cache_misses, 1);
+    assert_eq!(
+        metrics.bytes_downloaded,
+        transport.served_bytes.load(Ordering::SeqCst)
+    );
+    Ok(())
+}
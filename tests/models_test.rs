@@ -0,0 +1,441 @@
+// tests/models_test.rs
+
+use chrono::{NaiveDate, NaiveDateTime};
+use spotifier_core::{
+    Accessibility, Answer, Course, CourseSummary, DateRange, DetailCourse, Rps, Task, TaskStatus,
+    TopicDetail, TopicInfo, Urgency, User,
+};
+
+fn sample_detail() -> DetailCourse {
+    DetailCourse {
+        course_info: Course {
+            id: 2510009532,
+            code: "IK410".to_string(),
+            name: "Pemrograman Web".to_string(),
+            credits: 3,
+            lecturer: "Dosen Pengampu".to_string(),
+            lecturers: vec!["Dosen Pengampu".to_string()],
+            academic_year: "2025/2026 - Ganjil".to_string(),
+            href: "/mhs/topik/2510009532".to_string(),
+        },
+        description: "<p>Mata kuliah ini membahas dasar-dasar pengembangan web.</p>".repeat(10),
+        rps: Rps {
+            id: Some(98765),
+            href: Some("/mhs/rps/98765".to_string()),
+        },
+        topics: (0..16)
+            .map(|i| TopicInfo {
+                id: Some(1358800 + i),
+                course_id: Some(2510009532),
+                access_time: None,
+                updated_at: None,
+                is_accessible: i < 8,
+                accessibility: if i < 8 {
+                    Accessibility::Open
+                } else {
+                    Accessibility::Closed
+                },
+                href: Some(format!("/mhs/topik/2510009532/{}", 1358800 + i)),
+            })
+            .collect(),
+        lecturer_email: None,
+        lecturer_profile_href: None,
+    }
+}
+
+#[test]
+fn test_course_summary_is_much_smaller_than_detail() {
+    let detail = sample_detail();
+    let summary = CourseSummary::from_details(&detail, &[]);
+
+    assert_eq!(summary.course_id, 2510009532);
+    assert_eq!(summary.topic_count, 16);
+    assert_eq!(summary.accessible_topic_count, 8);
+    assert_eq!(summary.pending_task_count, 0);
+
+    let detail_json = serde_json::to_string(&detail).unwrap();
+    let summary_json = serde_json::to_string(&summary).unwrap();
+    assert!(
+        summary_json.len() * 10 < detail_json.len(),
+        "Summary ({} bytes) should be far smaller than detail ({} bytes)",
+        summary_json.len(),
+        detail_json.len()
+    );
+}
+
+fn course_in(academic_year: &str, lecturer: &str, credits: u8) -> Course {
+    Course {
+        id: 2510009532,
+        code: "IK410".to_string(),
+        name: "Pemrograman Web".to_string(),
+        credits,
+        lecturer: lecturer.to_string(),
+        lecturers: vec![lecturer.to_string()],
+        academic_year: academic_year.to_string(),
+        href: "/mhs/topik/2510009532".to_string(),
+    }
+}
+
+#[test]
+fn test_course_merge_prefers_latest_period() {
+    let old = course_in("2024/2025 - Genap", "Dosen Lama", 3);
+    let new = course_in("2025/2026 - Ganjil", "Dosen Baru", 0);
+
+    // Order of arguments must not matter
+    for merged in [old.merge(&new), new.merge(&old)] {
+        assert_eq!(merged.academic_year, "2025/2026 - Ganjil");
+        assert_eq!(merged.lecturer, "Dosen Baru");
+        // Missing credits on the latest record are filled from the older one
+        assert_eq!(merged.credits, 3);
+    }
+}
+
+#[test]
+fn test_course_merge_short_semester_is_after_even() {
+    let even = course_in("2024/2025 - Genap", "Dosen Genap", 3);
+    let short = course_in("2024/2025 - SP", "Dosen SP", 3);
+
+    assert_eq!(even.merge(&short).lecturer, "Dosen SP");
+    assert_eq!(short.merge(&even).lecturer, "Dosen SP");
+}
+
+fn topic_info(course_id: Option<u64>, id: Option<u64>) -> TopicInfo {
+    TopicInfo {
+        id,
+        course_id,
+        access_time: None,
+        updated_at: None,
+        is_accessible: id.is_some(),
+        accessibility: if id.is_some() {
+            Accessibility::Open
+        } else {
+            Accessibility::Closed
+        },
+        href: None,
+    }
+}
+
+#[test]
+fn test_course_detail_id_from_href_shapes() {
+    let with_href = |href: &str| Course {
+        href: href.to_string(),
+        ..course_in("2025/2026 - Ganjil", "Dosen", 3)
+    };
+
+    for href in [
+        "/mhs/topik/2510009532",
+        "/mhs/matkul/2510009532/",
+        "/mhs/topik/2510009532?tab=rps",
+        "/mhs/topik/2510009532/#topik",
+        "https://spot.upi.edu/mhs/topik/2510009532",
+        "2510009532",
+    ] {
+        assert_eq!(with_href(href).detail_id(), Some(2510009532), "{}", href);
+    }
+
+    assert_eq!(with_href("/mhs/topik/abc").detail_id(), None);
+    assert_eq!(with_href("/mhs/topik/").detail_id(), None);
+    assert_eq!(with_href("").detail_id(), None);
+}
+
+#[test]
+fn test_topic_info_ids() {
+    assert_eq!(
+        topic_info(Some(2510009532), Some(1358801)).ids(),
+        Some((2510009532, 1358801))
+    );
+    assert_eq!(topic_info(None, Some(1358801)).ids(), None);
+    assert_eq!(topic_info(Some(2510009532), None).ids(), None);
+    assert_eq!(topic_info(None, None).ids(), None);
+}
+
+#[test]
+fn test_topic_detail_round_trips_to_topic_info() {
+    let detail = TopicDetail {
+        id: 1358801,
+        access_time: None,
+        is_accessible: true,
+        href: "/mhs/topik/2510009532/1358801".to_string(),
+        description: None,
+        contents: Vec::new(),
+        tasks: Vec::new(),
+        quizzes: Vec::new(),
+    };
+
+    let info = detail.to_topic_info(2510009532);
+    assert_eq!(info.ids(), Some((2510009532, 1358801)));
+    assert!(info.is_accessible);
+    assert_eq!(info.href.as_deref(), Some(detail.href.as_str()));
+}
+
+fn task_with_window(start: Option<NaiveDateTime>, due: Option<NaiveDateTime>) -> Task {
+    Task {
+        id: Some(4242),
+        course_id: 2510009532,
+        topic_id: 1358801,
+        token: String::new(),
+        title: "Tugas".to_string(),
+        description: String::new(),
+        file: None,
+        start_date: start,
+        due_date: due,
+        status: TaskStatus::NotSubmitted,
+        answer: None,
+        min_chars: None,
+        max_chars: None,
+    }
+}
+
+fn at(day: u32, hour: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 9, day)
+        .unwrap()
+        .and_hms_opt(hour, 0, 0)
+        .unwrap()
+}
+
+#[test]
+fn test_task_is_open_for_all_window_shapes() {
+    // Both bounds
+    let task = task_with_window(Some(at(10, 8)), Some(at(20, 23)));
+    assert!(!task.is_open(at(9, 12)));
+    assert!(task.is_open(at(10, 8)));
+    assert!(task.is_open(at(20, 23)));
+    assert!(!task.is_open(at(21, 0)));
+
+    // No start: open from the beginning
+    let task = task_with_window(None, Some(at(20, 23)));
+    assert!(task.is_open(at(1, 0)));
+    assert!(!task.is_open(at(21, 0)));
+
+    // No deadline
+    let task = task_with_window(Some(at(10, 8)), None);
+    assert!(!task.is_open(at(9, 12)));
+    assert!(task.is_open(at(30, 23)));
+
+    // Neither: always open
+    let task = task_with_window(None, None);
+    assert!(task.is_open(at(1, 0)));
+    assert_eq!(
+        task.submission_window(),
+        DateRange {
+            start: None,
+            end: None
+        }
+    );
+}
+
+#[test]
+fn test_task_status_wire_format() {
+    let golden = [
+        (TaskStatus::Pending, r#""pending""#),
+        (TaskStatus::Submitted, r#""submitted""#),
+        (TaskStatus::Graded, r#""graded""#),
+        (TaskStatus::NotSubmitted, r#""not_submitted""#),
+    ];
+    for (status, json) in golden {
+        assert_eq!(serde_json::to_string(&status).unwrap(), json);
+        assert_eq!(serde_json::from_str::<TaskStatus>(json).unwrap(), status);
+    }
+
+    // Caches written before the format was pinned used the variant names.
+    for (json, status) in [
+        (r#""Submitted""#, TaskStatus::Submitted),
+        (r#""NotSubmitted""#, TaskStatus::NotSubmitted),
+    ] {
+        assert_eq!(serde_json::from_str::<TaskStatus>(json).unwrap(), status);
+    }
+}
+
+#[test]
+fn test_topic_content_hash() {
+    let topic = TopicDetail {
+        id: 1358801,
+        access_time: Some(at(1, 8)),
+        is_accessible: true,
+        href: "/mhs/topik/2510009532/1358801".to_string(),
+        description: Some("Pengantar".to_string()),
+        contents: Vec::new(),
+        tasks: vec![task_with_window(Some(at(10, 8)), Some(at(20, 23)))],
+        quizzes: Vec::new(),
+    };
+
+    let same = topic.clone();
+    assert_eq!(topic.content_hash(), same.content_hash());
+
+    // Visiting the page or reloading its CSRF token is not a change.
+    let mut revisited = topic.clone();
+    revisited.access_time = Some(at(2, 9));
+    revisited.tasks[0].token = "fresh-token".to_string();
+    assert_eq!(topic.content_hash(), revisited.content_hash());
+
+    let mut extended = topic.clone();
+    extended.tasks[0].due_date = Some(at(27, 23));
+    assert_ne!(topic.content_hash(), extended.content_hash());
+
+    // Every serialized field counts, not just a hand-picked list.
+    let mut limited = topic.clone();
+    limited.tasks[0].max_chars = Some(500);
+    assert_ne!(topic.content_hash(), limited.content_hash());
+}
+
+#[test]
+fn test_task_deadline_helpers() {
+    let due_soon = chrono::Duration::days(2);
+    let task = task_with_window(Some(at(10, 8)), Some(at(20, 23)));
+
+    assert_eq!(task.urgency(at(9, 12), due_soon), Urgency::NotStarted);
+    assert_eq!(task.urgency(at(12, 0), due_soon), Urgency::Upcoming);
+    assert_eq!(task.urgency(at(19, 0), due_soon), Urgency::DueSoon);
+    assert_eq!(task.urgency(at(21, 0), due_soon), Urgency::Overdue);
+
+    assert_eq!(
+        task.time_until_due(at(19, 23)),
+        Some(chrono::Duration::days(1))
+    );
+    assert_eq!(
+        task.time_until_due(at(21, 0)),
+        Some(chrono::Duration::hours(-1))
+    );
+    assert!(
+        !task.is_overdue(at(20, 23)),
+        "The deadline itself is still on time"
+    );
+    assert!(task.is_overdue(at(21, 0)));
+
+    let open_ended = task_with_window(None, None);
+    assert_eq!(open_ended.time_until_due(at(1, 0)), None);
+    assert!(!open_ended.is_overdue(at(30, 23)));
+    assert_eq!(open_ended.urgency(at(1, 0), due_soon), Urgency::Upcoming);
+}
+
+#[test]
+fn test_display_formats() {
+    let user = User {
+        name: "Budi Santoso".to_string(),
+        nim: "2101234".to_string(),
+    };
+    assert_eq!(user.to_string(), "Budi Santoso (2101234)");
+
+    let course = course_in("2025/2026 - Ganjil", "Dr. Budi", 3);
+    assert_eq!(
+        course.to_string(),
+        "IK410 - Pemrograman Web (3 SKS) — Dr. Budi"
+    );
+    let mut unknown = course_in("2025/2026 - Ganjil", "", 0);
+    unknown.lecturers.clear();
+    assert_eq!(unknown.to_string(), "IK410 - Pemrograman Web");
+
+    let mut task = task_with_window(None, Some(at(8, 23)));
+    assert_eq!(
+        task.to_string(),
+        "Tugas [not submitted, due 2025-09-08 23:00]"
+    );
+    task.status = TaskStatus::Graded;
+    task.answer = Some(Answer {
+        id: Some(1),
+        content: String::new(),
+        file_href: None,
+        is_graded: true,
+        lecturer_notes: String::new(),
+        score: 85.5,
+        date_submitted: None,
+        rubric: Vec::new(),
+    });
+    task.due_date = None;
+    assert_eq!(task.to_string(), "Tugas [graded 85.5]");
+
+    let topic = TopicDetail {
+        id: 1358801,
+        access_time: None,
+        is_accessible: false,
+        href: "/mhs/topik/2510009532/1358801".to_string(),
+        description: Some("\n  Pengantar\nMateri minggu pertama".to_string()),
+        contents: Vec::new(),
+        tasks: vec![task],
+        quizzes: Vec::new(),
+    };
+    assert_eq!(
+        topic.to_string(),
+        "Topic 1358801: Pengantar (0 contents, 1 task, 0 quizzes) [locked]"
+    );
+}
+
+#[test]
+fn test_identity_dedup_in_hash_set() {
+    use std::collections::HashSet;
+
+    let courses: HashSet<Course> = [
+        course_in("2024/2025 - Genap", "Dosen Lama", 3),
+        course_in("2025/2026 - Ganjil", "Dosen Baru", 3),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(courses.len(), 1);
+
+    let without_id = |code: &str, href: &str| Course {
+        id: 0,
+        code: code.to_string(),
+        href: href.to_string(),
+        ..course_in("2025/2026 - Ganjil", "Dosen", 3)
+    };
+    assert_ne!(without_id("IK410", "/a"), without_id("IK411", "/b"));
+    assert_ne!(without_id("IK410", "/a"), without_id("IK410", "/b"));
+    let courses: HashSet<Course> = [
+        without_id("IK410", "/a"),
+        without_id("IK410", "/a"),
+        without_id("IK411", "/b"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(courses.len(), 2);
+
+    let topic = |id: Option<u64>, href: &str| TopicInfo {
+        id,
+        course_id: Some(2510009532),
+        access_time: None,
+        updated_at: None,
+        is_accessible: id.is_some(),
+        accessibility: if id.is_some() {
+            Accessibility::Open
+        } else {
+            Accessibility::Closed
+        },
+        href: Some(href.to_string()),
+    };
+    let topics: HashSet<TopicInfo> = [
+        topic(Some(1), "/mhs/topik/2510009532/1"),
+        topic(Some(1), "/mhs/topik/2510009532/1?lama"),
+        topic(Some(2), "/mhs/topik/2510009532/2"),
+        topic(None, "#a"),
+        topic(None, "#a"),
+        topic(None, "#b"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(topics.len(), 5);
+
+    let mut locked = topic(None, "#");
+    locked.href = None;
+    let locked_topics: HashSet<TopicInfo> = [locked.clone(), locked.clone()].into_iter().collect();
+    assert_eq!(locked_topics.len(), 2);
+    assert_ne!(locked, locked.clone());
+
+    let mut renamed = task_with_window(None, None);
+    renamed.title = "Tugas (revisi)".to_string();
+    let mut without_id = task_with_window(None, None);
+    without_id.id = None;
+    let mut other_without_id = without_id.clone();
+    other_without_id.title = "Tugas Lain".to_string();
+    let tasks: HashSet<Task> = [
+        task_with_window(None, None),
+        renamed,
+        without_id.clone(),
+        without_id.clone(),
+        other_without_id,
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(tasks.len(), 4);
+    assert_ne!(task_with_window(None, None), without_id);
+    assert_ne!(without_id, without_id.clone());
+}
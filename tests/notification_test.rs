@@ -0,0 +1,110 @@
+// tests/notification_test.rs
+
+mod common;
+
+use chrono::NaiveDate;
+use common::{MockResponse, MockTransport};
+use spotifier_core::parsers::notification::parse_notifications_from_html;
+use spotifier_core::{NotificationKind, Result, ScraperError};
+
+const NOTIFICATIONS_HTML: &str = r##"
+<html><body>
+  <div class="message-center">
+    <a href="/mhs/topik/2510009532/1358801" data-id="501" class="unread">
+      <div class="mail-contnet">
+        <h5>Nilai Tugas</h5>
+        <span class="mail-desc">Tugas 1 Pemrograman Web telah dinilai</span>
+        <span class="time">10-09-2025 13:30</span>
+      </div>
+    </a>
+    <a href="/mhs/topik/2510009532/1358802" data-id="500">
+      <div class="mail-contnet">
+        <h5>Materi Baru</h5>
+        <span class="mail-desc">Topik 2 telah ditambahkan</span>
+        <span class="time">08-09-2025 08:00</span>
+      </div>
+    </a>
+    <a href="#" data-id="499">
+      <div class="mail-contnet">
+        <h5>Pengumuman</h5>
+        <span class="mail-desc">Libur nasional</span>
+      </div>
+    </a>
+    <a href="/mhs/notifikasi" class="text-center">Lihat semua notifikasi</a>
+  </div>
+</body></html>
+"##;
+
+#[test]
+fn test_parse_notifications() -> Result<()> {
+    let notifications = parse_notifications_from_html(NOTIFICATIONS_HTML)?;
+    assert_eq!(
+        notifications.len(),
+        3,
+        "The footer link is not a notification"
+    );
+
+    let grade = &notifications[0];
+    assert_eq!(grade.id, 501);
+    assert_eq!(grade.kind, NotificationKind::Grade);
+    assert_eq!(grade.message, "Tugas 1 Pemrograman Web telah dinilai");
+    assert_eq!(grade.link.as_deref(), Some("/mhs/topik/2510009532/1358801"));
+    assert_eq!(
+        grade.created_at,
+        NaiveDate::from_ymd_opt(2025, 9, 10).and_then(|d| d.and_hms_opt(13, 30, 0))
+    );
+    assert!(!grade.is_read);
+
+    assert_eq!(notifications[1].kind, NotificationKind::Material);
+    assert!(notifications[1].is_read);
+
+    let announcement = &notifications[2];
+    assert_eq!(announcement.kind, NotificationKind::Announcement);
+    assert_eq!(announcement.link, None);
+    assert_eq!(announcement.created_at, None);
+
+    Ok(())
+}
+
+#[test]
+fn test_notifications_are_sorted_newest_first() -> Result<()> {
+    let html = r##"<div class="message-center">
+        <a href="#" data-id="1"><span class="time">01-09-2025 08:00</span></a>
+        <a href="#" data-id="2"></a>
+        <a href="#" data-id="3"><span class="time">15-09-2025 08:00</span></a>
+        <a href="#" data-id="4"><span class="time">05-09-2025 08:00</span></a>
+    </div>"##;
+
+    let ids: Vec<u64> = parse_notifications_from_html(html)?
+        .iter()
+        .map(|n| n.id)
+        .collect();
+    assert_eq!(ids, [3, 4, 1, 2]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mark_notification_read_follows_the_redirect() -> Result<()> {
+    let transport = MockTransport::new(|_| {
+        MockResponse::html("<html></html>").at("https://spot.upi.edu/mhs/topik/2510009532/1358801")
+    });
+    let client = common::client_with(transport.clone());
+
+    client.mark_notification_read(501).await?;
+    assert_eq!(transport.last_request().path(), "/mhs/notifikasi/baca/501");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_mark_notification_read_reports_expired_session() {
+    let transport = MockTransport::new(|_| {
+        MockResponse::html("<html><form id=\"fm1\"></form></html>")
+            .at("https://sso.upi.edu/cas/login?service=https://spot.upi.edu/beranda")
+    });
+    let client = common::client_with(transport);
+
+    assert!(matches!(
+        client.mark_notification_read(501).await,
+        Err(ScraperError::SessionExpired)
+    ));
+}
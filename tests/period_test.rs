@@ -1,20 +1,27 @@
 // tests/period_test.rs
 
+mod common;
+
+use chrono::NaiveDate;
+use common::{MockResponse, MockTransport};
 use dotenvy::from_path;
-use spotifier_core::{Period, Result, Semester, SpotifierCoreClient};
-use std::env;
+use spotifier_core::parsers::period::parse_available_periods_from_html;
+use spotifier_core::{
+    Credentials, Period, Recording, ReplayTransport, Result, ScraperError, Semester,
+    SpotifierCoreClient,
+};
 use std::path::PathBuf;
+use std::sync::Arc;
 
 /// Helper to setup authenticated client
 async fn setup_client() -> Result<SpotifierCoreClient> {
     let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
     from_path(&env_path).ok();
 
-    let nim = env::var("SPOT_NIM").expect("SPOT_NIM env var not set");
-    let password = env::var("SPOT_PASSWORD").expect("SPOT_PASSWORD env var not set");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     let client = SpotifierCoreClient::new();
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
 
     Ok(client)
 }
@@ -53,10 +60,37 @@ async fn test_period_formatting() -> Result<()> {
     let period2 = Period::new(2024, Semester::Even);
     assert_eq!(period2.format(), "20242");
 
+    for period in [period, period2, Period::new(2024, Semester::Short)] {
+        assert_eq!(Period::from_code(&period.format())?, period);
+    }
+    assert_eq!(
+        Period::from_code(" 20251/ ")?,
+        Period::new(2025, Semester::Odd)
+    );
+    for code in ["", "2025", "20254", "2025a", "202511"] {
+        assert!(Period::from_code(code).is_err(), "{}", code);
+    }
+
     println!("✅ Period formatting tests passed");
     Ok(())
 }
 
+#[tokio::test]
+async fn test_period_from_calendar_boundaries() -> Result<()> {
+    let on = |y, m, d| Period::current_with_clock(NaiveDate::from_ymd_opt(y, m, d).unwrap());
+
+    // August is still the short semester of the academic year that started last September
+    assert_eq!(on(2025, 8, 31), Period::new(2024, Semester::Short));
+    // September starts a new academic year
+    assert_eq!(on(2025, 9, 1), Period::new(2025, Semester::Odd));
+    // January belongs to the previous year's odd semester
+    assert_eq!(on(2026, 1, 15), Period::new(2025, Semester::Odd));
+    assert_eq!(on(2026, 2, 1), Period::new(2025, Semester::Even));
+    assert_eq!(on(2026, 6, 30), Period::new(2025, Semester::Even));
+    assert_eq!(on(2026, 7, 1), Period::new(2025, Semester::Short));
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_change_period_valid() -> Result<()> {
     let client = setup_client().await?;
@@ -99,11 +133,328 @@ async fn test_semester_types() -> Result<()> {
 
     // Test all semester types to ensure they don't panic
     for semester in [Semester::Odd, Semester::Even, Semester::Short] {
-        let result = client.change_period(2025, semester).await;
-        println!("Testing semester {:?}: {:?}", semester, result);
-        // Should not panic, may succeed or fail depending on availability
+        // Should not panic; periods without data fail with `PeriodUnavailable`
+        let _ = client.change_period(2025, semester).await;
     }
 
-    println!("✅ Semester types test passed");
     Ok(())
 }
+
+const BASE_URL: &str = "https://spot.upi.edu";
+
+fn course_row(id: u64, code: &str, name: &str, lecturer: &str, academic_year: &str) -> String {
+    format!(
+        r#"<tr><td>{code}</td><td><a href="/mhs/topik/{id}">{name}</a></td><td>3</td><td>{lecturer}</td><td>{academic_year}</td></tr>"#
+    )
+}
+
+fn courses_page(rows: &[String]) -> String {
+    format!(
+        "<html><body><table><tbody>{}</tbody></table></body></html>",
+        rows.concat()
+    )
+}
+
+fn get(path: &str, final_path: &str, body: String) -> Recording {
+    common::recording(
+        "GET",
+        &format!("{}{}", BASE_URL, path),
+        &format!("{}{}", BASE_URL, final_path),
+        &body,
+    )
+}
+
+/// A client whose transport can switch to any of `periods` and serves `course_pages`
+/// from `/mhs` in order.
+fn multi_period_client(periods: &[&str], course_pages: Vec<String>) -> SpotifierCoreClient {
+    let mut transport = ReplayTransport::default();
+    for period in periods {
+        transport.insert(get(
+            &format!("/adm/semester/{}", period),
+            "/adm",
+            String::new(),
+        ));
+    }
+    for page in course_pages {
+        transport.insert(get("/mhs", "/mhs", page));
+    }
+
+    common::builder()
+        .transport(Arc::new(transport))
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_get_all_courses_aggregates_and_restores_period() -> Result<()> {
+    let even_2024 = courses_page(&[
+        course_row(
+            2420001,
+            "IK300",
+            "Algoritma",
+            "Dosen A",
+            "2024/2025 - Genap",
+        ),
+        course_row(
+            2510009532,
+            "IK410",
+            "Pemrograman Web",
+            "",
+            "2024/2025 - Genap",
+        ),
+    ]);
+    let odd_2025 = courses_page(&[
+        course_row(
+            2510009532,
+            "IK410",
+            "Pemrograman Web",
+            "Dosen B",
+            "2025/2026 - Ganjil",
+        ),
+        course_row(
+            2510009533,
+            "IK420",
+            "Basis Data",
+            "Dosen C",
+            "2025/2026 - Ganjil",
+        ),
+    ]);
+    let even_2025 = courses_page(&[course_row(
+        2520001,
+        "IK430",
+        "Jaringan Komputer",
+        "Dosen D",
+        "2025/2026 - Genap",
+    )]);
+    // `change_period` reads the course list back, so each switch is followed by a page
+    // of the period it selected.
+    let client = multi_period_client(
+        &["20242", "20251", "20252"],
+        vec![even_2025.clone(), even_2024, odd_2025, even_2025],
+    );
+    client.change_period(2025, Semester::Even).await?;
+
+    let courses = client
+        .get_all_courses(&[
+            Period::new(2024, Semester::Even),
+            Period::new(2025, Semester::Odd),
+        ])
+        .await?;
+
+    let ids: Vec<u64> = courses.iter().map(|c| c.id).collect();
+    assert_eq!(ids, [2420001, 2510009532, 2510009533]);
+    assert_eq!(courses[1].lecturer, "Dosen B");
+    assert_eq!(courses[1].academic_year, "2025/2026 - Ganjil");
+    assert_eq!(
+        client.current_period(),
+        Some(Period::new(2025, Semester::Even))
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_courses_for_period_restores_period_on_error() {
+    // Only the 2025 Even course list is recorded, so the switch to 2024 Odd fails.
+    let even_2025 = courses_page(&[course_row(
+        2520001,
+        "IK430",
+        "Jaringan Komputer",
+        "Dosen D",
+        "2025/2026 - Genap",
+    )]);
+    let client = multi_period_client(&["20241", "20252"], vec![even_2025]);
+    client.change_period(2025, Semester::Even).await.unwrap();
+
+    let result = client
+        .get_courses_for_period(Period::new(2024, Semester::Odd))
+        .await;
+
+    assert!(result.is_err());
+    assert_eq!(
+        client.current_period(),
+        Some(Period::new(2025, Semester::Even))
+    );
+}
+
+#[tokio::test]
+async fn test_get_courses_for_period_needs_a_known_period() {
+    // No period was selected and the course list does not name one, so the original
+    // period is unknown and switching away could not be undone.
+    let page = courses_page(&[course_row(2520001, "IK430", "Jaringan", "Dosen D", "-")]);
+    let transport = MockTransport::new(move |_| MockResponse::html(&page));
+    let client = common::client_with(transport.clone());
+
+    let result = client
+        .get_courses_for_period(Period::new(2024, Semester::Odd))
+        .await;
+
+    assert!(matches!(result, Err(ScraperError::ParsingError(_))));
+    assert!(
+        transport
+            .requests()
+            .iter()
+            .all(|request| !request.path().starts_with("/adm/semester/"))
+    );
+}
+
+const PERIOD_DROPDOWN_HTML: &str = r##"<html><body>
+  <ul class="nav navbar-nav">
+    <li class="dropdown">
+      <a class="dropdown-toggle" href="#">2025/2026 - Ganjil <span class="caret"></span></a>
+      <ul class="dropdown-menu">
+        <li><a href="/adm/semester/20251">2025/2026 - Ganjil</a></li>
+        <li><a href="https://spot.upi.edu/adm/semester/20243">2024/2025 - SP</a></li>
+        <li><a href="/adm/semester/20242">
+          2024/2025 -
+          Genap
+        </a></li>
+        <li><a href="/adm/semester/20241">Semester Ganjil 2024</a></li>
+        <li><a href="/adm/semester/20251">2025/2026 - Ganjil</a></li>
+      </ul>
+    </li>
+  </ul>
+</body></html>"##;
+
+#[test]
+fn test_parse_available_periods() -> Result<()> {
+    let periods = parse_available_periods_from_html(PERIOD_DROPDOWN_HTML)?;
+    assert_eq!(
+        periods,
+        [
+            Period::new(2025, Semester::Odd),
+            Period::new(2024, Semester::Short),
+            Period::new(2024, Semester::Even),
+            // The label does not parse, so the code in the link is used.
+            Period::new(2024, Semester::Odd),
+        ]
+    );
+
+    let select = r#"<select name="semester">
+      <option value="">Pilih Semester</option>
+      <option value="20252">2025/2026 - Genap</option>
+      <option value="20251" selected>2025/2026 - Ganjil</option>
+    </select>"#;
+    assert_eq!(
+        parse_available_periods_from_html(select)?,
+        [
+            Period::new(2025, Semester::Even),
+            Period::new(2025, Semester::Odd)
+        ]
+    );
+
+    assert!(matches!(
+        parse_available_periods_from_html("<html><body><p>Beranda</p></body></html>"),
+        Err(ScraperError::SelectorMissing { .. })
+    ));
+
+    // Any other dropdown on the page is not a period switcher.
+    let unrelated = r#"<select name="bahasa"><option value="id">Indonesia</option></select>"#;
+    assert!(matches!(
+        parse_available_periods_from_html(unrelated),
+        Err(ScraperError::SelectorMissing { .. })
+    ));
+
+    let form = r#"<form action="/adm/semester" method="post"><select name="kode">
+      <option value="20243">2024/2025 - SP</option>
+    </select></form>"#;
+    assert_eq!(
+        parse_available_periods_from_html(form)?,
+        [Period::new(2024, Semester::Short)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_list_available_periods() -> Result<()> {
+    let client = multi_period_client(&[], vec![PERIOD_DROPDOWN_HTML.to_string()]);
+    let periods = client.list_available_periods().await?;
+    assert_eq!(periods.len(), 4);
+    assert_eq!(periods[0], Period::new(2025, Semester::Odd));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_change_period_reports_ignored_switch() {
+    // SPOT redirects to /adm as usual but keeps 2025/2026 - Ganjil active.
+    let odd_2025 = courses_page(&[course_row(
+        2510009532,
+        "IK410",
+        "Pemrograman Web",
+        "Dosen B",
+        "2025/2026 - Ganjil",
+    )]);
+    let client = multi_period_client(&["20251", "20193"], vec![odd_2025]);
+    client.change_period(2025, Semester::Odd).await.unwrap();
+
+    let result = client.change_period(2019, Semester::Short).await;
+
+    match result {
+        Err(ScraperError::PeriodUnavailable(period)) => {
+            assert_eq!(period, Period::new(2019, Semester::Short));
+        }
+        other => panic!("expected PeriodUnavailable, got {:?}", other),
+    }
+    assert_eq!(
+        client.current_period(),
+        Some(Period::new(2025, Semester::Odd))
+    );
+}
+
+#[tokio::test]
+async fn test_change_period_keeps_the_switch_when_the_read_back_fails() {
+    let transport = MockTransport::new(|request| {
+        if request.path().starts_with("/adm/semester/") {
+            return MockResponse::html("").at(&format!("{}/adm", BASE_URL));
+        }
+        MockResponse::status(500)
+    });
+    let client = common::client_with(transport);
+
+    assert!(client.change_period(2024, Semester::Even).await.is_err());
+    // SPOT has already switched, so the client must not keep claiming the old period.
+    assert_eq!(
+        client.current_period(),
+        Some(Period::new(2024, Semester::Even))
+    );
+}
+
+#[tokio::test]
+async fn test_change_period_with_a_session_that_keeps_expiring_terminates() {
+    const CAS_LOGIN_HTML: &str = include_str!("fixtures/cas_login.html");
+    let transport = MockTransport::new(|request| {
+        if request.url.starts_with("https://sso.upi.edu") {
+            return match request.method.as_str() {
+                "POST" => MockResponse::html("").at(&format!("{}/beranda", BASE_URL)),
+                _ => MockResponse::html(CAS_LOGIN_HTML),
+            };
+        }
+        if request.path().starts_with("/adm/semester/") {
+            return MockResponse::html("").at(&format!("{}/adm", BASE_URL));
+        }
+        MockResponse::html(CAS_LOGIN_HTML)
+    });
+    let client = common::builder()
+        .transport(transport.clone())
+        .auto_relogin(spotifier_core::AutoReloginConfig::Provider(Arc::new(
+            || Some(("2301234".to_string(), "rahasia".to_string())),
+        )))
+        .build()
+        .unwrap();
+
+    assert!(matches!(
+        client.change_period(2024, Semester::Even).await,
+        Err(ScraperError::SessionExpired)
+    ));
+    let requests = transport.requests();
+    let count = |prefix: &str| {
+        requests
+            .iter()
+            .filter(|r| r.path().starts_with(prefix))
+            .count()
+    };
+    // One switch, one re-login, one restoring switch, then it gives up.
+    assert_eq!(count("/adm/semester/"), 2);
+    assert_eq!(requests.iter().filter(|r| r.method == "POST").count(), 1);
+}
@@ -0,0 +1,63 @@
+// tests/rate_limit_test.rs
+
+use spotifier_core::RateLimiter;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn test_rate_limiter_bounds_concurrent_requests() {
+    let interval = Duration::from_millis(100);
+    let limiter = Arc::new(RateLimiter::new(interval));
+    let start = Instant::now();
+
+    let handles: Vec<_> = (0..5)
+        .map(|_| {
+            let limiter = Arc::clone(&limiter);
+            tokio::spawn(async move {
+                limiter.acquire().await;
+                start.elapsed()
+            })
+        })
+        .collect();
+
+    let mut starts = Vec::new();
+    for handle in handles {
+        starts.push(handle.await.unwrap());
+    }
+    starts.sort();
+
+    // Five requests at one per 100ms need at least 400ms in aggregate
+    assert!(start.elapsed() >= interval * 4);
+    for pair in starts.windows(2) {
+        assert!(
+            pair[1] - pair[0] >= interval - Duration::from_millis(5),
+            "Requests started too close together: {:?}",
+            starts
+        );
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_rate_limiter_allows_burst_then_paces() {
+    let limiter = Arc::new(RateLimiter::per_second(10.0).with_burst(3));
+    assert_eq!(limiter.min_interval(), Duration::from_millis(100));
+    let start = tokio::time::Instant::now();
+
+    let handles: Vec<_> = (0..6)
+        .map(|_| {
+            let limiter = Arc::clone(&limiter);
+            tokio::spawn(async move {
+                limiter.acquire().await;
+                start.elapsed()
+            })
+        })
+        .collect();
+
+    let mut starts = Vec::new();
+    for handle in handles {
+        starts.push(handle.await.unwrap().as_millis());
+    }
+    starts.sort();
+
+    assert_eq!(starts, vec![0, 0, 0, 100, 200, 300]);
+}
@@ -0,0 +1,51 @@
+// tests/recording_test.rs
+
+use spotifier_core::{Recording, Result};
+
+#[test]
+fn test_recording_key_is_stable() {
+    let key = Recording::key("GET", "https://spot.upi.edu/mhs", None);
+    assert_eq!(key.len(), 16);
+    assert_eq!(key, Recording::key("GET", "https://spot.upi.edu/mhs", None));
+    assert_ne!(
+        key,
+        Recording::key("POST", "https://spot.upi.edu/mhs", None)
+    );
+    assert_ne!(
+        key,
+        Recording::key("GET", "https://spot.upi.edu/mhs", Some("a=1"))
+    );
+}
+
+#[tokio::test]
+async fn test_recording_round_trip() -> Result<()> {
+    let dir = std::env::temp_dir().join("spotifier_recording_test");
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    let recording = Recording {
+        method: "POST".to_string(),
+        url: "https://sso.upi.edu/cas/login".to_string(),
+        request_body: Some(r#"{"password":"<redacted>","username":"2101234"}"#.to_string()),
+        status: 200,
+        final_url: "https://spot.upi.edu/beranda".to_string(),
+        response_body: "<html><body>Beranda</body></html>".to_string(),
+    };
+    recording.save(&dir).await?;
+
+    let loaded = Recording::load(
+        &dir,
+        "POST",
+        "https://sso.upi.edu/cas/login",
+        recording.request_body.as_deref(),
+    )
+    .await?;
+    assert_eq!(loaded, Some(recording));
+
+    let missing = Recording::load(&dir, "GET", "https://spot.upi.edu/mhs", None).await?;
+    assert!(missing.is_none());
+
+    std::fs::remove_dir_all(&dir).ok();
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// tests/redis_cache_test.rs
+#![cfg(feature = "redis")]
+
+use spotifier_core::{CacheBackend, RedisCache};
+
+/// Runs against the server in `REDIS_URL`; does nothing when it is not set.
+#[tokio::test]
+async fn test_redis_cache_round_trip() {
+    let Ok(url) = std::env::var("REDIS_URL") else {
+        println!("REDIS_URL not set, skipping");
+        return;
+    };
+
+    let cache = RedisCache::new(&url).await.expect("connect to Redis");
+    let key = "spotifier-core-test:courses";
+
+    cache.set(key, r#"[{"id":1}]"#, 60).await.unwrap();
+    assert_eq!(cache.get(key).await.as_deref(), Some(r#"[{"id":1}]"#));
+
+    cache.delete(key).await.unwrap();
+    assert_eq!(cache.get(key).await, None);
+
+    cache.set(key, "expired", 0).await.unwrap();
+    assert_eq!(cache.get(key).await, None);
+
+    cache.set(key, "[]", 60).await.unwrap();
+    cache
+        .set("spotifier-core-other:courses", "[]", 60)
+        .await
+        .unwrap();
+    cache.delete_prefix("spotifier-core-test:").await.unwrap();
+    assert_eq!(cache.get(key).await, None);
+    assert!(cache.get("spotifier-core-other:courses").await.is_some());
+    cache.delete("spotifier-core-other:courses").await.unwrap();
+}
@@ -0,0 +1,53 @@
+// tests/replay_test.rs
+
+mod common;
+
+use common::replay_fixture;
+use spotifier_core::{ReplayTransport, Result, ScraperError};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+const DASHBOARD_HTML: &str = r#"<html><body><table><tbody><tr>
+  <td>IK410</td><td><a href="/mhs/topik/2510009532">Pemrograman Web</a></td>
+  <td>3</td><td>Dr. Budi Santoso</td><td>2025/2026 - Ganjil</td>
+</tr></tbody></table></body></html>"#;
+
+/// Offline port of `integration_test::test_get_course_detail_by_id`.
+#[tokio::test]
+async fn test_get_course_detail_by_id_replayed() -> Result<()> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures/recordings/course_detail_by_id");
+    let mut transport = ReplayTransport::from_dir(&dir)?;
+    // The fixture has no dashboard capture, so the course list is a hand-written page.
+    transport.insert(common::recording(
+        "GET",
+        "https://spot.upi.edu/mhs",
+        "https://spot.upi.edu/mhs",
+        DASHBOARD_HTML,
+    ));
+    let client = common::client_with(Arc::new(transport));
+    client.login("2301234", "not-the-recorded-password").await?;
+
+    let courses = client.get_courses().await?;
+    assert!(!courses.is_empty(), "Need at least one course for testing");
+    let first_course_id = courses[0].id;
+
+    let course_detail = client.get_course_detail_by_id(first_course_id).await?;
+    assert_eq!(course_detail.course_info.id, first_course_id);
+    assert_eq!(course_detail.course_info.name, "Pemrograman Web");
+    assert_eq!(course_detail.rps.id, Some(98765));
+    assert_eq!(course_detail.topics.len(), 1);
+    assert_eq!(course_detail.topics[0].id, Some(1358801));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unrecorded_request_fails() -> Result<()> {
+    let client = replay_fixture("course_detail_by_id")?;
+    assert!(matches!(
+        client.get_grades().await,
+        Err(ScraperError::ParsingError(message)) if message.contains("/mhs/khs")
+    ));
+    Ok(())
+}
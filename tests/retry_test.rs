@@ -0,0 +1,291 @@
+// tests/retry_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use chrono::{TimeZone, Utc};
+use common::{MockResponse, MockTransport};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{
+    HttpTransport, RetryConfig, ScraperError, SpotifierCoreClient, parse_retry_after, with_retry,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+fn timeout_error() -> ScraperError {
+    ScraperError::Timeout {
+        url: "https://spot.upi.edu/mhs".to_string(),
+        elapsed: Duration::from_secs(30),
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_with_retry_recovers_after_transient_failures() {
+    let config = RetryConfig {
+        max_retries: 3,
+        initial_backoff_ms: 100,
+        max_backoff_ms: 1000,
+        ..RetryConfig::default()
+    };
+    let attempts = AtomicU32::new(0);
+    let start = Instant::now();
+
+    let result = with_retry(&config, || async {
+        if attempts.fetch_add(1, Ordering::SeqCst) < 2 {
+            Err(timeout_error())
+        } else {
+            Ok("done")
+        }
+    })
+    .await;
+
+    assert_eq!(result.unwrap(), "done");
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    // Backoff of 100ms then 200ms before the successful third attempt
+    assert_eq!(start.elapsed(), Duration::from_millis(300));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_with_retry_gives_up_and_skips_permanent_errors() {
+    let config = RetryConfig {
+        max_retries: 2,
+        initial_backoff_ms: 100,
+        max_backoff_ms: 1000,
+        ..RetryConfig::default()
+    };
+
+    let attempts = AtomicU32::new(0);
+    let result: spotifier_core::Result<()> = with_retry(&config, || async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(timeout_error())
+    })
+    .await;
+    assert!(matches!(result, Err(ScraperError::Timeout { .. })));
+    assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+    let attempts = AtomicU32::new(0);
+    let result: spotifier_core::Result<()> = with_retry(&config, || async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(ScraperError::SessionExpired)
+    })
+    .await;
+    assert!(matches!(result, Err(ScraperError::SessionExpired)));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_jittered_backoff_stays_within_bounds() {
+    let config = RetryConfig {
+        initial_backoff_ms: 100,
+        max_backoff_ms: 1000,
+        ..RetryConfig::default()
+    };
+    let mut rng = StdRng::seed_from_u64(7);
+
+    for retry in 0..6 {
+        let full = config.backoff(retry);
+        let jittered = config.jittered_backoff(retry, &mut rng);
+        assert!(jittered >= full / 2 && jittered <= full);
+    }
+}
+
+#[test]
+fn test_http_status_retryability() {
+    let status = |status| ScraperError::HttpStatus {
+        status,
+        url: "https://spot.upi.edu/mhs".to_string(),
+        body_snippet: String::new(),
+    };
+    assert!(status(503).is_retryable());
+    assert!(!status(404).is_retryable());
+    assert!(
+        !ScraperError::RetriesExhausted {
+            attempts: 3,
+            last: Box::new(status(503)),
+        }
+        .is_retryable()
+    );
+}
+
+/// Serves `503 Service Unavailable` for the first `failures` requests, then a small file.
+fn flaky_transport(failures: usize) -> Arc<MockTransport> {
+    let hits = AtomicUsize::new(0);
+    MockTransport::new(move |_| {
+        if hits.fetch_add(1, Ordering::SeqCst) < failures {
+            MockResponse::status(503)
+        } else {
+            MockResponse::bytes(b"%PDF".to_vec()).header("content-type", "application/pdf")
+        }
+    })
+}
+
+fn retrying_client(transport: Arc<MockTransport>, max_retries: u32) -> SpotifierCoreClient {
+    let mut client = common::client_with(transport);
+    client.set_retry_config(RetryConfig {
+        max_retries,
+        initial_backoff_ms: 10,
+        max_backoff_ms: 20,
+        ..RetryConfig::default()
+    });
+    client
+}
+
+#[tokio::test]
+async fn test_client_retries_503_until_success() {
+    let transport = flaky_transport(2);
+
+    let bytes = retrying_client(transport.clone(), 3)
+        .download_file("/files/rps.pdf")
+        .await
+        .unwrap();
+    assert_eq!(&bytes[..], b"%PDF");
+    assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn test_client_reports_exhausted_retries() {
+    let transport = flaky_transport(usize::MAX);
+
+    match retrying_client(transport.clone(), 1)
+        .download_file("/files/rps.pdf")
+        .await
+    {
+        Err(ScraperError::RetriesExhausted { attempts, last }) => {
+            assert_eq!(attempts, 2);
+            assert!(matches!(
+                *last,
+                ScraperError::HttpStatus { status: 503, .. }
+            ));
+        }
+        other => panic!("expected RetriesExhausted, got {:?}", other),
+    }
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[test]
+fn test_parse_retry_after_forms() {
+    let now = Utc.with_ymd_and_hms(2025, 10, 1, 8, 0, 0).unwrap();
+
+    assert_eq!(parse_retry_after("2", now), Some(Duration::from_secs(2)));
+    assert_eq!(
+        parse_retry_after("Wed, 01 Oct 2025 08:01:30 GMT", now),
+        Some(Duration::from_secs(90))
+    );
+    assert_eq!(
+        parse_retry_after("Wed, 01 Oct 2025 07:00:00 GMT", now),
+        Some(Duration::ZERO)
+    );
+    assert_eq!(parse_retry_after("soon", now), None);
+}
+
+/// Answers the first `limited` requests with 429 and `Retry-After: 2`, then with a profile.
+struct RateLimitingTransport {
+    limited: u32,
+    hits: AtomicU32,
+}
+
+#[async_trait]
+impl HttpTransport for RateLimitingTransport {
+    async fn execute(
+        &self,
+        request: reqwest::Request,
+    ) -> spotifier_core::Result<reqwest::Response> {
+        let builder = http::Response::builder().url(request.url().clone());
+        let response = if self.hits.fetch_add(1, Ordering::SeqCst) < self.limited {
+            builder
+                .status(429)
+                .header("Retry-After", "2")
+                .body(String::new())
+        } else {
+            builder.status(200).body(
+                r#"<div class="user-profile"><div class="profile-text">Budi Santoso 2301234</div></div>"#
+                    .to_string(),
+            )
+        };
+        Ok(response.unwrap().into())
+    }
+}
+
+fn rate_limited_client(
+    limited: u32,
+    retry: Option<RetryConfig>,
+) -> (SpotifierCoreClient, Arc<RateLimitingTransport>) {
+    let transport = Arc::new(RateLimitingTransport {
+        limited,
+        hits: AtomicU32::new(0),
+    });
+    let mut builder = common::builder().transport(transport.clone());
+    if let Some(config) = retry {
+        builder = builder.retry(config);
+    }
+    (builder.build().unwrap(), transport)
+}
+
+#[tokio::test]
+async fn test_429_is_reported_as_rate_limited() {
+    let (client, _) = rate_limited_client(1, None);
+
+    match client.get_user_profile().await {
+        Err(ScraperError::RateLimited { retry_after }) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(2)));
+        }
+        other => panic!("expected RateLimited, got {:?}", other),
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_waits_for_retry_after() {
+    let config = RetryConfig {
+        max_retries: 2,
+        initial_backoff_ms: 10,
+        max_backoff_ms: 5_000,
+        ..RetryConfig::default()
+    };
+    let (client, transport) = rate_limited_client(1, Some(config));
+    let start = Instant::now();
+
+    let user = client.get_user_profile().await.unwrap();
+
+    assert_eq!(user.nim, "2301234");
+    assert_eq!(transport.hits.load(Ordering::SeqCst), 2);
+    assert_eq!(start.elapsed(), Duration::from_secs(2));
+}
+
+#[tokio::test(start_paused = true)]
+async fn test_retry_after_beyond_max_backoff_is_returned() {
+    let config = RetryConfig {
+        max_retries: 2,
+        initial_backoff_ms: 10,
+        max_backoff_ms: 1_000,
+        ..RetryConfig::default()
+    };
+    let (client, transport) = rate_limited_client(1, Some(config.clone()));
+    let start = Instant::now();
+
+    // SPOT asks for 2 s, more than the 1 s cap: give up instead of sleeping.
+    assert!(matches!(
+        client.get_user_profile().await,
+        Err(ScraperError::RateLimited {
+            retry_after: Some(retry_after)
+        }) if retry_after == Duration::from_secs(2)
+    ));
+    assert_eq!(transport.hits.load(Ordering::SeqCst), 1);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+
+    // `with_retry` applies the same cap.
+    let attempts = AtomicU32::new(0);
+    let result: spotifier_core::Result<()> = with_retry(&config, || async {
+        attempts.fetch_add(1, Ordering::SeqCst);
+        Err(ScraperError::RateLimited {
+            retry_after: Some(Duration::from_secs(3600)),
+        })
+    })
+    .await;
+    assert!(matches!(result, Err(ScraperError::RateLimited { .. })));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    assert_eq!(start.elapsed(), Duration::ZERO);
+}
@@ -0,0 +1,80 @@
+// tests/rps_test.rs
+
+mod common;
+
+use async_trait::async_trait;
+use reqwest::ResponseBuilderExt;
+use spotifier_core::{HttpTransport, Result, Rps, ScraperError, SpotifierCoreClient};
+use std::sync::Arc;
+
+const RPS_PDF: &[u8] = include_bytes!("fixtures/rps.pdf");
+
+/// Serves `body` as a file at `/rps/IK410.pdf`.
+struct RpsTransport {
+    content_type: &'static str,
+    body: &'static [u8],
+}
+
+#[async_trait]
+impl HttpTransport for RpsTransport {
+    async fn execute(&self, request: reqwest::Request) -> Result<reqwest::Response> {
+        assert_eq!(request.url().path(), "/rps/IK410.pdf");
+        let response = http::Response::builder()
+            .url(request.url().clone())
+            .status(200)
+            .header("content-type", self.content_type)
+            .body(self.body)
+            .unwrap();
+        Ok(response.into())
+    }
+}
+
+fn rps_client(content_type: &'static str, body: &'static [u8]) -> Result<SpotifierCoreClient> {
+    common::builder()
+        .transport(Arc::new(RpsTransport { content_type, body }))
+        .build()
+}
+
+fn rps(href: Option<&str>) -> Rps {
+    Rps {
+        id: Some(77),
+        href: href.map(String::from),
+    }
+}
+
+#[tokio::test]
+async fn test_download_rps_returns_file_bytes() -> Result<()> {
+    let client = rps_client("application/pdf", RPS_PDF)?;
+    let bytes = client.download_rps(&rps(Some("/rps/IK410.pdf"))).await?;
+    assert_eq!(bytes, RPS_PDF);
+
+    assert!(matches!(
+        client.download_rps(&rps(None)).await,
+        Err(ScraperError::RpsNotAvailable(77))
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "pdf")]
+#[tokio::test]
+async fn test_get_rps_text_extracts_pdf_text() -> Result<()> {
+    let client = rps_client("application/pdf", RPS_PDF)?;
+    let text = client.get_rps_text(&rps(Some("/rps/IK410.pdf"))).await?;
+    assert!(text.contains("RPS Pemrograman Web"), "{text:?}");
+
+    let client = rps_client("application/octet-stream", b"not a pdf")?;
+    assert!(matches!(
+        client.get_rps_text(&rps(Some("/rps/IK410.pdf"))).await,
+        Err(ScraperError::ParsingError(_))
+    ));
+
+    // A truncated PDF fails (or panics) in the extractor; either way it is a parse error.
+    let client = rps_client("application/pdf", &RPS_PDF[..RPS_PDF.len() / 3])?;
+    assert!(matches!(
+        client.get_rps_text(&rps(Some("/rps/IK410.pdf"))).await,
+        Err(ScraperError::ParsingError(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// tests/schedule_test.rs
+
+use chrono::{NaiveTime, Weekday};
+use spotifier_core::Result;
+use spotifier_core::parsers::schedule::{parse_schedule_from_html, parse_weekday};
+
+const SCHEDULE_HTML: &str = r#"
+<html><body>
+  <table class="table table-bordered">
+    <thead>
+      <tr><th>Hari</th><th>Jam</th><th>Kode</th><th>Mata Kuliah</th><th>Ruang</th><th>Dosen</th></tr>
+    </thead>
+    <tbody>
+      <tr>
+        <td rowspan="2">Senin</td><td>07:00 - 08:40</td><td>IK410</td>
+        <td>Pemrograman Web</td><td>FPMIPA-C 301</td><td>Dr. Budi</td>
+      </tr>
+      <tr>
+        <td>10:20 - 12:00, 13:00 - 14:40</td><td>IK420</td>
+        <td>Kecerdasan Buatan</td><td>Lab RPL</td><td>Dr. Siti</td>
+      </tr>
+      <tr>
+        <td>Selasa</td><td>-</td><td>-</td><td>Tidak ada kuliah</td><td></td><td></td>
+      </tr>
+      <tr>
+        <td>Jum'at</td><td>13.00 - 14.40</td><td>IK430</td>
+        <td>Basis Data</td><td>FPMIPA-C 205</td><td>Dr. Andi</td>
+      </tr>
+    </tbody>
+  </table>
+</body></html>
+"#;
+
+fn time(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+#[test]
+fn test_parse_schedule() -> Result<()> {
+    let entries = parse_schedule_from_html(SCHEDULE_HTML)?;
+    assert_eq!(entries.len(), 3, "The placeholder row is skipped");
+
+    let web = &entries[0];
+    assert_eq!(web.course_code, "IK410");
+    assert_eq!(web.course_name, "Pemrograman Web");
+    assert_eq!(web.day_of_week, Weekday::Mon);
+    assert_eq!((web.start_time, web.end_time), (time(7, 0), time(8, 40)));
+    assert_eq!(web.room, "FPMIPA-C 301");
+    assert_eq!(web.lecturer, "Dr. Budi");
+
+    // Inherits "Senin" from the merged cell and spans two slots.
+    let ai = &entries[1];
+    assert_eq!(ai.day_of_week, Weekday::Mon);
+    assert_eq!((ai.start_time, ai.end_time), (time(10, 20), time(14, 40)));
+    assert_eq!(ai.room, "Lab RPL");
+
+    let db = &entries[2];
+    assert_eq!(db.day_of_week, Weekday::Fri);
+    assert_eq!((db.start_time, db.end_time), (time(13, 0), time(14, 40)));
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_weekday() {
+    assert_eq!(parse_weekday("Senin"), Some(Weekday::Mon));
+    assert_eq!(parse_weekday(" KAMIS "), Some(Weekday::Thu));
+    assert_eq!(parse_weekday("Jumat"), Some(Weekday::Fri));
+    assert_eq!(parse_weekday("Monday"), None);
+}
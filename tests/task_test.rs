@@ -1,8 +1,8 @@
 // tests/task_test.rs
 
 use dotenvy::from_path;
-use spotifier_core::{Result, Semester, SpotifierCoreClient, TaskStatus};
-use std::env;
+use spotifier_core::parsers::topic_detail::parse_topic_detail_from_html;
+use spotifier_core::{Credentials, Result, ScraperError, Semester, SpotifierCoreClient};
 use std::path::PathBuf;
 
 #[tokio::test]
@@ -10,11 +10,10 @@ async fn test_task_submission_with_file() -> Result<()> {
     let env_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(".env");
     from_path(&env_path).ok();
 
-    let nim = env::var("SPOT_NIM").expect("SPOT_NIM env var not set");
-    let password = env::var("SPOT_PASSWORD").expect("SPOT_PASSWORD env var not set");
+    let credentials = Credentials::from_env().expect("SPOT_NIM and SPOT_PASSWORD must be set");
 
     let client = SpotifierCoreClient::new();
-    client.login(&nim, &password).await?;
+    client.login_with(&credentials).await?;
 
     // Step 1: Switch to 2025 Odd
     println!("Switching to 2025 Odd semester...");
@@ -44,15 +43,13 @@ async fn test_task_submission_with_file() -> Result<()> {
     println!("Found task: {} (Status: {:?})", task.title, task.status);
 
     // If already submitted, delete it first to ensure clean test
-    if let Some(answer) = &task.answer {
-        if let Some(answer_id) = answer.id {
-            println!("Cleaning up existing submission with ID {}...", answer_id);
-            client
-                .delete_task_submission(course_id, topic_id, answer_id)
-                .await?;
-            // Wait a bit after deletion
-            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
-        }
+    if let Some(answer) = &task.answer
+        && let Some(answer_id) = answer.id
+    {
+        println!("Cleaning up existing submission with ID {}...", answer_id);
+        client
+            .delete_task_submission_verified(course_id, topic_id, answer_id)
+            .await?;
     }
 
     // Step 3: Submit Task (Create) with File
@@ -94,8 +91,10 @@ async fn test_task_submission_with_file() -> Result<()> {
         test_content, file_name
     );
 
-    client
-        .submit_task(
+    // The answer is read from SPOT's response to the upload (re-fetching the topic only
+    // if that page does not show it), so there is no need to wait and poll.
+    let answer = client
+        .submit_task_returning(
             course_id,
             topic_id,
             task_id,
@@ -105,37 +104,89 @@ async fn test_task_submission_with_file() -> Result<()> {
             Some(file_data),
         )
         .await?;
-    println!("✅ Task submitted successfully. Waiting 3s for server to sync...");
-    tokio::time::sleep(std::time::Duration::from_secs(3)).await;
-
-    // Re-fetch to verify status
-    let updated_topic = client.get_topic_detail_by_id(course_id, topic_id).await?;
-    let updated_task = &updated_topic.tasks[0];
-
-    if !matches!(updated_task.status, TaskStatus::Submitted) {
-        println!("DEBUG: Task detail after re-fetch: {:#?}", updated_task);
-    }
-
-    assert!(
-        matches!(updated_task.status, TaskStatus::Submitted),
-        "Task status should be Submitted after re-fetch. Current: {:?}",
-        updated_task.status
-    );
-
-    let answer = updated_task
-        .answer
-        .as_ref()
-        .expect("Answer should be present after submission");
-
+    println!("✅ Task submitted successfully: {:?}", answer.id);
+
+    assert!(answer.id.is_some(), "The new submission should have an ID");
+    // An id-only answer means SPOT's response did not show it; read it from the topic.
+    let answer = if answer.content.is_empty() {
+        let topic = client.get_topic_detail_by_id(course_id, topic_id).await?;
+        topic
+            .tasks
+            .iter()
+            .filter_map(|task| task.answer.clone())
+            .find(|submitted| submitted.id == answer.id)
+            .expect("The new submission should be listed on the topic")
+    } else {
+        answer
+    };
     assert!(
         answer.file_href.is_some(),
         "File href should be present after upload"
     );
     println!("✅ Task status verified: Submitted with file");
     println!(
-        "Check the submission manually at: https://spot.upi.edu/mhs/topik/{}/{}",
-        course_id, topic_id
+        "Check the submission manually at: {}",
+        client.topic_url(course_id, topic_id)
     );
 
     Ok(())
 }
+
+const ESSAY_TASK_HTML: &str = r#"
+<html><body>
+  <div id="tugas">
+    <table class="table table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Esai Refleksi</td></tr>
+        <tr><td>Deskripsi</td><td>: Tulis refleksi pembelajaran minggu ini</td></tr>
+      </tbody>
+    </table>
+    <div class="modal">
+      <input type="hidden" name="_token" value="csrf-token">
+      <input type="hidden" name="id_tg" value="4242">
+      <textarea name="isi" minlength="10" maxlength="20"></textarea>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_essay_length_limits() -> Result<()> {
+    let topic = parse_topic_detail_from_html(ESSAY_TASK_HTML, 1358801, 2510009532)?;
+    let task = &topic.tasks[0];
+    assert_eq!(task.id, Some(4242));
+    assert_eq!(task.min_chars, Some(10));
+    assert_eq!(task.max_chars, Some(20));
+
+    // Under the minimum
+    assert!(matches!(
+        task.validate_content("pendek"),
+        Err(ScraperError::ContentLengthInvalid {
+            min: Some(10),
+            max: Some(20),
+            actual: 6
+        })
+    ));
+    // Over the maximum (characters, not bytes)
+    assert!(matches!(
+        task.validate_content(&"é".repeat(21)),
+        Err(ScraperError::ContentLengthInvalid { actual: 21, .. })
+    ));
+    // Within the limits
+    assert!(task.validate_content("cukup panjang").is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_unconstrained_task_accepts_any_length() -> Result<()> {
+    let html = ESSAY_TASK_HTML.replace(r#" minlength="10" maxlength="20""#, "");
+    let topic = parse_topic_detail_from_html(&html, 1358801, 2510009532)?;
+    let task = &topic.tasks[0];
+    assert_eq!(task.min_chars, None);
+    assert_eq!(task.max_chars, None);
+    assert!(task.validate_content("").is_ok());
+    assert!(task.validate_content(&"x".repeat(10_000)).is_ok());
+
+    Ok(())
+}
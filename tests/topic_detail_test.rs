@@ -0,0 +1,387 @@
+// tests/topic_detail_test.rs
+
+use spotifier_core::parsers::parse_youtube_url;
+use spotifier_core::parsers::topic_detail::parse_topic_detail_from_html;
+use spotifier_core::{ContentKind, Result, RubricItem, TaskStatus};
+
+const DUPLICATED_CONTENT_HTML: &str = r#"
+<html><body>
+  <div id="materi">
+    <div class="row">
+      <div class="col-lg-12">
+        <iframe src="https://www.youtube.com/embed/abc123DEF_0?rel=0"></iframe>
+      </div>
+      <div class="col-lg-12"><p>Bacaan minggu ini</p></div>
+      <div class="col-lg-12">
+          <iframe src="https://www.youtube.com/embed/abc123DEF_0?rel=0"></iframe>
+      </div>
+      <div class="col-lg-12"><p>Bacaan   minggu ini</p></div>
+      <div class="col-lg-12"><p>Materi tambahan</p></div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_duplicate_contents_are_collapsed() -> Result<()> {
+    let topic = parse_topic_detail_from_html(DUPLICATED_CONTENT_HTML, 1, 2)?;
+
+    assert_eq!(topic.contents.len(), 3, "Duplicated blocks should collapse");
+
+    let ids: Vec<u32> = topic.contents.iter().map(|c| c.id).collect();
+    assert_eq!(ids, vec![0, 1, 4], "First occurrences keep their position");
+
+    assert_eq!(topic.contents[0].youtube_id.as_deref(), Some("abc123DEF_0"));
+    assert!(topic.contents[1].raw_html.contains("Bacaan minggu ini"));
+    assert!(topic.contents[2].raw_html.contains("Materi tambahan"));
+
+    Ok(())
+}
+
+const QUIZ_HTML: &str = r#"
+<html><body>
+  <div id="kuis">
+    <table class="table table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Kuis Pertemuan 3</td></tr>
+        <tr><td>Batas Waktu</td><td>: 20-09-2025 23:59</td></tr>
+        <tr><td>Percobaan</td><td>: 2 / 3</td></tr>
+        <tr><td>Nilai Tertinggi</td><td>: 87,5</td></tr>
+      </tbody>
+    </table>
+    <a class="btn btn-info" href="/mhs/kuis/2510009532/1358801/7001">Kerjakan</a>
+    <table class="table table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Kuis Pertemuan 4</td></tr>
+        <tr><td>Batas Waktu</td><td>: 27-09-2025 23:59</td></tr>
+        <tr><td>Jumlah Percobaan</td><td>: 1</td></tr>
+        <tr><td>Nilai Tertinggi</td><td>: -</td></tr>
+      </tbody>
+    </table>
+    <a class="btn btn-info" href="/mhs/kuis/2510009532/1358801/7002">Kerjakan</a>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_quizzes_are_parsed() -> Result<()> {
+    let topic = parse_topic_detail_from_html(QUIZ_HTML, 1358801, 2510009532)?;
+    assert_eq!(topic.quizzes.len(), 2);
+
+    let attempted = &topic.quizzes[0];
+    assert_eq!(attempted.id, Some(7001));
+    assert_eq!(attempted.title, "Kuis Pertemuan 3");
+    assert!(attempted.due_date.is_some());
+    assert_eq!(attempted.attempts_used, 2);
+    assert_eq!(attempted.attempts_allowed, Some(3));
+    assert_eq!(attempted.best_score, Some(87.5));
+    assert_eq!(
+        attempted.href.as_deref(),
+        Some("/mhs/kuis/2510009532/1358801/7001")
+    );
+
+    let fresh = &topic.quizzes[1];
+    assert_eq!(fresh.id, Some(7002));
+    assert_eq!(fresh.attempts_used, 0);
+    assert_eq!(fresh.attempts_allowed, Some(1));
+    assert_eq!(fresh.best_score, None);
+
+    Ok(())
+}
+
+const FORMATTED_SUBMISSION_HTML: &str = r#"
+<html><body>
+  <div id="tugas">
+    <table class="table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Esai 1</td></tr>
+      </tbody>
+    </table>
+    <div class="panel panel-info">
+      <table>
+        <tr><td>Waktu Pengumpulan</td><td>: 10-09-2025 13:30</td></tr>
+      </table>
+      <div class="panel-body">
+        <p>Jawaban saya: <b>HTTP</b> &amp; <i>HTML</i></p>
+        <ul><li>Poin satu</li></ul>
+        <a href="/tugas/mhs/jawaban.pdf">jawaban.pdf</a>
+        <a class="btn btn-danger" href="/mhs/tugas_del/1/2/777">Hapus</a>
+      </div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_formatted_submission_keeps_html() -> Result<()> {
+    let topic = parse_topic_detail_from_html(FORMATTED_SUBMISSION_HTML, 2, 1)?;
+    let answer = topic.tasks[0].answer.as_ref().expect("answer");
+
+    assert!(
+        answer
+            .content
+            .starts_with("<p>Jawaban saya: <b>HTTP</b> &amp; <i>HTML</i></p>")
+    );
+    assert!(answer.content.contains("<li>Poin satu</li>"));
+    assert!(
+        !answer.content.contains("Hapus"),
+        "Action links are not content"
+    );
+    assert_eq!(answer.content_text(), "Jawaban saya: HTTP & HTML Poin satu");
+    assert_eq!(answer.char_count(), 35);
+    assert_eq!(answer.id, Some(777));
+    assert_eq!(answer.file_href.as_deref(), Some("/tugas/jawaban.pdf"));
+
+    Ok(())
+}
+
+const RUBRIC_GRADED_HTML: &str = r#"
+<html><body>
+  <div id="tugas">
+    <table class="table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Laporan Praktikum</td></tr>
+      </tbody>
+    </table>
+    <div class="panel panel-info">
+      <table>
+        <tr><td>Waktu Pengumpulan</td><td>: 10-09-2025 13:30</td></tr>
+        <tr><td>Nilai</td><td>: 82,5</td></tr>
+        <tr><td>Catatan</td><td>: Analisis perlu diperdalam</td></tr>
+      </table>
+      <table class="table table-bordered">
+        <thead>
+          <tr><th>Kriteria</th><th>Nilai</th><th>Nilai Maksimal</th></tr>
+        </thead>
+        <tbody>
+          <tr><td>Kelengkapan</td><td>30</td><td>30</td></tr>
+          <tr><td>Nilai</td><td>32,5</td><td>40</td></tr>
+          <tr><td>Analisis</td><td>20</td><td>30</td></tr>
+          <tr><td>Total</td><td>82,5</td><td>100</td></tr>
+        </tbody>
+      </table>
+      <div class="panel-body">laporan terlampir</div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+const PLAIN_GRADED_HTML: &str = r#"
+<html><body>
+  <div id="tugas">
+    <table class="table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Esai 2</td></tr>
+      </tbody>
+    </table>
+    <div class="panel panel-info">
+      <table>
+        <tr><td>Waktu Pengumpulan</td><td>: 10-09-2025 13:30</td></tr>
+        <tr><td>Nilai</td><td>: 90</td></tr>
+      </table>
+      <div class="panel-body">jawaban esai</div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_rubric_graded_answer() -> Result<()> {
+    let topic = parse_topic_detail_from_html(RUBRIC_GRADED_HTML, 2, 1)?;
+    let task = &topic.tasks[0];
+    let answer = task.answer.as_ref().expect("answer");
+
+    assert_eq!(task.status, TaskStatus::Graded);
+    assert!(answer.is_graded);
+    assert_eq!(answer.score, 82.5);
+    assert_eq!(answer.lecturer_notes, "Analisis perlu diperdalam");
+    assert_eq!(
+        answer.rubric,
+        vec![
+            RubricItem {
+                name: "Kelengkapan".to_string(),
+                points: 30.0,
+                max_points: 30.0,
+            },
+            RubricItem {
+                name: "Nilai".to_string(),
+                points: 32.5,
+                max_points: 40.0,
+            },
+            RubricItem {
+                name: "Analisis".to_string(),
+                points: 20.0,
+                max_points: 30.0,
+            },
+        ]
+    );
+    assert_eq!(answer.content, "laporan terlampir");
+
+    Ok(())
+}
+
+#[test]
+fn test_rubric_combined_points_cell() -> Result<()> {
+    let html = RUBRIC_GRADED_HTML
+        .replace("<th>Nilai Maksimal</th>", "")
+        .replace("<td>30</td><td>30</td>", "<td>30 / 30</td>")
+        .replace("<td>32,5</td><td>40</td>", "<td>32,5/40</td>")
+        .replace("<td>20</td><td>30</td>", "<td>20/30</td>");
+    let topic = parse_topic_detail_from_html(&html, 2, 1)?;
+    let rubric = &topic.tasks[0].answer.as_ref().expect("answer").rubric;
+
+    assert_eq!(rubric.len(), 3);
+    assert_eq!((rubric[1].points, rubric[1].max_points), (32.5, 40.0));
+
+    Ok(())
+}
+
+#[test]
+fn test_plain_graded_answer_has_no_rubric() -> Result<()> {
+    let topic = parse_topic_detail_from_html(PLAIN_GRADED_HTML, 2, 1)?;
+    let answer = topic.tasks[0].answer.as_ref().expect("answer");
+
+    assert!(answer.is_graded);
+    assert_eq!(answer.score, 90.0);
+    assert!(answer.rubric.is_empty());
+
+    Ok(())
+}
+
+const CONTENT_KINDS_HTML: &str = r#"
+<html><body>
+  <div id="materi">
+    <div class="row">
+      <div class="col-lg-12"><iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ"></iframe></div>
+      <div class="col-lg-12"><p>Baca <a href="https://developer.mozilla.org/">MDN</a> sebelum kelas.</p></div>
+      <div class="col-lg-12">
+        <a href="/materi/mhs/Modul%201%20-%20HTML.pdf" target="_blank"><i class="fa fa-file-pdf-o"></i> Modul 1 - HTML</a>
+      </div>
+      <div class="col-lg-12"><a href="/download/materi/991"></a></div>
+      <div class="col-lg-12"><a href="https://meet.google.com/abc-defg-hij">Link kelas daring</a></div>
+      <div class="col-lg-12"><iframe src="https://docs.google.com/presentation/d/1x/embed"></iframe></div>
+      <div class="col-lg-12"><a href="forum/7#balasan">Forum diskusi</a></div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_content_kinds() -> Result<()> {
+    let topic = parse_topic_detail_from_html(CONTENT_KINDS_HTML, 2, 1)?;
+    let kinds: Vec<ContentKind> = topic.contents.iter().map(|c| c.kind()).collect();
+
+    assert_eq!(
+        kinds[0],
+        ContentKind::Video {
+            youtube_id: "dQw4w9WgXcQ".to_string()
+        }
+    );
+    assert!(matches!(&kinds[1], ContentKind::Article { html } if html.contains("sebelum kelas")));
+    assert_eq!(
+        kinds[2],
+        ContentKind::File {
+            href: "/materi/mhs/Modul%201%20-%20HTML.pdf".to_string(),
+            name: "Modul 1 - HTML".to_string(),
+        }
+    );
+    assert_eq!(
+        kinds[3],
+        ContentKind::File {
+            href: "/download/materi/991".to_string(),
+            name: "991".to_string(),
+        }
+    );
+    assert_eq!(
+        kinds[4],
+        ContentKind::Link {
+            url: "https://meet.google.com/abc-defg-hij".to_string()
+        }
+    );
+    assert_eq!(kinds[5], ContentKind::Unknown);
+    assert_eq!(
+        kinds[6],
+        ContentKind::Link {
+            url: "/mhs/topik/1/forum/7".to_string()
+        }
+    );
+
+    // The existing fields are unchanged.
+    assert_eq!(topic.contents[0].youtube_id.as_deref(), Some("dQw4w9WgXcQ"));
+    assert_eq!(topic.contents[2].youtube_id, None);
+    assert!(topic.contents[2].raw_html.contains("Modul 1 - HTML"));
+
+    Ok(())
+}
+
+const YOUTUBE_VARIANTS_HTML: &str = r#"
+<html><body>
+  <div id="materi">
+    <div class="row">
+      <div class="col-lg-12"><iframe src="https://www.youtube.com/embed/dQw4w9WgXcQ?rel=0&start=90"></iframe></div>
+      <div class="col-lg-12"><iframe src="//www.youtube-nocookie.com/embed/M7lc1UVf-VE"></iframe></div>
+      <div class="col-lg-12"><iframe src="https://youtu.be/aqz-KE-bpKQ?t=1m5s"></iframe></div>
+      <div class="col-lg-12"><iframe src="https://www.youtube.com/watch?v=9bZkp7q19f0&list=PL123"></iframe></div>
+      <div class="col-lg-12"><iframe src="https://www.youtube.com/embed/short?rel=0"></iframe></div>
+    </div>
+  </div>
+</body></html>
+"#;
+
+#[test]
+fn test_youtube_embed_variants() -> Result<()> {
+    let topic = parse_topic_detail_from_html(YOUTUBE_VARIANTS_HTML, 1, 2)?;
+    let videos: Vec<(Option<&str>, Option<u32>)> = topic
+        .contents
+        .iter()
+        .map(|c| (c.youtube_id.as_deref(), c.youtube_start_secs))
+        .collect();
+
+    assert_eq!(
+        videos,
+        vec![
+            (Some("dQw4w9WgXcQ"), Some(90)),
+            (Some("M7lc1UVf-VE"), None),
+            (Some("aqz-KE-bpKQ"), Some(65)),
+            (Some("9bZkp7q19f0"), None),
+            (None, None),
+        ]
+    );
+
+    let first = &topic.contents[0];
+    assert_eq!(
+        first.youtube_watch_url().as_deref(),
+        Some("https://www.youtube.com/watch?v=dQw4w9WgXcQ&t=90s")
+    );
+    assert_eq!(
+        first.youtube_embed_url().as_deref(),
+        Some("https://www.youtube.com/embed/dQw4w9WgXcQ?start=90")
+    );
+    assert_eq!(
+        topic.contents[1].youtube_embed_url().as_deref(),
+        Some("https://www.youtube.com/embed/M7lc1UVf-VE")
+    );
+    assert_eq!(topic.contents[4].youtube_watch_url(), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_parse_youtube_url_rejects_malformed() {
+    assert_eq!(
+        parse_youtube_url("https://vimeo.com/embed/dQw4w9WgXcQ"),
+        None
+    );
+    assert_eq!(
+        parse_youtube_url("https://www.youtube.com/embed/dQw4w9WgX!Q"),
+        None
+    );
+    assert_eq!(
+        parse_youtube_url("https://www.youtube.com/channel/dQw4w9WgXcQ"),
+        None
+    );
+    assert_eq!(
+        parse_youtube_url("youtube.com/watch?v=dQw4w9WgXcQ&t=abc"),
+        Some(("dQw4w9WgXcQ".to_string(), None))
+    );
+}
@@ -0,0 +1,63 @@
+// tests/tracing_test.rs
+#![cfg(feature = "tracing")]
+
+use spotifier_core::{ScraperError, SpotifierCoreClient};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::{LookupSpan, Registry};
+
+/// Records the name and fields of every span, and the fields of every event.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+struct FieldsToString<'a>(&'a mut String);
+
+impl Visit for FieldsToString<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push_str(&format!(" {}={:?}", field.name(), value));
+    }
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Capture {
+    fn on_new_span(&self, attrs: &Attributes<'_>, _id: &Id, _ctx: Context<'_, S>) {
+        let mut line = format!("span {}", attrs.metadata().name());
+        attrs.record(&mut FieldsToString(&mut line));
+        self.0.lock().unwrap().push(line);
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let parent = ctx
+            .event_span(event)
+            .map(|span| span.name())
+            .unwrap_or("none");
+        let mut line = format!("event in {}", parent);
+        event.record(&mut FieldsToString(&mut line));
+        self.0.lock().unwrap().push(line);
+    }
+}
+
+#[tokio::test]
+async fn test_login_emits_span_without_password() {
+    let capture = Capture::default();
+    let _guard = tracing::subscriber::set_default(Registry::default().with(capture.clone()));
+
+    let client = SpotifierCoreClient::new();
+    let result = client.login("   ", "hunter2-secret").await;
+    assert!(matches!(
+        result,
+        Err(ScraperError::MissingCredentials("NIM"))
+    ));
+
+    let lines = capture.0.lock().unwrap().clone();
+    assert!(lines.iter().any(|line| line.starts_with("span login")));
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.starts_with("event in login") && line.contains("login failed"))
+    );
+    assert!(lines.iter().all(|line| !line.contains("hunter2-secret")));
+}
@@ -0,0 +1,499 @@
+// tests/upload_test.rs
+
+mod common;
+
+use common::{MockResponse, MockTransport};
+use spotifier_core::parsers::topic_detail::parse_submitted_answer;
+use spotifier_core::{
+    DEFAULT_UPLOAD_TYPES, Recording, ScraperError, SpotifierCoreClient, detect_mime_type,
+};
+use std::sync::Arc;
+
+/// A client that serves `body` as a download with the given `Content-Disposition`.
+fn download_client(body: &'static [u8], disposition: &'static str) -> SpotifierCoreClient {
+    common::client_with(MockTransport::new(move |_| {
+        MockResponse::bytes(body.to_vec())
+            .header("content-type", "application/pdf")
+            .header("content-disposition", disposition)
+    }))
+}
+
+#[tokio::test]
+async fn test_download_file_returns_the_body() {
+    let transport = MockTransport::new(|_| {
+        MockResponse::bytes(b"%PDF-1.4 modul".to_vec()).header("content-type", "application/pdf")
+    });
+    let client = common::client_with(transport.clone());
+
+    let bytes = client.download_file("/mhs/tugas/modul.pdf").await.unwrap();
+    assert_eq!(&bytes[..], b"%PDF-1.4 modul");
+    assert_eq!(transport.last_request().path(), "/mhs/tugas/modul.pdf");
+}
+
+#[tokio::test]
+async fn test_download_file_to_names_files_from_the_response() {
+    let dir = std::env::temp_dir().join(format!("spotifier-download-names-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let cases = [
+        (r#"attachment; filename="Modul 1.pdf""#, "Modul 1.pdf"),
+        (
+            "attachment; filename=\"Modul.pdf\"; filename*=UTF-8''Modul%20%E2%80%93%202.pdf",
+            "Modul – 2.pdf",
+        ),
+        // Without a file name the last URL segment is used.
+        ("inline", "modul.pdf"),
+    ];
+    for (disposition, expected) in cases {
+        let client = download_client(b"%PDF-1.4", disposition);
+        let path = client
+            .download_file_to("/mhs/tugas/modul.pdf", &dir)
+            .await
+            .unwrap();
+        assert_eq!(path, dir.join(expected));
+        assert_eq!(std::fs::read(&path).unwrap(), b"%PDF-1.4");
+    }
+
+    // A file path is used as is.
+    let target = dir.join("saved.pdf");
+    let client = download_client(b"%PDF-1.4", r#"attachment; filename="other.pdf""#);
+    let path = client
+        .download_file_to("/mhs/tugas/modul.pdf", &target)
+        .await
+        .unwrap();
+    assert_eq!(path, target);
+
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[tokio::test]
+async fn test_download_error_status_keeps_the_body_snippet() {
+    let transport = MockTransport::new(|_| MockResponse {
+        status: 404,
+        ..MockResponse::bytes(b"File tidak ditemukan".to_vec())
+    });
+    let client = common::builder()
+        .transport(transport)
+        .error_body_snippets(true)
+        .build()
+        .unwrap();
+
+    match client.download_file("/mhs/tugas/hilang.pdf").await {
+        Err(ScraperError::HttpStatus {
+            status,
+            url,
+            body_snippet,
+        }) => {
+            assert_eq!(status, 404);
+            assert_eq!(url, "https://spot.upi.edu/mhs/tugas/hilang.pdf");
+            assert_eq!(body_snippet, "File tidak ditemukan");
+        }
+        other => panic!(
+            "expected an HTTP status error, got {:?}",
+            other.map(|bytes| bytes.len())
+        ),
+    }
+}
+
+#[tokio::test]
+async fn test_download_of_login_page_reports_expired_session() {
+    let client = common::client_with(MockTransport::new(|_| {
+        MockResponse::html("<html>Login</html>")
+    }));
+    assert!(matches!(
+        client.download_file("/mhs/tugas/modul.pdf").await,
+        Err(ScraperError::SessionExpired)
+    ));
+}
+
+#[tokio::test]
+async fn test_download_file_to_strips_server_directories() {
+    let transport = MockTransport::new(|_| {
+        MockResponse::bytes(b"alias ls=rm".to_vec())
+            .header("content-type", "application/octet-stream")
+            .header(
+                "content-disposition",
+                r#"attachment; filename="../../.bashrc""#,
+            )
+    });
+    let client = common::client_with(transport);
+    let dir = std::env::temp_dir().join(format!("spotifier-download-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let path = client.download_file_to("/tugas/1/2/a", &dir).await.unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+    assert_eq!(path, dir.join(".bashrc"));
+}
+
+/// Returns the `Content-Disposition` line of every file part in a multipart body.
+fn file_dispositions(body: &str) -> Vec<&str> {
+    body.lines()
+        .filter(|line| line.starts_with("Content-Disposition:") && line.contains("filename="))
+        .collect()
+}
+
+/// A client whose every request is answered with `response_body`.
+fn upload_client(response_body: &'static str) -> (SpotifierCoreClient, Arc<MockTransport>) {
+    let transport = MockTransport::new(move |_| MockResponse::html(response_body));
+    (common::client_with(transport.clone()), transport)
+}
+
+#[tokio::test]
+async fn test_submit_task_multi_sends_every_file() {
+    let (client, transport) = upload_client("<html><body>Topik</body></html>");
+
+    let files = vec![
+        ("laporan.pdf".to_string(), b"%PDF-1.4".to_vec()),
+        ("data/lampiran – 1.csv".to_string(), b"a,b\n1,2".to_vec()),
+        ("foto.png".to_string(), vec![0x89, b'P', b'N', b'G']),
+    ];
+    client
+        .submit_task_multi(1, 2, 3, "csrf", "Jawaban", files)
+        .await
+        .unwrap();
+
+    let request = transport.last_request();
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path(), "/mhs/tugas_store");
+    let request = request.body_text();
+    let file_names: Vec<&str> = request
+        .split("name=\"filename\"; filename=\"")
+        .skip(1)
+        .filter_map(|part| part.split('"').next())
+        .collect();
+    assert_eq!(file_names, ["laporan.pdf", "lampiran – 1.csv", "foto.png"]);
+    assert!(request.contains("name=\"isi\"\r\n\r\nJawaban"));
+}
+
+#[tokio::test]
+async fn test_non_ascii_file_name_is_sent_with_rfc5987_parameter() {
+    let (client, transport) = upload_client("<html><body>Topik</body></html>");
+
+    let files = vec![
+        ("Tugas Besar – Budi.pdf".to_string(), b"%PDF-1.4".to_vec()),
+        (
+            "C:\\Users\\budi\\Laporan \"Akhir\"\r\nSiti.docx".to_string(),
+            b"PK".to_vec(),
+        ),
+    ];
+    client
+        .submit_task_multi(1, 2, 3, "csrf", "", files)
+        .await
+        .unwrap();
+
+    let body = transport.last_request().body_text();
+    assert_eq!(
+        file_dispositions(&body),
+        [
+            "Content-Disposition: form-data; name=\"filename\"; \
+             filename=\"Tugas Besar – Budi.pdf\"; \
+             filename*=UTF-8''Tugas%20Besar%20%E2%80%93%20Budi.pdf",
+            "Content-Disposition: form-data; name=\"filename\"; \
+             filename=\"Laporan %22Akhir%22  Siti.docx\"",
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_recorded_upload_lists_its_parts() {
+    let dir = std::env::temp_dir().join(format!("spotifier-upload-rec-{}", std::process::id()));
+    std::fs::remove_dir_all(&dir).ok();
+    let transport = MockTransport::new(|_| MockResponse::html("<html><body>Topik</body></html>"));
+    let client = common::builder()
+        .transport(transport)
+        .recording_dir(&dir)
+        .build()
+        .unwrap();
+
+    client
+        .submit_task(
+            1,
+            2,
+            3,
+            "csrf",
+            "Jawaban",
+            Some("tugas.pdf".to_string()),
+            Some(b"%PDF-1.4".to_vec()),
+        )
+        .await
+        .unwrap();
+
+    let recordings: Vec<Recording> = std::fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| {
+            serde_json::from_slice(&std::fs::read(entry.unwrap().path()).unwrap()).unwrap()
+        })
+        .collect();
+    std::fs::remove_dir_all(&dir).ok();
+
+    let upload = recordings.iter().find(|r| r.method == "POST").unwrap();
+    let body = upload.request_body.as_deref().unwrap();
+    assert!(body.contains(r#"["isi","Jawaban"]"#), "{}", body);
+    assert!(body.contains("<file tugas.pdf, 8 bytes>"), "{}", body);
+    assert!(!body.contains("%PDF"));
+}
+
+#[tokio::test]
+async fn test_submit_task_multi_enforces_size_cap() {
+    let mut client = SpotifierCoreClient::new();
+    client.set_max_upload_bytes(10);
+    let start = std::time::Instant::now();
+
+    let files = vec![
+        ("a.txt".to_string(), vec![0; 6]),
+        ("b.txt".to_string(), vec![0; 6]),
+    ];
+    let result = client.submit_task_multi(1, 2, 3, "csrf", "", files).await;
+
+    assert!(matches!(
+        result,
+        Err(ScraperError::UploadTooLarge {
+            total_bytes: 12,
+            limit: 10
+        })
+    ));
+    assert!(
+        start.elapsed().as_millis() < 500,
+        "The cap is checked before any request"
+    );
+}
+
+#[tokio::test]
+async fn test_rejected_upload_reports_spot_message() {
+    let (client, _) = upload_client(
+        r#"<div id="tugas"><div class="modal"><form action="/mhs/tugas_store">
+        <div class="alert alert-danger"><button class="close">×</button>
+        File harus bertipe pdf, docx.</div></form></div></div>"#,
+    );
+
+    let result = client
+        .submit_task(
+            1,
+            2,
+            3,
+            "csrf",
+            "",
+            Some("tugas.exe".to_string()),
+            Some(vec![1]),
+        )
+        .await;
+
+    match result {
+        Err(ScraperError::TaskSubmissionFailed(message)) => {
+            assert_eq!(message, "File harus bertipe pdf, docx.")
+        }
+        other => panic!("Expected a submission failure, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_alert_outside_the_submission_form_is_not_a_rejection() {
+    let (client, _) = upload_client(
+        r#"<div class="panel-body"><div class="alert alert-danger">Batas pengumpulan
+        diperpanjang.</div></div><div id="tugas"><div class="modal">
+        <form action="/mhs/tugas_store"></form></div></div>"#,
+    );
+
+    client
+        .submit_task(1, 2, 3, "csrf", "Jawaban", None, None)
+        .await
+        .unwrap();
+}
+
+const PNG_BYTES: &[u8] = &[
+    0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D, b'I', b'H', b'D', b'R',
+];
+
+#[test]
+fn test_detect_mime_type_ignores_the_name() {
+    assert_eq!(detect_mime_type(PNG_BYTES), Some("image/png"));
+    assert_eq!(detect_mime_type(b"%PDF-1.7\n"), Some("application/pdf"));
+    assert_eq!(detect_mime_type(b"just some text"), None);
+}
+
+#[tokio::test]
+async fn test_upload_part_uses_sniffed_content_type() {
+    let (client, transport) = upload_client("<html><body>Topik</body></html>");
+
+    client
+        .submit_task(
+            1,
+            2,
+            3,
+            "csrf",
+            "",
+            Some("tugas.pdf".to_string()),
+            Some(PNG_BYTES.to_vec()),
+        )
+        .await
+        .unwrap();
+
+    let request = transport.last_request().body_text();
+    assert!(
+        request
+            .contains("name=\"filename\"; filename=\"tugas.pdf\"\r\nContent-Type: image/png\r\n")
+    );
+}
+
+#[tokio::test]
+async fn test_disallowed_file_type_is_rejected_before_upload() {
+    let mut client = SpotifierCoreClient::new();
+    client.set_allowed_upload_types(&["PDF", ".docx"]);
+    let start = std::time::Instant::now();
+
+    let result = client
+        .submit_task(
+            1,
+            2,
+            3,
+            "csrf",
+            "",
+            Some("tugas.pdf".to_string()),
+            Some(PNG_BYTES.to_vec()),
+        )
+        .await;
+
+    match result {
+        Err(ScraperError::UnsupportedFileType {
+            file_name,
+            detected,
+        }) => {
+            assert_eq!(file_name, "tugas.pdf");
+            assert_eq!(detected.as_deref(), Some("image/png"));
+        }
+        other => panic!("Expected an unsupported file type, got {:?}", other),
+    }
+    assert!(start.elapsed().as_millis() < 500);
+
+    // Without a signature the extension decides.
+    let client = SpotifierCoreClient::builder()
+        .allowed_upload_types(DEFAULT_UPLOAD_TYPES)
+        .build()
+        .unwrap();
+    let result = client
+        .submit_task_multi(
+            1,
+            2,
+            3,
+            "csrf",
+            "",
+            vec![("catatan.txt".to_string(), b"hai".to_vec())],
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ScraperError::UnsupportedFileType { detected: None, .. })
+    ));
+}
+
+const SUBMITTED_TOPIC_HTML: &str = r#"<html><body>
+  <div id="tugas">
+    <table class="table table-striped">
+      <tbody>
+        <tr><td>Judul</td><td>: Esai Refleksi</td></tr>
+      </tbody>
+    </table>
+    <div class="panel panel-info">
+      <table><tr><td>Waktu Pengumpulan</td><td>Senin, 1 September 2025 10:00</td></tr></table>
+      <div class="panel-body">
+        <p>Jawaban</p>
+        <a href="/mhs/tugas_del/1/2/98765">Hapus</a>
+      </div>
+    </div>
+    <div class="modal">
+      <input type="hidden" name="_token" value="csrf">
+      <input type="hidden" name="id_tg" value="3">
+    </div>
+  </div>
+</body></html>"#;
+
+#[tokio::test]
+async fn test_submit_task_returning_reads_answer_from_response() {
+    let (client, _) = upload_client(SUBMITTED_TOPIC_HTML);
+
+    let answer = client
+        .submit_task_returning(1, 2, 3, "csrf", "Jawaban", None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(answer.id, Some(98765));
+    assert!(answer.content.contains("Jawaban"));
+    assert!(answer.date_submitted.is_some());
+}
+
+#[tokio::test]
+async fn test_submit_task_returning_accepts_confirmation_page() {
+    let (client, _) = upload_client(
+        r#"<div class="alert alert-success">Tugas berhasil dikumpulkan.
+        <a href="/mhs/tugas_del/1/2/4321">Batalkan</a></div>"#,
+    );
+
+    let answer = client
+        .submit_task_returning(1, 2, 3, "csrf", "Jawaban", None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(answer.id, Some(4321));
+}
+
+#[test]
+fn test_confirmation_page_only_links_answers_of_the_topic() {
+    let page = r#"<div class="alert alert-success">Tugas berhasil dikumpulkan.</div>
+        <a href="/mhs/tugas_del/1/9/1111">Tugas lain</a>
+        <a href="/mhs/tugas_del/7/2/2222">Mata kuliah lain</a>
+        <a href="/mhs/tugas_del/1/2/4321?ref=topik">Batalkan</a>"#;
+
+    let answer = parse_submitted_answer(page, 1, 2, 3).expect("answer of topic 2");
+    assert_eq!(answer.id, Some(4321));
+
+    assert!(parse_submitted_answer(page, 1, 5, 3).is_none());
+}
+
+#[tokio::test]
+async fn test_delete_task_submission_confirms_removal() {
+    let (client, transport) = upload_client(
+        r#"<div id="tugas"><table class="table table-striped"><tbody>
+        <tr><td>Judul</td><td>: Esai Refleksi</td></tr></tbody></table></div>"#,
+    );
+
+    client.delete_task_submission(1, 2, 98765).await.unwrap();
+
+    let request = transport.last_request();
+    assert_eq!(request.method, "GET");
+    assert_eq!(request.path(), "/mhs/tugas_del/1/2/98765");
+}
+
+#[tokio::test]
+async fn test_delete_task_submission_fails_when_still_shown() {
+    let (client, _) = upload_client(SUBMITTED_TOPIC_HTML);
+
+    let result = client.delete_task_submission(1, 2, 98765).await;
+
+    match result {
+        Err(ScraperError::TaskDeletionFailed(message)) => assert!(message.contains("98765")),
+        other => panic!("Expected a deletion failure, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_delete_task_submission_reports_a_stalled_response() {
+    let server = common::TestServer::bind().await;
+    let base_url = server.url();
+    server.serve(|_| {
+        common::Reply::now(
+            "HTTP/1.1 200 OK\r\ncontent-type: text/html\r\ncontent-length: 100\r\n\r\n".to_string(),
+        )
+        .then_after(std::time::Duration::from_secs(5), "<html></html>")
+    });
+    let client = common::builder()
+        .base_url(&base_url)
+        .timeout(std::time::Duration::from_millis(300))
+        .build()
+        .unwrap();
+
+    // Not `Ok`: the deletion cannot be confirmed from a page that never arrived.
+    assert!(matches!(
+        client.delete_task_submission(1, 2, 98765).await,
+        Err(ScraperError::Timeout { .. })
+    ));
+}
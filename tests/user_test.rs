@@ -0,0 +1,42 @@
+// tests/user_test.rs
+
+use spotifier_core::parsers::user::parse_user_from_html;
+use spotifier_core::{Result, ScraperError};
+
+#[test]
+fn test_parse_user() -> Result<()> {
+    let html = r##"
+<html><body>
+  <div class="user-profile">
+    <div class="profile-text"><a href="#">Budi Santoso 2301234</a></div>
+  </div>
+</body></html>
+"##;
+
+    let user = parse_user_from_html(html)?;
+    assert_eq!(user.name, "Budi Santoso");
+    assert_eq!(user.nim, "2301234");
+    Ok(())
+}
+
+#[test]
+fn test_missing_profile_reports_selector() {
+    let html = format!(
+        "<html><body><div class=\"navbar\">SPOT UPI</div>{}</body></html>",
+        "<p>Lorem ipsum</p>".repeat(50)
+    );
+
+    match parse_user_from_html(&html) {
+        Err(ScraperError::SelectorMissing {
+            selector,
+            context,
+            snippet,
+        }) => {
+            assert_eq!(selector, ".user-profile .profile-text");
+            assert_eq!(context, "dashboard");
+            assert!(snippet.starts_with(r#"<div class="navbar">SPOT UPI</div>"#));
+            assert_eq!(snippet.chars().count(), 200);
+        }
+        other => panic!("expected SelectorMissing, got {:?}", other.map(|u| u.nim)),
+    }
+}